@@ -0,0 +1,765 @@
+//! An optional ahead-of-time type checking pass.
+//!
+//! The checker only reports errors that are certain to happen if the
+//! offending code runs, such as `"a" - 1` or `-true`. Anything it cannot be
+//! sure of is given the type [`Type::Any`] and allowed.
+//!
+//! Variables are typed flow-insensitively: each one has the join of every
+//! value ever stored in it, wherever the store happens. Since a function body
+//! may run after later assignments, the program is checked repeatedly until
+//! no variable's type widens any further, and only the errors of the final
+//! pass are reported.
+//!
+//! Variables, parameters and return values may be annotated with a type, as
+//! in `var x: number = 1;`. An annotated variable always has its declared
+//! type, and storing anything that is certainly not of that type is an
+//! error. An instance of a subclass may be stored wherever an instance of
+//! its superclass is expected, and an annotated variable declared without an
+//! initializer may start out as nil.
+
+use std::collections::HashMap;
+
+use lox_generated::ast::Arguments;
+use lox_generated::ast::Assignment;
+use lox_generated::ast::Block;
+use lox_generated::ast::Call;
+use lox_generated::ast::CallRhs;
+use lox_generated::ast::ClassDeclaration;
+use lox_generated::ast::Comparison;
+use lox_generated::ast::Declaration;
+use lox_generated::ast::Equality;
+use lox_generated::ast::Expression;
+use lox_generated::ast::Factor;
+use lox_generated::ast::ForInitializer;
+use lox_generated::ast::Function as FunctionNode;
+use lox_generated::ast::Identifier;
+use lox_generated::ast::LogicAnd;
+use lox_generated::ast::LogicOr;
+use lox_generated::ast::Primary;
+use lox_generated::ast::Program;
+use lox_generated::ast::Statement;
+use lox_generated::ast::Term;
+use lox_generated::ast::TermOperator;
+use lox_generated::ast::TypeAnnotation;
+use lox_generated::ast::Unary;
+use lox_generated::ast::UnaryOperator;
+use lox_generated::ast::VariableDeclaration;
+use lox_generated::span::LineIndex;
+use lox_generated::span::Span;
+
+use self::errors::TypeErrorKind;
+use self::types::FunctionType;
+use self::types::Type;
+use crate::compiler::errors::CompileError;
+use crate::compiler::errors::CompileErrorKind;
+use crate::compiler::parse;
+
+pub(crate) mod errors;
+pub(crate) mod types;
+
+/// Identifies a local variable by where its name appears in its declaration,
+/// which is stable across passes.
+type Binding = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Key<'input> {
+    Global(&'input str),
+    Local(Binding),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Variable {
+    ty: Type,
+    /// Whether `ty` was declared by an annotation rather than inferred.
+    annotated: bool,
+}
+
+/// Type checks `source`, returning every definite type error.
+pub(crate) fn check(source: &str) -> Result<(), Vec<CompileError>> {
+    let lines = LineIndex::new(source);
+    let program = parse(source, &lines)?;
+
+    let mut checker = Checker {
+        lines,
+        span: Span::default(),
+        variables: HashMap::new(),
+        scopes: vec![],
+        classes: HashMap::new(),
+        returns: vec![],
+        changed: false,
+        errors: vec![],
+    };
+    checker.program(&program);
+
+    if checker.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(checker.errors)
+    }
+}
+
+struct Checker<'input> {
+    lines: LineIndex<'input>,
+    /// Where the node being checked is, for errors that have no better
+    /// location.
+    span: Span,
+    variables: HashMap<Key<'input>, Variable>,
+    scopes: Vec<HashMap<&'input str, Binding>>,
+    /// Every class declared so far, which annotations may name, with the
+    /// name of its superclass.
+    classes: HashMap<&'input str, Option<&'input str>>,
+    /// The declared return type of each function being checked, innermost
+    /// last.
+    returns: Vec<Option<Type>>,
+    /// Whether any variable's type widened during the current pass.
+    changed: bool,
+    errors: Vec<CompileError>,
+}
+
+impl<'input> Checker<'input> {
+    fn program(&mut self, program: &Program<'input>) {
+        loop {
+            self.changed = false;
+            self.errors.clear();
+            for decl in &program.0 {
+                self.declaration(decl);
+            }
+            if !self.changed {
+                break;
+            }
+        }
+    }
+
+    fn declaration(&mut self, decl: &Declaration<'input>) {
+        match decl {
+            Declaration::Class(class_decl) => self.class_declaration(class_decl),
+            Declaration::Function(fun_decl) => {
+                let function = &fun_decl.0;
+                let ty = self.function_type(function);
+                self.declare(function.name, Type::Function(ty.clone()), None);
+                self.function(function, ty);
+            }
+            Declaration::Variable(var_decl) => self.var_declaration(var_decl),
+            Declaration::Statement(stmt) => self.statement(stmt),
+            Declaration::Error(_) => {}
+        }
+    }
+
+    fn class_declaration(&mut self, class_decl: &ClassDeclaration<'input>) {
+        let name = class_decl.class_name;
+        let base_name = class_decl.base.map(|base| base.name);
+        self.classes.insert(name.name, base_name);
+        self.declare(name, Type::Class(name.name.to_string()), None);
+        if let Some(base) = class_decl.base {
+            self.mark(base.span);
+            self.variable(base.name);
+        }
+
+        for method in &class_decl.members {
+            let ty = self.function_type(method);
+            self.function(method, ty);
+        }
+    }
+
+    fn function(&mut self, function: &FunctionNode<'input>, ty: FunctionType) {
+        let FunctionType { params, returns } = ty;
+        self.scopes.push(HashMap::new());
+        if let Some(parameters) = &function.parameters {
+            let param_names = std::iter::once(&parameters.first).chain(&parameters.rest);
+            for (param, ty) in param_names.zip(params) {
+                let declared = param.annotation.as_ref().map(|_| ty.clone());
+                self.declare(param.name, ty, declared);
+            }
+        }
+
+        let returns = function.return_type.as_ref().map(|_| *returns);
+        self.returns.push(returns);
+        self.block(&function.body);
+        self.returns.pop();
+        self.scopes.pop();
+    }
+
+    fn var_declaration(&mut self, var_decl: &VariableDeclaration<'input>) {
+        let ty = var_decl
+            .initializer
+            .as_ref()
+            .map(|initializer| self.expression(initializer));
+        let declared = self.annotation_type(var_decl.annotation.as_ref());
+        // Without an initializer the variable is nil until it is first
+        // assigned, and only that assignment is checked.
+        let ty = ty.unwrap_or_else(|| declared.clone().unwrap_or(Type::Nil));
+        self.declare(var_decl.name, ty, declared);
+    }
+
+    fn statement(&mut self, stmt: &Statement<'input>) {
+        match stmt {
+            Statement::Expression(expr_stmt) => {
+                self.expression(&expr_stmt.0);
+            }
+            Statement::For(for_stmt) => {
+                self.scopes.push(HashMap::new());
+                match &for_stmt.initializer {
+                    ForInitializer::Declaration(var_decl) => self.var_declaration(var_decl),
+                    ForInitializer::Expression(expr_stmt) => {
+                        self.expression(&expr_stmt.0);
+                    }
+                    ForInitializer::Semicolon => {}
+                }
+                if let Some(condition) = &for_stmt.condition {
+                    self.expression(condition);
+                }
+                if let Some(increment) = &for_stmt.increment {
+                    self.expression(increment);
+                }
+                self.statement(&for_stmt.body);
+                self.scopes.pop();
+            }
+            Statement::If(if_stmt) => {
+                self.expression(&if_stmt.condition);
+                self.statement(&if_stmt.body);
+                if let Some(else_body) = &if_stmt.else_body {
+                    self.statement(else_body);
+                }
+            }
+            Statement::Print(print_stmt) => {
+                self.expression(&print_stmt.0);
+            }
+            Statement::Return(return_stmt) => {
+                let found = match &return_stmt.0 {
+                    Some(value) => self.expression(value),
+                    None => Type::Nil,
+                };
+                if let Some(Some(expected)) = self.returns.last() {
+                    if !expected.accepts(&found, &self.classes) {
+                        let expected = expected.clone();
+                        self.error(TypeErrorKind::MismatchedReturn { expected, found });
+                    }
+                }
+            }
+            Statement::While(while_stmt) => {
+                self.expression(&while_stmt.condition);
+                self.statement(&while_stmt.body);
+            }
+            Statement::Block(block) => {
+                self.scopes.push(HashMap::new());
+                self.block(block);
+                self.scopes.pop();
+            }
+        }
+    }
+
+    fn block(&mut self, block: &Block<'input>) {
+        for decl in &block.0 {
+            self.declaration(decl);
+        }
+    }
+
+    fn expression(&mut self, expr: &Expression<'input>) -> Type {
+        match expr {
+            Expression::Assignment(assignment) => self.assignment(assignment),
+        }
+    }
+
+    fn assignment(&mut self, assignment: &Assignment<'input>) -> Type {
+        match assignment {
+            Assignment::Assignment {
+                object: Some(object),
+                target,
+                value,
+                ..
+            } => {
+                let object = self.call(object);
+                if !object.may_be_instance() {
+                    self.mark(target.span);
+                    self.error(TypeErrorKind::OnlyInstancesHaveFields(object));
+                }
+                self.assignment(value)
+            }
+            Assignment::Assignment {
+                object: None,
+                target,
+                value,
+                ..
+            } => {
+                let ty = self.assignment(value);
+                self.mark(target.span);
+                self.assign(target.name, ty.clone());
+                ty
+            }
+            Assignment::Index {
+                object,
+                index,
+                value,
+                span,
+                ..
+            } => {
+                let object = self.call(object);
+                let index = self.expression(index);
+                self.index_operands(*span, object, index);
+                self.assignment(value)
+            }
+            Assignment::LogicOr(logic_or) => self.logic_or(logic_or),
+        }
+    }
+
+    fn logic_or(&mut self, logic_or: &LogicOr<'input>) -> Type {
+        let mut ty = self.logic_and(&logic_or.left);
+        for right in &logic_or.rest {
+            let right = self.logic_and(right);
+            ty = ty.join(right);
+        }
+        ty
+    }
+
+    fn logic_and(&mut self, logic_and: &LogicAnd<'input>) -> Type {
+        let mut ty = self.equality(&logic_and.left);
+        for right in &logic_and.rest {
+            let right = self.equality(right);
+            ty = ty.join(right);
+        }
+        ty
+    }
+
+    fn equality(&mut self, equality: &Equality<'input>) -> Type {
+        let mut ty = self.comparison(&equality.left);
+        for (_, right) in &equality.rest {
+            self.comparison(right);
+            ty = Type::Boolean;
+        }
+        ty
+    }
+
+    fn comparison(&mut self, comparison: &Comparison<'input>) -> Type {
+        let mut ty = self.term(&comparison.left);
+        for (op, right) in &comparison.rest {
+            let right = self.term(right);
+            let span = op.span;
+            self.number_operands(span, ty, right);
+            ty = Type::Boolean;
+        }
+        ty
+    }
+
+    fn term(&mut self, term: &Term<'input>) -> Type {
+        let mut ty = self.factor(&term.left);
+        for (op, right) in &term.rest {
+            let right = self.factor(right);
+            let span = op.span;
+            ty = match op.node {
+                TermOperator::Minus => self.number_operands(span, ty, right),
+                TermOperator::Plus => self.add_operands(span, ty, right),
+            };
+        }
+        ty
+    }
+
+    fn factor(&mut self, factor: &Factor<'input>) -> Type {
+        let mut ty = self.unary(&factor.left);
+        for (op, right) in &factor.rest {
+            let right = self.unary(right);
+            let span = op.span;
+            ty = self.number_operands(span, ty, right);
+        }
+        ty
+    }
+
+    fn unary(&mut self, unary: &Unary<'input>) -> Type {
+        match unary {
+            Unary::Unary {
+                operator, right, ..
+            } => {
+                let right = self.unary(right);
+                match operator.node {
+                    UnaryOperator::LogicNot => Type::Boolean,
+                    UnaryOperator::Neg => {
+                        if !right.may_be_number() {
+                            self.mark(operator.span);
+                            self.error(TypeErrorKind::OperandMustBeNumber(right));
+                        }
+                        Type::Number
+                    }
+                }
+            }
+            Unary::Call(call) => self.call(call),
+        }
+    }
+
+    fn call(&mut self, call: &Call<'input>) -> Type {
+        let mut ty = self.primary(&call.target);
+        for rhs in &call.rhs {
+            ty = match rhs {
+                CallRhs::Call(arguments, span) => {
+                    let arguments = self.arguments(arguments.as_ref());
+                    self.call_type(*span, ty, arguments)
+                }
+                CallRhs::Member(name) => {
+                    self.mark(name.span);
+                    if !ty.may_be_instance() {
+                        self.error(TypeErrorKind::OnlyInstancesHaveProperties(ty));
+                    }
+                    Type::Any
+                }
+                CallRhs::Index(index, span) => {
+                    let index = self.expression(index);
+                    self.index_operands(*span, ty, index);
+                    Type::Any
+                }
+            };
+        }
+        ty
+    }
+
+    fn index_operands(&mut self, span: Span, list: Type, index: Type) {
+        if !list.may_be_list() {
+            self.error_at(span, TypeErrorKind::OnlyListsCanBeIndexed(list));
+        }
+        if !index.may_be_number() {
+            self.error_at(span, TypeErrorKind::IndexMustBeNumber(index));
+        }
+    }
+
+    fn arguments(&mut self, arguments: Option<&Arguments<'input>>) -> Vec<Type> {
+        let arguments = match arguments {
+            Some(arguments) => arguments,
+            None => return vec![],
+        };
+
+        std::iter::once(arguments.first.as_ref())
+            .chain(&arguments.rest)
+            .map(|argument| self.expression(argument))
+            .collect()
+    }
+
+    fn call_type(&mut self, span: Span, callee: Type, arguments: Vec<Type>) -> Type {
+        match callee {
+            Type::Any => Type::Any,
+            Type::Class(name) => Type::Instance(name),
+            Type::Function(function) => {
+                if function.params.len() != arguments.len() {
+                    self.error_at(
+                        span,
+                        TypeErrorKind::ArityMismatch {
+                            expected: function.params.len(),
+                            got: arguments.len(),
+                        },
+                    );
+                }
+
+                let pairs = function.params.into_iter().zip(arguments);
+                for (index, (expected, found)) in pairs.enumerate() {
+                    if !expected.accepts(&found, &self.classes) {
+                        self.error_at(
+                            span,
+                            TypeErrorKind::MismatchedArgument {
+                                index: index + 1,
+                                expected,
+                                found,
+                            },
+                        );
+                    }
+                }
+                *function.returns
+            }
+            other => {
+                self.error_at(span, TypeErrorKind::NotCallable(other));
+                Type::Any
+            }
+        }
+    }
+
+    fn primary(&mut self, primary: &Primary<'input>) -> Type {
+        match primary {
+            Primary::True(_) | Primary::False(_) => Type::Boolean,
+            Primary::Nil(_) => Type::Nil,
+            Primary::This(_) => Type::Any,
+            Primary::Number(_) => Type::Number,
+            Primary::String(_) => Type::String,
+            Primary::Identifier(name) => {
+                self.mark(name.span);
+                self.variable(name.name)
+            }
+            Primary::Parenthesized(expr, _) => self.expression(expr),
+            Primary::SuperMember(..) => Type::Any,
+            Primary::List(elements, _) => {
+                for element in elements {
+                    self.expression(element);
+                }
+                Type::List
+            }
+        }
+    }
+
+    fn number_operands(&mut self, span: Span, left: Type, right: Type) -> Type {
+        if !left.may_be_number() || !right.may_be_number() {
+            self.error_at(span, TypeErrorKind::OperandsMustBeNumbers(left, right));
+        }
+        Type::Number
+    }
+
+    fn add_operands(&mut self, span: Span, left: Type, right: Type) -> Type {
+        let numbers = left.may_be_number() && right.may_be_number();
+        let strings = left.may_be_string() && right.may_be_string();
+        match (numbers, strings) {
+            (true, false) => Type::Number,
+            (false, true) => Type::String,
+            (true, true) => Type::Any,
+            (false, false) => {
+                self.error_at(
+                    span,
+                    TypeErrorKind::OperandsMustBeNumbersOrStrings(left, right),
+                );
+                Type::Any
+            }
+        }
+    }
+
+    /// Declares `name` in the innermost scope, or as a global at the top
+    /// level, holding a value of type `ty`. A `declared` type from an
+    /// annotation fixes the variable's type for good.
+    fn declare(&mut self, name: Identifier<'input>, ty: Type, declared: Option<Type>) {
+        self.mark(name.span);
+        let key = match self.scopes.last_mut() {
+            Some(scope) => {
+                let binding = name.span.start;
+                scope.insert(name.name, binding);
+                Key::Local(binding)
+            }
+            None => Key::Global(name.name),
+        };
+
+        match declared {
+            Some(declared) => {
+                self.check_assignable(name.name, &declared, ty);
+                self.update(
+                    key,
+                    Variable {
+                        ty: declared,
+                        annotated: true,
+                    },
+                );
+            }
+            None => self.widen(key, ty),
+        }
+    }
+
+    /// Records that a value of type `ty` is stored in the variable `name`.
+    fn assign(&mut self, name: &'input str, ty: Type) {
+        let key = self.resolve(name).map_or(Key::Global(name), Key::Local);
+        match self.variables.get(&key) {
+            Some(variable) if variable.annotated => {
+                let declared = variable.ty.clone();
+                self.check_assignable(name, &declared, ty);
+            }
+            _ => self.widen(key, ty),
+        }
+    }
+
+    fn check_assignable(&mut self, name: &str, declared: &Type, found: Type) {
+        if !declared.accepts(&found, &self.classes) {
+            self.error(TypeErrorKind::MismatchedAssignment {
+                name: name.to_string(),
+                declared: declared.clone(),
+                found,
+            });
+        }
+    }
+
+    /// Joins `ty` into the type of an unannotated variable.
+    fn widen(&mut self, key: Key<'input>, ty: Type) {
+        let variable = match self.variables.get(&key) {
+            Some(variable) if variable.annotated => return,
+            Some(variable) => Variable {
+                ty: variable.ty.clone().join(ty),
+                annotated: false,
+            },
+            None => Variable {
+                ty,
+                annotated: false,
+            },
+        };
+        self.update(key, variable);
+    }
+
+    fn update(&mut self, key: Key<'input>, variable: Variable) {
+        if self.variables.get(&key) != Some(&variable) {
+            self.variables.insert(key, variable);
+            self.changed = true;
+        }
+    }
+
+    fn variable(&mut self, name: &'input str) -> Type {
+        let key = self.resolve(name).map_or(Key::Global(name), Key::Local);
+        self.variables
+            .get(&key)
+            .map_or(Type::Any, |variable| variable.ty.clone())
+    }
+
+    fn resolve(&self, name: &str) -> Option<Binding> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).copied())
+    }
+
+    fn function_type(&mut self, function: &FunctionNode<'input>) -> FunctionType {
+        let mut params = vec![];
+        if let Some(parameters) = &function.parameters {
+            for param in std::iter::once(&parameters.first).chain(&parameters.rest) {
+                let ty = self.annotation_type(param.annotation.as_ref());
+                params.push(ty.unwrap_or(Type::Any));
+            }
+        }
+
+        let returns = self.annotation_type(function.return_type.as_ref());
+        FunctionType {
+            params,
+            returns: Box::new(returns.unwrap_or(Type::Any)),
+        }
+    }
+
+    fn annotation_type(&mut self, annotation: Option<&TypeAnnotation<'input>>) -> Option<Type> {
+        annotation.map(|annotation| self.resolve_annotation(annotation))
+    }
+
+    fn resolve_annotation(&mut self, annotation: &TypeAnnotation<'input>) -> Type {
+        match annotation {
+            TypeAnnotation::Named(Identifier { name, span }) => match *name {
+                "any" => Type::Any,
+                "nil" => Type::Nil,
+                "boolean" => Type::Boolean,
+                "number" => Type::Number,
+                "string" => Type::String,
+                "list" => Type::List,
+                name if self.classes.contains_key(name) => Type::Instance(name.to_string()),
+                name => {
+                    self.mark(*span);
+                    self.error(TypeErrorKind::UnknownType(name.to_string()));
+                    Type::Any
+                }
+            },
+            TypeAnnotation::Function {
+                parameters,
+                returns,
+                ..
+            } => Type::Function(FunctionType {
+                params: parameters
+                    .iter()
+                    .map(|param| self.resolve_annotation(param))
+                    .collect(),
+                returns: Box::new(self.resolve_annotation(returns)),
+            }),
+        }
+    }
+
+    fn mark(&mut self, span: Span) {
+        self.span = span;
+    }
+
+    fn error(&mut self, kind: TypeErrorKind) {
+        self.error_at(self.span, kind);
+    }
+
+    fn error_at(&mut self, span: Span, kind: TypeErrorKind) {
+        self.errors.push(CompileError {
+            kind: CompileErrorKind::Type(kind),
+            line: self.lines.line(span.start),
+            span: Some(span),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(source: &str) -> Vec<String> {
+        match check(source) {
+            Ok(()) => vec![],
+            Err(errors) => errors.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn reports_definite_errors() {
+        assert_eq!(
+            errors("print \"a\" - 1;\nprint -\"b\";"),
+            vec![
+                "[line 1] Error: Operands must be numbers, but found string and number.",
+                "[line 2] Error: Operand must be a number, but found string.",
+            ]
+        );
+    }
+
+    #[test]
+    fn infers_variable_types() {
+        assert_eq!(
+            errors("var a = \"s\";\nvar b = 1;\nprint a + b;"),
+            vec!["[line 3] Error: Operands must be two numbers or two strings, but found string and number."]
+        );
+        assert_eq!(
+            errors("fun f(a, b) {}\nf(1);"),
+            vec!["[line 2] Error: Expected 2 arguments but got 1."]
+        );
+    }
+
+    #[test]
+    fn enforces_annotations() {
+        assert_eq!(
+            errors(
+                "fun add(a: number, b: number): number { return a + b; }\n\
+                 var s: string = add(1, \"2\");\n\
+                 fun f(): Point { return nil; }"
+            ),
+            vec![
+                "[line 2] Error: Argument 2 must be number, but found string.",
+                "[line 2] Error: Can't assign number to 's', which is declared as string.",
+                "[line 3] Error: Unknown type 'Point'.",
+            ]
+        );
+    }
+
+    #[test]
+    fn allows_what_might_succeed() {
+        // `a` holds a string by the time `f` is called.
+        assert!(errors("var a = 1; fun f() { return a + \"s\"; } a = \"s\"; f();").is_empty());
+        assert!(errors("fun f(x) { return -x; } print f(1) + 1;").is_empty());
+        assert!(errors("class A {} var a = A(); a.x = 1; print a.x - 1;").is_empty());
+    }
+
+    #[test]
+    fn checks_indexing() {
+        assert_eq!(
+            errors("var xs: list = [1, \"a\"];\nprint xs[\"0\"];\nvar n = 1;\nn[0] = 2;"),
+            vec![
+                "[line 2] Error: Index must be a number, but found string.",
+                "[line 4] Error: Only lists can be indexed, but found number.",
+            ]
+        );
+        assert!(errors("fun f(xs) { return xs[0] + 1; } print f([1]);").is_empty());
+    }
+
+    #[test]
+    fn accepts_subclass_instances() {
+        assert!(errors(
+            "class A {} class B < A {} class C < B {}\n\
+             var a: A = C();\n\
+             fun f(b: B) {} f(C());"
+        )
+        .is_empty());
+        assert_eq!(
+            errors("class A {} class B < A {}\nvar b: B = A();"),
+            vec![
+                "[line 2] Error: Can't assign A instance to 'b', which is declared as B instance."
+            ]
+        );
+    }
+
+    #[test]
+    fn allows_annotated_variables_without_initializers() {
+        assert!(errors("var x: number;\nx = 1;").is_empty());
+        assert_eq!(
+            errors("var x: number;\nx = \"s\";"),
+            vec!["[line 2] Error: Can't assign string to 'x', which is declared as number."]
+        );
+    }
+}
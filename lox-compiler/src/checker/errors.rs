@@ -0,0 +1,96 @@
+use std::fmt::Display;
+
+use super::types::Type;
+
+#[derive(Debug)]
+pub(crate) enum TypeErrorKind {
+    OperandMustBeNumber(Type),
+    OperandsMustBeNumbers(Type, Type),
+    OperandsMustBeNumbersOrStrings(Type, Type),
+    NotCallable(Type),
+    ArityMismatch {
+        expected: usize,
+        got: usize,
+    },
+    OnlyInstancesHaveProperties(Type),
+    OnlyInstancesHaveFields(Type),
+    OnlyListsCanBeIndexed(Type),
+    IndexMustBeNumber(Type),
+    UnknownType(String),
+    MismatchedAssignment {
+        name: String,
+        declared: Type,
+        found: Type,
+    },
+    MismatchedArgument {
+        index: usize,
+        expected: Type,
+        found: Type,
+    },
+    MismatchedReturn {
+        expected: Type,
+        found: Type,
+    },
+}
+
+impl Display for TypeErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OperandMustBeNumber(found) => {
+                write!(f, "Operand must be a number, but found {}.", found)
+            }
+            Self::OperandsMustBeNumbers(left, right) => write!(
+                f,
+                "Operands must be numbers, but found {} and {}.",
+                left, right
+            ),
+            Self::OperandsMustBeNumbersOrStrings(left, right) => write!(
+                f,
+                "Operands must be two numbers or two strings, but found {} and {}.",
+                left, right
+            ),
+            Self::NotCallable(found) => write!(
+                f,
+                "Can only call functions and classes, but found {}.",
+                found
+            ),
+            Self::ArityMismatch { expected, got } => {
+                write!(f, "Expected {} arguments but got {}.", expected, got)
+            }
+            Self::OnlyInstancesHaveProperties(found) => {
+                write!(f, "Only instances have properties, but found {}.", found)
+            }
+            Self::OnlyInstancesHaveFields(found) => {
+                write!(f, "Only instances have fields, but found {}.", found)
+            }
+            Self::OnlyListsCanBeIndexed(found) => {
+                write!(f, "Only lists can be indexed, but found {}.", found)
+            }
+            Self::IndexMustBeNumber(found) => {
+                write!(f, "Index must be a number, but found {}.", found)
+            }
+            Self::UnknownType(name) => write!(f, "Unknown type '{}'.", name),
+            Self::MismatchedAssignment {
+                name,
+                declared,
+                found,
+            } => write!(
+                f,
+                "Can't assign {} to '{}', which is declared as {}.",
+                found, name, declared
+            ),
+            Self::MismatchedArgument {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Argument {} must be {}, but found {}.",
+                index, expected, found
+            ),
+            Self::MismatchedReturn { expected, found } => {
+                write!(f, "Must return {}, but found {}.", expected, found)
+            }
+        }
+    }
+}
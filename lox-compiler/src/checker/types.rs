@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// What the checker knows about the values an expression can produce.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Type {
+    /// Nothing is known, so every operation is allowed.
+    Any,
+    Nil,
+    Boolean,
+    Number,
+    String,
+    /// A list of elements of any type.
+    List,
+    Function(FunctionType),
+    /// A class object, named by its declaration.
+    Class(String),
+    /// An instance of the named class.
+    Instance(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FunctionType {
+    pub(crate) params: Vec<Type>,
+    pub(crate) returns: Box<Type>,
+}
+
+impl Type {
+    /// The type of a value that is either of `self` or `other`.
+    pub(crate) fn join(self, other: Type) -> Type {
+        if self == other {
+            self
+        } else {
+            Type::Any
+        }
+    }
+
+    /// Whether a value of type `value` might be used where `self` is
+    /// expected. `classes` maps each class to its superclass, if it has one,
+    /// so that an instance of a subclass is accepted wherever an instance of
+    /// the superclass is.
+    pub(crate) fn accepts(&self, value: &Type, classes: &HashMap<&str, Option<&str>>) -> bool {
+        match (self, value) {
+            (Type::Any, _) | (_, Type::Any) => true,
+            (Type::Function(expected), Type::Function(found)) => {
+                expected.params.len() == found.params.len()
+                    && expected
+                        .params
+                        .iter()
+                        .zip(&found.params)
+                        .all(|(expected, found)| found.accepts(expected, classes))
+                    && expected.returns.accepts(&found.returns, classes)
+            }
+            (Type::Instance(expected), Type::Instance(found)) => {
+                let mut class = Some(found.as_str());
+                // Only as many steps as there are classes, in case a broken
+                // program has classes inherit from each other in a cycle.
+                for _ in 0..=classes.len() {
+                    match class {
+                        Some(name) if name == expected => return true,
+                        Some(name) => class = classes.get(name).copied().flatten(),
+                        None => return false,
+                    }
+                }
+                false
+            }
+            _ => self == value,
+        }
+    }
+
+    pub(crate) fn may_be_number(&self) -> bool {
+        matches!(self, Type::Any | Type::Number)
+    }
+
+    pub(crate) fn may_be_string(&self) -> bool {
+        matches!(self, Type::Any | Type::String)
+    }
+
+    pub(crate) fn may_be_list(&self) -> bool {
+        matches!(self, Type::Any | Type::List)
+    }
+
+    pub(crate) fn may_be_instance(&self) -> bool {
+        matches!(self, Type::Any | Type::Instance(_))
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Type::Any => write!(f, "any"),
+            Type::Nil => write!(f, "nil"),
+            Type::Boolean => write!(f, "boolean"),
+            Type::Number => write!(f, "number"),
+            Type::String => write!(f, "string"),
+            Type::List => write!(f, "list"),
+            Type::Function(function) => {
+                write!(f, "fun(")?;
+                for (i, param) in function.params.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", param)?;
+                }
+                write!(f, "): {}", function.returns)
+            }
+            Type::Class(name) => write!(f, "class {}", name),
+            Type::Instance(name) => write!(f, "{} instance", name),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use lox_generated::span::Span;
+
+use crate::value::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum OpCode {
+    Constant,
+    Nil,
+    True,
+    False,
+    Pop,
+    GetLocal,
+    SetLocal,
+    GetGlobal,
+    DefineGlobal,
+    SetGlobal,
+    GetUpvalue,
+    SetUpvalue,
+    GetProperty,
+    SetProperty,
+    GetSuper,
+    Equal,
+    Greater,
+    Less,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Not,
+    Negate,
+    Print,
+    Jump,
+    JumpIfFalse,
+    Loop,
+    Call,
+    Closure,
+    CloseUpvalue,
+    Return,
+    Class,
+    Inherit,
+    Method,
+    BuildList,
+    GetIndex,
+    SetIndex,
+}
+
+impl OpCode {
+    const ALL: &'static [OpCode] = &[
+        OpCode::Constant,
+        OpCode::Nil,
+        OpCode::True,
+        OpCode::False,
+        OpCode::Pop,
+        OpCode::GetLocal,
+        OpCode::SetLocal,
+        OpCode::GetGlobal,
+        OpCode::DefineGlobal,
+        OpCode::SetGlobal,
+        OpCode::GetUpvalue,
+        OpCode::SetUpvalue,
+        OpCode::GetProperty,
+        OpCode::SetProperty,
+        OpCode::GetSuper,
+        OpCode::Equal,
+        OpCode::Greater,
+        OpCode::Less,
+        OpCode::Add,
+        OpCode::Subtract,
+        OpCode::Multiply,
+        OpCode::Divide,
+        OpCode::Not,
+        OpCode::Negate,
+        OpCode::Print,
+        OpCode::Jump,
+        OpCode::JumpIfFalse,
+        OpCode::Loop,
+        OpCode::Call,
+        OpCode::Closure,
+        OpCode::CloseUpvalue,
+        OpCode::Return,
+        OpCode::Class,
+        OpCode::Inherit,
+        OpCode::Method,
+        OpCode::BuildList,
+        OpCode::GetIndex,
+        OpCode::SetIndex,
+    ];
+
+    pub(crate) fn from_byte(byte: u8) -> Option<Self> {
+        Self::ALL.get(byte as usize).copied()
+    }
+
+    /// How many operand bytes follow this instruction in the code.
+    ///
+    /// `Closure` is additionally followed by two bytes for each upvalue of the
+    /// function it wraps.
+    pub(crate) fn operand_bytes(self) -> usize {
+        match self {
+            OpCode::Constant
+            | OpCode::GetLocal
+            | OpCode::SetLocal
+            | OpCode::GetGlobal
+            | OpCode::DefineGlobal
+            | OpCode::SetGlobal
+            | OpCode::GetUpvalue
+            | OpCode::SetUpvalue
+            | OpCode::GetProperty
+            | OpCode::SetProperty
+            | OpCode::GetSuper
+            | OpCode::Call
+            | OpCode::Closure
+            | OpCode::Class
+            | OpCode::Method
+            | OpCode::BuildList => 1,
+            OpCode::Jump | OpCode::JumpIfFalse | OpCode::Loop => 2,
+            _ => 0,
+        }
+    }
+
+    /// How many values this instruction pops off the stack and how many it
+    /// then pushes, given its first operand byte (only `Call` and `BuildList`
+    /// look at it).
+    ///
+    /// Instructions that only peek at a value count it as popped and pushed
+    /// again.
+    pub(crate) fn stack_effect(self, operand: u8) -> (usize, usize) {
+        match self {
+            OpCode::Constant
+            | OpCode::Nil
+            | OpCode::True
+            | OpCode::False
+            | OpCode::GetLocal
+            | OpCode::GetGlobal
+            | OpCode::GetUpvalue
+            | OpCode::Closure
+            | OpCode::Class => (0, 1),
+            OpCode::Pop
+            | OpCode::DefineGlobal
+            | OpCode::Print
+            | OpCode::CloseUpvalue
+            | OpCode::Return => (1, 0),
+            OpCode::SetLocal
+            | OpCode::SetGlobal
+            | OpCode::SetUpvalue
+            | OpCode::GetProperty
+            | OpCode::Not
+            | OpCode::Negate
+            | OpCode::JumpIfFalse => (1, 1),
+            OpCode::SetProperty
+            | OpCode::GetSuper
+            | OpCode::Equal
+            | OpCode::Greater
+            | OpCode::Less
+            | OpCode::Add
+            | OpCode::Subtract
+            | OpCode::Multiply
+            | OpCode::Divide
+            | OpCode::Inherit
+            | OpCode::Method
+            | OpCode::GetIndex => (2, 1),
+            OpCode::SetIndex => (3, 1),
+            OpCode::Call => (operand as usize + 1, 1),
+            OpCode::BuildList => (operand as usize, 1),
+            OpCode::Jump | OpCode::Loop => (0, 0),
+        }
+    }
+
+    /// The name shown for this instruction in disassembly, e.g. `OP_GET_LOCAL`.
+    pub(crate) fn mnemonic(self) -> String {
+        let mut mnemonic = String::from("OP");
+        for c in format!("{:?}", self).chars() {
+            if c.is_ascii_uppercase() {
+                mnemonic.push('_');
+            }
+            mnemonic.push(c.to_ascii_uppercase());
+        }
+        mnemonic
+    }
+}
+
+/// A sequence of bytecode together with its constant pool.
+///
+/// `lines` runs parallel to `code` and records the source line each byte
+/// was compiled from. `spans` does the same for the exact source span, but is
+/// empty in chunks loaded from a `.loxc` file, which only keeps lines.
+#[derive(Debug, Default)]
+pub(crate) struct Chunk {
+    pub(crate) code: Vec<u8>,
+    pub(crate) constants: Vec<Value>,
+    pub(crate) lines: Vec<usize>,
+    pub(crate) spans: Vec<Span>,
+}
+
+impl Chunk {
+    pub(crate) fn write(&mut self, byte: u8, line: usize, span: Span) {
+        self.code.push(byte);
+        self.lines.push(line);
+        self.spans.push(span);
+    }
+
+    pub(crate) fn add_constant(&mut self, value: Value) -> usize {
+        if let Some(index) = self.constants.iter().position(|&c| c == value) {
+            return index;
+        }
+        self.constants.push(value);
+        self.constants.len() - 1
+    }
+}
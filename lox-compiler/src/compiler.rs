@@ -1,283 +1,923 @@
-use std::collections::HashMap;
-
-use lalrpop_util::lexer::Token;
-use lalrpop_util::ParseError;
-use lox_generated::ast::Assignment;
-use lox_generated::ast::Call;
-use lox_generated::ast::Comparison;
-use lox_generated::ast::ComparisonOperator;
-use lox_generated::ast::Declaration;
-use lox_generated::ast::Equality;
-use lox_generated::ast::EqualityOperator;
-use lox_generated::ast::Factor;
-use lox_generated::ast::FactorOperator;
-use lox_generated::ast::LogicAnd;
-use lox_generated::ast::LogicOr;
-use lox_generated::ast::Primary;
-use lox_generated::ast::Program;
-use lox_generated::ast::Term;
-use lox_generated::ast::TermOperator;
-use lox_generated::ast::Unary;
-use lox_generated::ast::UnaryOperator;
-use lox_generated::lox::ProgramParser;
-use types::Type;
-
-use self::errors::RuntimeError;
-
-pub(crate) mod errors;
-mod types;
-
-#[derive(Debug)]
-struct Symbol {
-    name: String,
-    value: Type,
-}
-
-pub struct Compiler<'input> {
-    program: Program<'input>,
-    stack: Vec<Type>,
-    symbols: HashMap<String, Symbol>,
-}
-
-impl<'input> Compiler<'input> {
-    pub fn new(input: &'input str) -> Result<Self, ParseError<usize, Token<'input>, &'static str>> {
-        Ok(Self {
-            program: ProgramParser::new().parse(input)?,
-            stack: vec![],
-            symbols: HashMap::new(),
-        })
-    }
-
-    pub fn execute(&mut self) -> Result<(), RuntimeError> {
-        self.execute_program(&self.program)
-    }
-
-    fn execute_program(&mut self, program: &Program<'input>) -> Result<(), RuntimeError> {
-        self.execute_declarations(&program.0)
-    }
-
-    fn execute_declarations(&mut self, decls: &[Declaration<'input>]) -> Result<(), RuntimeError> {
-        decls
-            .into_iter()
-            .map(|decl| self.execute_declaration(decl))
-            .collect::<Result<Vec<_>, _>>()
-            .map(|_| ())
-    }
-
-    fn execute_declaration(&mut self, decl: &Declaration<'input>) -> Result<(), RuntimeError> {
-        match decl {
-            Declaration::Class(class_decl) => Self::execute_class_declaration(class_decl),
-            Declaration::Function(fun_decl) => self.execute_function_declaration(fun_decl),
-            Declaration::Variable(_) => {}
-            Declaration::Statement(_) => {}
-        }
-    }
-
-    fn check_integer_value(value: Type) -> Result<i32, RuntimeError> {
-        match value {
-            Type::Integer(i) => Ok(i),
-            _ => Err(RuntimeError::InvalidType(value.clone())),
-        }
-    }
-
-    fn check_boolean_value(value: Type) -> Result<bool, RuntimeError> {
-        match value {
-            Type::Boolean(b) => Ok(b),
-            _ => Err(RuntimeError::InvalidType(value.clone())),
-        }
-    }
-
-    fn evaluate_assignment(
-        &mut self,
-        assignment: &Assignment<'input>,
-    ) -> Result<Type, RuntimeError> {
-        match assignment {
-            Assignment::Assignment {
-                object,
-                target,
-                value,
-            } => {
-                let object = self.evaluate_call(object)?;
-            }
-            Assignment::LogicOr(_) => {}
-        }
-    }
-
-    fn evaluate_logic_or(&mut self, logic_or: &LogicOr<'input>) -> Result<Type, RuntimeError> {
-        let mut result = self.evaluate_logic_and(&logic_or.left)?;
-        for right in &logic_or.rest {
-            let right = self.evaluate_logic_and(right)?;
-            let b = Self::check_boolean_value(result)?;
-            let c = Self::check_boolean_value(right).unwrap();
-            result = Type::Boolean(b || c);
-        }
-        Ok(result)
-    }
-
-    fn evaluate_logic_and(&mut self, logic_and: &LogicAnd<'input>) -> Result<Type, RuntimeError> {
-        let mut result = self.evaluate_equality(&logic_and.left)?;
-        for right in &logic_and.rest {
-            let right = self.evaluate_equality(right)?;
-            let b = Self::check_boolean_value(result)?;
-            let c = Self::check_boolean_value(right)?;
-            result = Type::Boolean(b && c);
-        }
-        Ok(result)
-    }
-
-    fn evaluate_equality(&mut self, equality: &Equality<'input>) -> Result<Type, RuntimeError> {
-        let mut result = self.evaluate_comparison(&equality.left)?;
-        for (op, right) in &equality.rest {
-            let right = self.evaluate_comparison(right)?;
-            result = match op {
-                EqualityOperator::Neq => Type::Boolean(result != right),
-                EqualityOperator::Eq => Type::Boolean(result == right),
-            }
-        }
-        Ok(result)
-    }
-
-    fn evaluate_comparison(
-        &mut self,
-        comparison: &Comparison<'input>,
-    ) -> Result<Type, RuntimeError> {
-        let mut result = self.evaluate_term(&comparison.left)?;
-        for (op, right) in &comparison.rest {
-            let right = self.evaluate_term(right)?;
-            result = match op {
-                ComparisonOperator::Gt => {
-                    let i = Self::check_integer_value(result)?;
-                    let j = Self::check_integer_value(right)?;
-                    Type::Boolean(i > j)
-                }
-                ComparisonOperator::Ge => {
-                    let i = Self::check_integer_value(result)?;
-                    let j = Self::check_integer_value(right)?;
-                    Type::Boolean(i >= j)
-                }
-                ComparisonOperator::Lt => {
-                    let i = Self::check_integer_value(result)?;
-                    let j = Self::check_integer_value(right)?;
-                    Type::Boolean(i < j)
-                }
-                ComparisonOperator::Le => {
-                    let i = Self::check_integer_value(result)?;
-                    let j = Self::check_integer_value(right)?;
-                    Type::Boolean(i <= j)
-                }
-            };
-        }
-        Ok(result)
-    }
-
-    fn evaluate_term(&mut self, term: &Term<'input>) -> Result<Type, RuntimeError> {
-        let mut result = self.evaluate_factor(&term.left)?;
-        for (op, right) in &term.rest {
-            let right = self.evaluate_factor(right)?;
-            result = match op {
-                TermOperator::Minus => {
-                    let i = Self::check_integer_value(result)?;
-                    let j = Self::check_integer_value(right)?;
-                    Type::Integer(i - j)
-                }
-                TermOperator::Plus => {
-                    let i = Self::check_integer_value(result)?;
-                    let j = Self::check_integer_value(right)?;
-                    Type::Integer(i + j)
-                }
-            };
-        }
-        Ok(result)
-    }
-
-    fn evaluate_factor(&mut self, factor: &Factor<'input>) -> Result<Type, RuntimeError> {
-        let mut result = self.evaluate_unary(&factor.left)?;
-        for (op, right) in &factor.rest {
-            let right = self.evaluate_unary(right)?;
-            result = match op {
-                FactorOperator::Slash => {
-                    let i = Self::check_integer_value(result)?;
-                    let j = Self::check_integer_value(right)?;
-                    Type::Integer(i / j)
-                }
-                FactorOperator::Star => {
-                    let i = Self::check_integer_value(result)?;
-                    let j = Self::check_integer_value(right)?;
-                    Type::Integer(i * j)
-                }
-            };
-        }
-        Ok(result)
-    }
-
-    fn evaluate_unary(&mut self, unary: &Unary<'input>) -> Result<Type, RuntimeError> {
-        match unary {
-            Unary::Unary { operator, right } => {
-                let right = self.evaluate_unary(right)?;
-                let t = match operator {
-                    UnaryOperator::LogicNot => match right {
-                        Type::Boolean(b) => Type::Boolean(!b),
-                        _ => {
-                            return Err(RuntimeError::MismatchedUnaryOperator(
-                                *operator,
-                                right.clone(),
-                            ))
-                        }
-                    },
-                    UnaryOperator::Neg => match right {
-                        Type::Integer(i) => Type::Integer(-i),
-                        _ => {
-                            return Err(RuntimeError::MismatchedUnaryOperator(
-                                *operator,
-                                right.clone(),
-                            ))
-                        }
-                    },
-                };
-                Ok(t)
-            }
-            Unary::Call(c) => self.evaluate_call(c),
-        }
-    }
-
-    fn evaluate_call(&mut self, call: &Call<'input>) -> Result<Type, RuntimeError> {
-        match &call.target {
-            Primary::Identifier(i) => {
-                let symbol = self
-                    .symbols
-                    .get(*i)
-                    .ok_or_else(|| RuntimeError::UndefinedSymbol(i.to_string()))?;
-                match symbol {
-                    _ => return Err(RuntimeError::BadCallTarget(symbol.clone())),
-                }
-                Ok(symbol.value.clone())
-            }
-            p => {
-                if !call.rhs.is_empty() {
-                    return Err(RuntimeError::BadCallTarget(self.evaluate_primary(p)?));
-                }
-
-                self.evaluate_primary(p)
-            }
-        }
-    }
-
-    fn evaluate_primary(&mut self, primary: &Primary<'input>) -> Result<Type, RuntimeError> {
-        let t = match primary {
-            Primary::True => Type::Boolean(true),
-            Primary::False => Type::Boolean(false),
-            Primary::Nil => Type::Nil,
-            Primary::This => todo!(),
-            Primary::Number(n) => Type::Integer(
-                n.parse()
-                    .map_err(|_| RuntimeError::BadIntegerLiteral(n.to_string()))?,
-            ),
-            Primary::String(s) => Type::String(s.to_string()),
-            Primary::Identifier(i) => todo!(),
-            Primary::Parenthesized(expr) => self.evaluate_expression(expr),
-            Primary::SuperMember(m) => todo!(),
-        };
-
-        Ok(t)
-    }
-}
+use lox_generated::ast::Arguments;
+use lox_generated::ast::Assignment;
+use lox_generated::ast::Block;
+use lox_generated::ast::Call;
+use lox_generated::ast::CallRhs;
+use lox_generated::ast::ClassDeclaration;
+use lox_generated::ast::Comparison;
+use lox_generated::ast::ComparisonOperator;
+use lox_generated::ast::Declaration;
+use lox_generated::ast::Equality;
+use lox_generated::ast::EqualityOperator;
+use lox_generated::ast::Expression;
+use lox_generated::ast::Factor;
+use lox_generated::ast::FactorOperator;
+use lox_generated::ast::ForInitializer;
+use lox_generated::ast::ForStatement;
+use lox_generated::ast::Function as FunctionNode;
+use lox_generated::ast::Identifier;
+use lox_generated::ast::IfStatement;
+use lox_generated::ast::LogicAnd;
+use lox_generated::ast::LogicOr;
+use lox_generated::ast::Primary;
+use lox_generated::ast::Program;
+use lox_generated::ast::Statement;
+use lox_generated::ast::Term;
+use lox_generated::ast::TermOperator;
+use lox_generated::ast::Unary;
+use lox_generated::ast::UnaryOperator;
+use lox_generated::ast::VariableDeclaration;
+use lox_generated::ast::WhileStatement;
+use lox_generated::span::LineIndex;
+use lox_generated::span::Span;
+
+use self::errors::CompileError;
+use self::errors::CompileErrorKind;
+use crate::chunk::OpCode;
+use crate::heap::Heap;
+use crate::heap::ObjRef;
+use crate::object::Function;
+use crate::object::Obj;
+use crate::value::Value;
+
+pub(crate) mod errors;
+
+const MAX_LOCALS: usize = u8::MAX as usize + 1;
+const MAX_UPVALUES: usize = u8::MAX as usize + 1;
+const MAX_PARAMETERS: usize = 255;
+const MAX_ELEMENTS: usize = 255;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FunctionKind {
+    Script,
+    Function,
+    Method,
+    Initializer,
+}
+
+struct Local<'input> {
+    name: &'input str,
+    /// `None` while the variable's initializer is still being compiled.
+    depth: Option<usize>,
+    /// Whether a closure captures this local, so it must be moved off the
+    /// stack when its scope ends.
+    is_captured: bool,
+}
+
+/// Where a closure finds one of its captured variables when it is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct UpvalueRef {
+    /// A stack slot of the enclosing function if `is_local`, otherwise an
+    /// index into the enclosing function's own upvalues.
+    index: u8,
+    is_local: bool,
+}
+
+/// Compilation state for one function body.
+struct FunctionState<'input> {
+    function: Function,
+    kind: FunctionKind,
+    locals: Vec<Local<'input>>,
+    upvalues: Vec<UpvalueRef>,
+    scope_depth: usize,
+}
+
+impl<'input> FunctionState<'input> {
+    fn new(name: Option<String>, kind: FunctionKind) -> Self {
+        // Slot zero holds the callee, or the receiver inside methods.
+        let slot_zero = match kind {
+            FunctionKind::Method | FunctionKind::Initializer => "this",
+            FunctionKind::Script | FunctionKind::Function => "",
+        };
+        Self {
+            function: Function::new(name),
+            kind,
+            locals: vec![Local {
+                name: slot_zero,
+                depth: Some(0),
+                is_captured: false,
+            }],
+            upvalues: vec![],
+            scope_depth: 0,
+        }
+    }
+}
+
+struct ClassState<'input> {
+    base: Option<&'input str>,
+}
+
+/// Compiles a parsed program into bytecode for the [`Vm`](crate::vm::Vm).
+pub(crate) struct Compiler<'input, 'heap> {
+    lines: LineIndex<'input>,
+    heap: &'heap mut Heap,
+    states: Vec<FunctionState<'input>>,
+    classes: Vec<ClassState<'input>>,
+    line: usize,
+    /// Where the node being compiled is, for error messages.
+    span: Span,
+    errors: Vec<CompileError>,
+}
+
+/// Parses `source`, reporting every syntax error in it.
+pub(crate) fn parse<'input>(
+    source: &'input str,
+    lines: &LineIndex<'_>,
+) -> Result<Program<'input>, Vec<CompileError>> {
+    let (program, errors) = lox_generated::parse(source);
+    match program {
+        Some(program) if errors.is_empty() => Ok(program),
+        _ => Err(errors
+            .into_iter()
+            .map(|error| CompileError::from_parse_error(lines, error))
+            .collect()),
+    }
+}
+
+impl<'input, 'heap> Compiler<'input, 'heap> {
+    /// Compiles `source` into the top-level script function.
+    pub(crate) fn compile(
+        source: &'input str,
+        heap: &'heap mut Heap,
+    ) -> Result<ObjRef, Vec<CompileError>> {
+        let lines = LineIndex::new(source);
+        let program = parse(source, &lines)?;
+
+        let mut compiler = Self {
+            lines,
+            heap,
+            states: vec![FunctionState::new(None, FunctionKind::Script)],
+            classes: vec![],
+            line: 1,
+            span: Span::default(),
+            errors: vec![],
+        };
+
+        for decl in &program.0 {
+            compiler.declaration(decl);
+        }
+        let (function, _) = compiler.end_function();
+
+        if compiler.errors.is_empty() {
+            Ok(compiler.heap.alloc(Obj::Function(function)))
+        } else {
+            Err(compiler.errors)
+        }
+    }
+
+    fn declaration(&mut self, decl: &Declaration<'input>) {
+        match decl {
+            Declaration::Class(class_decl) => self.class_declaration(class_decl),
+            Declaration::Function(fun_decl) => self.function_declaration(&fun_decl.0),
+            Declaration::Variable(var_decl) => self.var_declaration(var_decl),
+            Declaration::Statement(stmt) => self.statement(stmt),
+            Declaration::Error(_) => {}
+        }
+    }
+
+    fn class_declaration(&mut self, class_decl: &ClassDeclaration<'input>) {
+        let name = class_decl.class_name.name;
+        self.mark(class_decl.class_name.span);
+        let name_constant = self.identifier_constant(name);
+        self.declare_variable(name);
+
+        self.emit_op(OpCode::Class);
+        self.emit_byte(name_constant);
+        self.define_variable(name_constant);
+
+        self.classes.push(ClassState {
+            base: class_decl.base.map(|base| base.name),
+        });
+
+        if let Some(Identifier { name: base, span }) = class_decl.base {
+            self.mark(span);
+            if base == name {
+                self.error(CompileErrorKind::InheritFromSelf(base.to_string()));
+            }
+            self.named_variable(base);
+
+            // Methods reach the superclass through this local, so it stays
+            // alive as long as any of them does.
+            self.begin_scope();
+            self.declare_variable("super");
+            self.mark_initialized();
+
+            self.named_variable(name);
+            self.emit_op(OpCode::Inherit);
+        }
+
+        self.named_variable(name);
+        for method in &class_decl.members {
+            self.method(method);
+        }
+        self.emit_op(OpCode::Pop);
+
+        if class_decl.base.is_some() {
+            self.end_scope();
+        }
+        self.classes.pop();
+    }
+
+    fn method(&mut self, method: &FunctionNode<'input>) {
+        self.mark(method.name.span);
+        let constant = self.identifier_constant(method.name.name);
+        let kind = if method.name.name == "init" {
+            FunctionKind::Initializer
+        } else {
+            FunctionKind::Method
+        };
+        self.function(method, kind);
+        self.emit_op(OpCode::Method);
+        self.emit_byte(constant);
+    }
+
+    fn function_declaration(&mut self, function: &FunctionNode<'input>) {
+        let global = self.parse_variable(function.name);
+        self.mark_initialized();
+        self.function(function, FunctionKind::Function);
+        self.define_variable(global);
+    }
+
+    fn function(&mut self, node: &FunctionNode<'input>, kind: FunctionKind) {
+        self.states
+            .push(FunctionState::new(Some(node.name.name.to_string()), kind));
+        self.begin_scope();
+
+        if let Some(parameters) = &node.parameters {
+            for param in std::iter::once(&parameters.first).chain(&parameters.rest) {
+                self.mark(param.name.span);
+                let state = self.state_mut();
+                state.function.arity += 1;
+                if state.function.arity > MAX_PARAMETERS {
+                    self.error(CompileErrorKind::TooManyParameters);
+                }
+                let constant = self.parse_variable(param.name);
+                self.define_variable(constant);
+            }
+        }
+
+        self.block(&node.body);
+
+        let (function, upvalues) = self.end_function();
+        let function = self.heap.alloc(Obj::Function(function));
+        let constant = self.make_constant(Value::Object(function));
+        self.emit_op(OpCode::Closure);
+        self.emit_byte(constant);
+
+        for upvalue in upvalues {
+            self.emit_byte(upvalue.is_local as u8);
+            self.emit_byte(upvalue.index);
+        }
+    }
+
+    fn var_declaration(&mut self, var_decl: &VariableDeclaration<'input>) {
+        let global = self.parse_variable(var_decl.name);
+
+        match &var_decl.initializer {
+            Some(initializer) => self.expression(initializer),
+            None => self.emit_op(OpCode::Nil),
+        }
+
+        self.define_variable(global);
+    }
+
+    fn statement(&mut self, stmt: &Statement<'input>) {
+        match stmt {
+            Statement::Expression(expr_stmt) => {
+                self.expression(&expr_stmt.0);
+                self.emit_op(OpCode::Pop);
+            }
+            Statement::For(for_stmt) => self.for_statement(for_stmt),
+            Statement::If(if_stmt) => self.if_statement(if_stmt),
+            Statement::Print(print_stmt) => {
+                self.expression(&print_stmt.0);
+                self.emit_op(OpCode::Print);
+            }
+            Statement::Return(return_stmt) => {
+                self.mark(return_stmt.1);
+                self.return_statement(&return_stmt.0);
+            }
+            Statement::While(while_stmt) => self.while_statement(while_stmt),
+            Statement::Block(block) => {
+                self.begin_scope();
+                self.block(block);
+                self.end_scope();
+            }
+        }
+    }
+
+    fn block(&mut self, block: &Block<'input>) {
+        for decl in &block.0 {
+            self.declaration(decl);
+        }
+    }
+
+    fn for_statement(&mut self, for_stmt: &ForStatement<'input>) {
+        self.begin_scope();
+
+        match &for_stmt.initializer {
+            ForInitializer::Declaration(var_decl) => self.var_declaration(var_decl),
+            ForInitializer::Expression(expr_stmt) => {
+                self.expression(&expr_stmt.0);
+                self.emit_op(OpCode::Pop);
+            }
+            ForInitializer::Semicolon => {}
+        }
+
+        let mut loop_start = self.current_offset();
+
+        let exit_jump = for_stmt.condition.as_ref().map(|condition| {
+            self.expression(condition);
+            let exit_jump = self.emit_jump(OpCode::JumpIfFalse);
+            self.emit_op(OpCode::Pop);
+            exit_jump
+        });
+
+        if let Some(increment) = &for_stmt.increment {
+            let body_jump = self.emit_jump(OpCode::Jump);
+            let increment_start = self.current_offset();
+            self.expression(increment);
+            self.emit_op(OpCode::Pop);
+
+            self.emit_loop(loop_start);
+            loop_start = increment_start;
+            self.patch_jump(body_jump);
+        }
+
+        self.statement(&for_stmt.body);
+        self.emit_loop(loop_start);
+
+        if let Some(exit_jump) = exit_jump {
+            self.patch_jump(exit_jump);
+            self.emit_op(OpCode::Pop);
+        }
+
+        self.end_scope();
+    }
+
+    fn if_statement(&mut self, if_stmt: &IfStatement<'input>) {
+        self.expression(&if_stmt.condition);
+
+        let then_jump = self.emit_jump(OpCode::JumpIfFalse);
+        self.emit_op(OpCode::Pop);
+        self.statement(&if_stmt.body);
+
+        let else_jump = self.emit_jump(OpCode::Jump);
+        self.patch_jump(then_jump);
+        self.emit_op(OpCode::Pop);
+        if let Some(else_body) = &if_stmt.else_body {
+            self.statement(else_body);
+        }
+        self.patch_jump(else_jump);
+    }
+
+    fn return_statement(&mut self, value: &Option<Expression<'input>>) {
+        if self.state().kind == FunctionKind::Script {
+            self.error(CompileErrorKind::ReturnFromTopLevel);
+        }
+
+        match value {
+            None => self.emit_return(),
+            Some(value) => {
+                if self.state().kind == FunctionKind::Initializer {
+                    self.error(CompileErrorKind::ReturnValueFromInitializer);
+                }
+                self.expression(value);
+                self.emit_op(OpCode::Return);
+            }
+        }
+    }
+
+    fn while_statement(&mut self, while_stmt: &WhileStatement<'input>) {
+        let loop_start = self.current_offset();
+        self.expression(&while_stmt.condition);
+
+        let exit_jump = self.emit_jump(OpCode::JumpIfFalse);
+        self.emit_op(OpCode::Pop);
+        self.statement(&while_stmt.body);
+        self.emit_loop(loop_start);
+
+        self.patch_jump(exit_jump);
+        self.emit_op(OpCode::Pop);
+    }
+
+    fn expression(&mut self, expr: &Expression<'input>) {
+        match expr {
+            Expression::Assignment(assignment) => self.assignment(assignment),
+        }
+    }
+
+    fn assignment(&mut self, assignment: &Assignment<'input>) {
+        match assignment {
+            Assignment::Assignment {
+                object: Some(object),
+                target,
+                value,
+                ..
+            } => {
+                self.call(object);
+                self.assignment(value);
+                self.mark(target.span);
+                let name = self.identifier_constant(target.name);
+                self.emit_op(OpCode::SetProperty);
+                self.emit_byte(name);
+            }
+            Assignment::Assignment {
+                object: None,
+                target,
+                value,
+                ..
+            } => {
+                self.assignment(value);
+                self.mark(target.span);
+                self.set_variable(target.name);
+            }
+            Assignment::Index {
+                object,
+                index,
+                value,
+                bracket,
+                ..
+            } => {
+                self.call(object);
+                self.expression(index);
+                self.assignment(value);
+                // Runtime errors point at the closing bracket, as for reads.
+                self.mark(*bracket);
+                self.emit_op(OpCode::SetIndex);
+            }
+            Assignment::LogicOr(logic_or) => self.logic_or(logic_or),
+        }
+    }
+
+    fn logic_or(&mut self, logic_or: &LogicOr<'input>) {
+        self.logic_and(&logic_or.left);
+        for right in &logic_or.rest {
+            let else_jump = self.emit_jump(OpCode::JumpIfFalse);
+            let end_jump = self.emit_jump(OpCode::Jump);
+
+            self.patch_jump(else_jump);
+            self.emit_op(OpCode::Pop);
+            self.logic_and(right);
+            self.patch_jump(end_jump);
+        }
+    }
+
+    fn logic_and(&mut self, logic_and: &LogicAnd<'input>) {
+        self.equality(&logic_and.left);
+        for right in &logic_and.rest {
+            let end_jump = self.emit_jump(OpCode::JumpIfFalse);
+
+            self.emit_op(OpCode::Pop);
+            self.equality(right);
+            self.patch_jump(end_jump);
+        }
+    }
+
+    fn equality(&mut self, equality: &Equality<'input>) {
+        self.comparison(&equality.left);
+        for (op, right) in &equality.rest {
+            self.comparison(right);
+            self.mark(op.span);
+            match op.node {
+                EqualityOperator::Neq => self.emit_ops(&[OpCode::Equal, OpCode::Not]),
+                EqualityOperator::Eq => self.emit_op(OpCode::Equal),
+            }
+        }
+    }
+
+    fn comparison(&mut self, comparison: &Comparison<'input>) {
+        self.term(&comparison.left);
+        for (op, right) in &comparison.rest {
+            self.term(right);
+            self.mark(op.span);
+            match op.node {
+                ComparisonOperator::Gt => self.emit_op(OpCode::Greater),
+                ComparisonOperator::Ge => self.emit_ops(&[OpCode::Less, OpCode::Not]),
+                ComparisonOperator::Lt => self.emit_op(OpCode::Less),
+                ComparisonOperator::Le => self.emit_ops(&[OpCode::Greater, OpCode::Not]),
+            }
+        }
+    }
+
+    fn term(&mut self, term: &Term<'input>) {
+        self.factor(&term.left);
+        for (op, right) in &term.rest {
+            self.factor(right);
+            self.mark(op.span);
+            match op.node {
+                TermOperator::Minus => self.emit_op(OpCode::Subtract),
+                TermOperator::Plus => self.emit_op(OpCode::Add),
+            }
+        }
+    }
+
+    fn factor(&mut self, factor: &Factor<'input>) {
+        self.unary(&factor.left);
+        for (op, right) in &factor.rest {
+            self.unary(right);
+            self.mark(op.span);
+            match op.node {
+                FactorOperator::Slash => self.emit_op(OpCode::Divide),
+                FactorOperator::Star => self.emit_op(OpCode::Multiply),
+            }
+        }
+    }
+
+    fn unary(&mut self, unary: &Unary<'input>) {
+        match unary {
+            Unary::Unary {
+                operator, right, ..
+            } => {
+                self.unary(right);
+                self.mark(operator.span);
+                match operator.node {
+                    UnaryOperator::LogicNot => self.emit_op(OpCode::Not),
+                    UnaryOperator::Neg => self.emit_op(OpCode::Negate),
+                }
+            }
+            Unary::Call(call) => self.call(call),
+        }
+    }
+
+    fn call(&mut self, call: &Call<'input>) {
+        self.primary(&call.target);
+        for rhs in &call.rhs {
+            match rhs {
+                CallRhs::Call(arguments, span) => {
+                    let count = self.argument_list(arguments.as_ref());
+                    // Runtime errors point at the closing parenthesis.
+                    self.mark(Span::new(span.end - 1, span.end));
+                    self.emit_op(OpCode::Call);
+                    self.emit_byte(count);
+                }
+                CallRhs::Member(name) => {
+                    self.mark(name.span);
+                    let name = self.identifier_constant(name.name);
+                    self.emit_op(OpCode::GetProperty);
+                    self.emit_byte(name);
+                }
+                CallRhs::Index(index, span) => {
+                    self.expression(index);
+                    // Runtime errors point at the closing bracket.
+                    self.mark(Span::new(span.end - 1, span.end));
+                    self.emit_op(OpCode::GetIndex);
+                }
+            }
+        }
+    }
+
+    fn argument_list(&mut self, arguments: Option<&Arguments<'input>>) -> u8 {
+        let arguments = match arguments {
+            Some(arguments) => arguments,
+            None => return 0,
+        };
+
+        self.expression(&arguments.first);
+        for argument in &arguments.rest {
+            self.expression(argument);
+        }
+
+        let count = 1 + arguments.rest.len();
+        if count > MAX_PARAMETERS {
+            self.error(CompileErrorKind::TooManyArguments);
+        }
+        count as u8
+    }
+
+    fn primary(&mut self, primary: &Primary<'input>) {
+        match primary {
+            Primary::True(_) => self.emit_op(OpCode::True),
+            Primary::False(_) => self.emit_op(OpCode::False),
+            Primary::Nil(_) => self.emit_op(OpCode::Nil),
+            Primary::This(span) => {
+                self.mark(*span);
+                if self.classes.is_empty() {
+                    self.error(CompileErrorKind::ThisOutsideClass);
+                    return;
+                }
+                self.named_variable("this");
+            }
+            Primary::Number(n) => {
+                self.mark(n.span);
+                // The grammar only admits digits with an optional fraction, which
+                // always parse.
+                self.emit_constant(Value::Number(n.node.parse().unwrap()));
+            }
+            Primary::String(s) => {
+                self.mark(s.span);
+                let s = self.heap.intern(&s.node[1..s.node.len() - 1]);
+                self.emit_constant(Value::Object(s));
+            }
+            Primary::Identifier(name) => {
+                self.mark(name.span);
+                self.named_variable(name.name);
+            }
+            Primary::Parenthesized(expr, _) => self.expression(expr),
+            Primary::List(elements, span) => {
+                for element in elements {
+                    self.expression(element);
+                }
+                self.mark(*span);
+                if elements.len() > MAX_ELEMENTS {
+                    self.error(CompileErrorKind::TooManyElements);
+                }
+                self.emit_op(OpCode::BuildList);
+                self.emit_byte(elements.len() as u8);
+            }
+            Primary::SuperMember(name, span) => {
+                self.mark(*span);
+                match self.classes.last() {
+                    None => {
+                        self.error(CompileErrorKind::SuperOutsideClass);
+                        return;
+                    }
+                    Some(ClassState { base: None }) => {
+                        self.error(CompileErrorKind::SuperWithoutSuperclass);
+                        return;
+                    }
+                    Some(ClassState { base: Some(_) }) => {}
+                }
+
+                let name = self.identifier_constant(name.name);
+                self.named_variable("this");
+                self.named_variable("super");
+                self.emit_op(OpCode::GetSuper);
+                self.emit_byte(name);
+            }
+        }
+    }
+
+    fn named_variable(&mut self, name: &str) {
+        let (get, _, arg) = self.resolve_variable(name);
+        self.emit_op(get);
+        self.emit_byte(arg);
+    }
+
+    fn set_variable(&mut self, name: &str) {
+        let (_, set, arg) = self.resolve_variable(name);
+        self.emit_op(set);
+        self.emit_byte(arg);
+    }
+
+    /// Finds the instructions that read and write `name` from the current
+    /// function, along with their operand.
+    fn resolve_variable(&mut self, name: &str) -> (OpCode, OpCode, u8) {
+        let current = self.states.len() - 1;
+        if let Some(slot) = self.resolve_local(current, name) {
+            return (OpCode::GetLocal, OpCode::SetLocal, slot);
+        }
+        if let Some(index) = self.resolve_upvalue(current, name) {
+            return (OpCode::GetUpvalue, OpCode::SetUpvalue, index);
+        }
+        let name = self.identifier_constant(name);
+        (OpCode::GetGlobal, OpCode::SetGlobal, name)
+    }
+
+    fn resolve_local(&mut self, state: usize, name: &str) -> Option<u8> {
+        let (slot, local) = self.states[state]
+            .locals
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, local)| local.name == name)?;
+
+        if local.depth.is_none() {
+            self.error(CompileErrorKind::ReadInOwnInitializer(name.to_string()));
+        }
+        Some(slot as u8)
+    }
+
+    /// Resolves `name` as a variable captured from a function enclosing the
+    /// one at `state`, threading it through every function in between.
+    fn resolve_upvalue(&mut self, state: usize, name: &str) -> Option<u8> {
+        let enclosing = state.checked_sub(1)?;
+
+        if let Some(slot) = self.resolve_local(enclosing, name) {
+            self.states[enclosing].locals[slot as usize].is_captured = true;
+            return Some(self.add_upvalue(state, slot, true));
+        }
+
+        let index = self.resolve_upvalue(enclosing, name)?;
+        Some(self.add_upvalue(state, index, false))
+    }
+
+    fn add_upvalue(&mut self, state: usize, index: u8, is_local: bool) -> u8 {
+        let upvalue = UpvalueRef { index, is_local };
+        let upvalues = &self.states[state].upvalues;
+        if let Some(existing) = upvalues.iter().position(|&u| u == upvalue) {
+            return existing as u8;
+        }
+
+        if upvalues.len() == MAX_UPVALUES {
+            self.error(CompileErrorKind::TooManyUpvalues);
+            return 0;
+        }
+
+        let state = &mut self.states[state];
+        state.upvalues.push(upvalue);
+        state.function.upvalue_count = state.upvalues.len();
+        (state.upvalues.len() - 1) as u8
+    }
+
+    /// Declares `name` in the current scope, returning the constant index of
+    /// its name if it is a global.
+    fn parse_variable(&mut self, name: Identifier<'input>) -> u8 {
+        self.mark(name.span);
+        self.declare_variable(name.name);
+        if self.state().scope_depth > 0 {
+            return 0;
+        }
+
+        self.identifier_constant(name.name)
+    }
+
+    fn declare_variable(&mut self, name: &'input str) {
+        let state = self.state();
+        if state.scope_depth == 0 {
+            return;
+        }
+
+        let already_declared = state
+            .locals
+            .iter()
+            .rev()
+            .take_while(|local| local.depth.is_none_or(|depth| depth >= state.scope_depth))
+            .any(|local| local.name == name);
+        if already_declared {
+            self.error(CompileErrorKind::AlreadyDeclared(name.to_string()));
+        }
+
+        if self.state().locals.len() == MAX_LOCALS {
+            self.error(CompileErrorKind::TooManyLocals);
+            return;
+        }
+        self.state_mut().locals.push(Local {
+            name,
+            depth: None,
+            is_captured: false,
+        });
+    }
+
+    fn define_variable(&mut self, global: u8) {
+        if self.state().scope_depth > 0 {
+            self.mark_initialized();
+            return;
+        }
+
+        self.emit_op(OpCode::DefineGlobal);
+        self.emit_byte(global);
+    }
+
+    fn mark_initialized(&mut self) {
+        let state = self.state_mut();
+        if state.scope_depth == 0 {
+            return;
+        }
+        let depth = state.scope_depth;
+        if let Some(local) = state.locals.last_mut() {
+            local.depth = Some(depth);
+        }
+    }
+
+    fn begin_scope(&mut self) {
+        self.state_mut().scope_depth += 1;
+    }
+
+    fn end_scope(&mut self) {
+        let state = self.state_mut();
+        state.scope_depth -= 1;
+        let depth = state.scope_depth;
+
+        while let Some(local) = self.state().locals.last() {
+            if local.depth.is_some_and(|d| d <= depth) {
+                break;
+            }
+            let is_captured = local.is_captured;
+            if is_captured {
+                self.emit_op(OpCode::CloseUpvalue);
+            } else {
+                self.emit_op(OpCode::Pop);
+            }
+            self.state_mut().locals.pop();
+        }
+    }
+
+    /// Finishes the current function, returning it along with the variables
+    /// its closure must capture.
+    fn end_function(&mut self) -> (Function, Vec<UpvalueRef>) {
+        self.emit_return();
+        let state = self
+            .states
+            .pop()
+            .expect("function state stack is never empty");
+        (state.function, state.upvalues)
+    }
+
+    fn identifier_constant(&mut self, name: &str) -> u8 {
+        let name = self.heap.intern(name);
+        self.make_constant(Value::Object(name))
+    }
+
+    fn make_constant(&mut self, value: Value) -> u8 {
+        let constant = self.state_mut().function.chunk.add_constant(value);
+        if constant > u8::MAX as usize {
+            self.error(CompileErrorKind::TooManyConstants);
+            return 0;
+        }
+        constant as u8
+    }
+
+    fn emit_constant(&mut self, value: Value) {
+        let constant = self.make_constant(value);
+        self.emit_op(OpCode::Constant);
+        self.emit_byte(constant);
+    }
+
+    fn emit_return(&mut self) {
+        if self.state().kind == FunctionKind::Initializer {
+            self.emit_op(OpCode::GetLocal);
+            self.emit_byte(0);
+        } else {
+            self.emit_op(OpCode::Nil);
+        }
+        self.emit_op(OpCode::Return);
+    }
+
+    fn emit_jump(&mut self, op: OpCode) -> usize {
+        self.emit_op(op);
+        self.emit_byte(0xff);
+        self.emit_byte(0xff);
+        self.current_offset() - 2
+    }
+
+    fn patch_jump(&mut self, offset: usize) {
+        // -2 to adjust for the jump offset itself.
+        let jump = self.current_offset() - offset - 2;
+        if jump > u16::MAX as usize {
+            self.error(CompileErrorKind::JumpTooLarge);
+        }
+
+        let code = &mut self.state_mut().function.chunk.code;
+        code[offset] = (jump >> 8) as u8;
+        code[offset + 1] = jump as u8;
+    }
+
+    fn emit_loop(&mut self, loop_start: usize) {
+        self.emit_op(OpCode::Loop);
+
+        let offset = self.current_offset() - loop_start + 2;
+        if offset > u16::MAX as usize {
+            self.error(CompileErrorKind::LoopTooLarge);
+        }
+
+        self.emit_byte((offset >> 8) as u8);
+        self.emit_byte(offset as u8);
+    }
+
+    fn emit_ops(&mut self, ops: &[OpCode]) {
+        for &op in ops {
+            self.emit_op(op);
+        }
+    }
+
+    fn emit_op(&mut self, op: OpCode) {
+        self.emit_byte(op as u8);
+    }
+
+    fn emit_byte(&mut self, byte: u8) {
+        let (line, span) = (self.line, self.span);
+        self.state_mut().function.chunk.write(byte, line, span);
+    }
+
+    fn current_offset(&self) -> usize {
+        self.state().function.chunk.code.len()
+    }
+
+    fn state(&self) -> &FunctionState<'input> {
+        self.states
+            .last()
+            .expect("function state stack is never empty")
+    }
+
+    fn state_mut(&mut self) -> &mut FunctionState<'input> {
+        self.states
+            .last_mut()
+            .expect("function state stack is never empty")
+    }
+
+    /// Moves the current location to `span`, which both compile errors and
+    /// the instructions emitted from here on are blamed on.
+    fn mark(&mut self, span: Span) {
+        self.line = self.lines.line(span.start);
+        self.span = span;
+    }
+
+    fn error(&mut self, kind: CompileErrorKind) {
+        self.errors.push(CompileError {
+            kind,
+            line: self.line,
+            span: Some(self.span),
+        });
+    }
+}
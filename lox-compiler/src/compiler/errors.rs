@@ -1,15 +1,156 @@
-use lox_generated::ast::Primary;
-use lox_generated::ast::UnaryOperator;
-
-use super::types::Type;
-use super::Symbol;
-
-#[derive(Debug)]
-pub(crate) enum RuntimeError {
-    IncompatibleTypes(Type, Type),
-    UndefinedSymbol(String),
-    BadCallTarget(Symbol),
-    BadIntegerLiteral(String),
-    MismatchedUnaryOperator(UnaryOperator, Type),
-    InvalidType(Type),
-}
+use std::fmt::Display;
+
+use lalrpop_util::lexer::Token;
+use lox::diagnostic::codes;
+use lox::diagnostic::Diagnostic;
+use lox_generated::span::LineIndex;
+use lox_generated::span::Span;
+use lox_generated::ParseError;
+
+use crate::checker::errors::TypeErrorKind;
+
+#[derive(Debug)]
+pub(crate) struct CompileError {
+    pub(crate) kind: CompileErrorKind,
+    pub(crate) line: usize,
+    /// Where exactly the error is, if known.
+    pub(crate) span: Option<Span>,
+}
+
+#[derive(Debug)]
+pub(crate) enum CompileErrorKind {
+    Syntax(String),
+    TooManyConstants,
+    TooManyLocals,
+    TooManyUpvalues,
+    TooManyParameters,
+    TooManyArguments,
+    TooManyElements,
+    JumpTooLarge,
+    LoopTooLarge,
+    AlreadyDeclared(String),
+    ReadInOwnInitializer(String),
+    ReturnFromTopLevel,
+    ReturnValueFromInitializer,
+    InheritFromSelf(String),
+    ThisOutsideClass,
+    SuperOutsideClass,
+    SuperWithoutSuperclass,
+    Type(TypeErrorKind),
+}
+
+impl CompileError {
+    pub(crate) fn from_parse_error(lines: &LineIndex<'_>, error: ParseError<'_>) -> Self {
+        let (span, message) = match error {
+            ParseError::InvalidToken { location } => (
+                Span::new(location, location + 1),
+                String::from("Invalid token."),
+            ),
+            ParseError::UnrecognizedEOF { location, .. } => (
+                Span::new(location, location),
+                String::from("Unexpected end of input."),
+            ),
+            ParseError::UnrecognizedToken {
+                token: (start, Token(_, text), end),
+                ..
+            } => (Span::new(start, end), format!("Unexpected '{}'.", text)),
+            ParseError::ExtraToken {
+                token: (start, Token(_, text), end),
+            } => (Span::new(start, end), format!("Extra token '{}'.", text)),
+            ParseError::User { error } => (Span::default(), error.to_string()),
+        };
+        Self {
+            kind: CompileErrorKind::Syntax(message),
+            line: lines.line(span.start),
+            span: Some(span),
+        }
+    }
+
+    pub(crate) fn diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::error(self.kind.to_string()).with_code(self.kind.code());
+        let diagnostic = match self.span {
+            Some(span) => diagnostic.with_span(span.start, span.end),
+            None => diagnostic.with_line(self.line),
+        };
+        match self.kind {
+            CompileErrorKind::ReturnValueFromInitializer => {
+                diagnostic.with_note("Initializers always return 'this'.")
+            }
+            CompileErrorKind::SuperWithoutSuperclass => {
+                diagnostic.with_help("Give the class a superclass with '<'.")
+            }
+            _ => diagnostic,
+        }
+    }
+}
+
+impl CompileErrorKind {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Syntax(_) => codes::SYNTAX,
+            Self::TooManyConstants
+            | Self::TooManyLocals
+            | Self::TooManyUpvalues
+            | Self::TooManyParameters
+            | Self::TooManyArguments
+            | Self::TooManyElements
+            | Self::JumpTooLarge
+            | Self::LoopTooLarge => codes::LIMIT_EXCEEDED,
+            Self::AlreadyDeclared(_) => codes::ALREADY_DECLARED,
+            Self::ReadInOwnInitializer(_) => codes::READ_IN_OWN_INITIALIZER,
+            Self::ReturnFromTopLevel | Self::ReturnValueFromInitializer => codes::INVALID_RETURN,
+            Self::InheritFromSelf(_) => codes::INHERIT_FROM_SELF,
+            Self::ThisOutsideClass | Self::SuperOutsideClass | Self::SuperWithoutSuperclass => {
+                codes::INVALID_RECEIVER
+            }
+            Self::Type(_) => codes::TYPE_MISMATCH,
+        }
+    }
+}
+
+impl Display for CompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[line {}] Error: {}", self.line, self.kind)
+    }
+}
+
+impl Display for CompileErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax(message) => write!(f, "{}", message),
+            Self::TooManyConstants => write!(f, "Too many constants in one chunk."),
+            Self::TooManyLocals => write!(f, "Too many local variables in function."),
+            Self::TooManyUpvalues => write!(f, "Too many closure variables in function."),
+            Self::TooManyParameters => write!(f, "Can't have more than 255 parameters."),
+            Self::TooManyArguments => write!(f, "Can't have more than 255 arguments."),
+            Self::TooManyElements => {
+                write!(f, "Can't have more than 255 elements in a list literal.")
+            }
+            Self::JumpTooLarge => write!(f, "Too much code to jump over."),
+            Self::LoopTooLarge => write!(f, "Loop body too large."),
+            Self::AlreadyDeclared(name) => {
+                write!(f, "Already a variable named '{}' in this scope.", name)
+            }
+            Self::ReadInOwnInitializer(name) => write!(
+                f,
+                "Can't read local variable '{}' in its own initializer.",
+                name
+            ),
+            Self::ReturnFromTopLevel => write!(f, "Can't return from top-level code."),
+            Self::ReturnValueFromInitializer => {
+                write!(f, "Can't return a value from an initializer.")
+            }
+            Self::InheritFromSelf(name) => {
+                write!(f, "A class can't inherit from itself ('{}').", name)
+            }
+            Self::ThisOutsideClass => write!(f, "Can't use 'this' outside of a class."),
+            Self::SuperOutsideClass => write!(f, "Can't use 'super' outside of a class."),
+            Self::SuperWithoutSuperclass => {
+                write!(f, "Can't use 'super' in a class with no superclass.")
+            }
+            Self::Type(kind) => write!(f, "{}", kind),
+        }
+    }
+}
+
+impl std::error::Error for CompileError {}
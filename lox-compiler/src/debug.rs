@@ -0,0 +1,197 @@
+use crate::chunk::Chunk;
+use crate::chunk::OpCode;
+use crate::heap::Heap;
+use crate::object::Obj;
+use crate::value::Value;
+
+/// Prints the bytecode of `function` and of every function nested in its
+/// constant pool.
+pub(crate) fn disassemble_function(heap: &Heap, function: &crate::object::Function) {
+    let name = function.name.as_deref().unwrap_or("<script>");
+    disassemble_chunk(heap, &function.chunk, name);
+
+    for constant in &function.chunk.constants {
+        if let Value::Object(obj) = constant {
+            if let Obj::Function(nested) = heap.get(*obj) {
+                println!();
+                disassemble_function(heap, nested);
+            }
+        }
+    }
+}
+
+pub(crate) fn disassemble_chunk(heap: &Heap, chunk: &Chunk, name: &str) {
+    println!("== {} ==", name);
+
+    let mut offset = 0;
+    while offset < chunk.code.len() {
+        offset = disassemble_instruction(heap, chunk, offset);
+    }
+}
+
+/// Prints the instruction at `offset` and returns the offset of the next one.
+pub(crate) fn disassemble_instruction(heap: &Heap, chunk: &Chunk, offset: usize) -> usize {
+    print!("{:04} ", offset);
+    if offset > 0 && chunk.lines[offset] == chunk.lines[offset - 1] {
+        print!("   | ");
+    } else {
+        print!("{:4} ", chunk.lines[offset]);
+    }
+
+    let instruction = chunk.code[offset];
+    let op = match OpCode::from_byte(instruction) {
+        Some(op) => op,
+        None => {
+            println!("Unknown opcode {}", instruction);
+            return offset + 1;
+        }
+    };
+
+    match op {
+        OpCode::Constant
+        | OpCode::GetGlobal
+        | OpCode::DefineGlobal
+        | OpCode::SetGlobal
+        | OpCode::GetProperty
+        | OpCode::SetProperty
+        | OpCode::GetSuper
+        | OpCode::Class
+        | OpCode::Method => constant_instruction(heap, op, chunk, offset),
+        OpCode::GetLocal
+        | OpCode::SetLocal
+        | OpCode::GetUpvalue
+        | OpCode::SetUpvalue
+        | OpCode::Call
+        | OpCode::BuildList => byte_instruction(op, chunk, offset),
+        OpCode::Jump | OpCode::JumpIfFalse => jump_instruction(op, 1, chunk, offset),
+        OpCode::Loop => jump_instruction(op, -1, chunk, offset),
+        OpCode::Closure => closure_instruction(heap, chunk, offset),
+        OpCode::Nil
+        | OpCode::True
+        | OpCode::False
+        | OpCode::Pop
+        | OpCode::Equal
+        | OpCode::Greater
+        | OpCode::Less
+        | OpCode::Add
+        | OpCode::Subtract
+        | OpCode::Multiply
+        | OpCode::Divide
+        | OpCode::Not
+        | OpCode::Negate
+        | OpCode::Print
+        | OpCode::CloseUpvalue
+        | OpCode::Return
+        | OpCode::Inherit
+        | OpCode::GetIndex
+        | OpCode::SetIndex => simple_instruction(op, offset),
+    }
+}
+
+fn simple_instruction(op: OpCode, offset: usize) -> usize {
+    println!("{}", op.mnemonic());
+    offset + 1
+}
+
+fn byte_instruction(op: OpCode, chunk: &Chunk, offset: usize) -> usize {
+    let slot = chunk.code[offset + 1];
+    println!("{:<16} {:4}", op.mnemonic(), slot);
+    offset + 2
+}
+
+fn constant_instruction(heap: &Heap, op: OpCode, chunk: &Chunk, offset: usize) -> usize {
+    let constant = chunk.code[offset + 1];
+    println!(
+        "{:<16} {:4} '{}'",
+        op.mnemonic(),
+        constant,
+        heap.format_value(chunk.constants[constant as usize])
+    );
+    offset + 2
+}
+
+fn closure_instruction(heap: &Heap, chunk: &Chunk, offset: usize) -> usize {
+    let constant = chunk.code[offset + 1];
+    let value = chunk.constants[constant as usize];
+    println!(
+        "{:<16} {:4} {}",
+        OpCode::Closure.mnemonic(),
+        constant,
+        heap.format_value(value)
+    );
+
+    let upvalue_count = match value {
+        Value::Object(function) => heap.function(function).upvalue_count,
+        _ => 0,
+    };
+
+    let mut offset = offset + 2;
+    for _ in 0..upvalue_count {
+        let is_local = chunk.code[offset];
+        let index = chunk.code[offset + 1];
+        println!(
+            "{:04}    |                     {} {}",
+            offset,
+            if is_local != 0 { "local" } else { "upvalue" },
+            index
+        );
+        offset += 2;
+    }
+    offset
+}
+
+fn jump_instruction(op: OpCode, sign: isize, chunk: &Chunk, offset: usize) -> usize {
+    let jump = u16::from_be_bytes([chunk.code[offset + 1], chunk.code[offset + 2]]);
+    let target = offset as isize + 3 + sign * jump as isize;
+    println!("{:<16} {:4} -> {}", op.mnemonic(), offset, target);
+    offset + 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Compiler;
+
+    #[test]
+    fn steps_over_whole_instructions() {
+        let mut heap = Heap::new();
+        let source = "fun outer(a) { fun inner() { return a; } return inner; }
+                      var i = 0;
+                      while (i < 2) { i = i + 1; }
+                      print outer(i)();";
+        let script = Compiler::compile(source, &mut heap).unwrap();
+        let script = heap.function(script);
+        let outer = script
+            .chunk
+            .constants
+            .iter()
+            .find_map(|constant| match constant {
+                Value::Object(obj) => match heap.get(*obj) {
+                    Obj::Function(function) => Some(function),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .unwrap();
+
+        for function in [script, outer] {
+            let chunk = &function.chunk;
+            let mut offset = 0;
+            while offset < chunk.code.len() {
+                let op = OpCode::from_byte(chunk.code[offset]).unwrap();
+                let next = disassemble_instruction(&heap, chunk, offset);
+                if op != OpCode::Closure {
+                    assert_eq!(next, offset + 1 + op.operand_bytes());
+                }
+                offset = next;
+            }
+            assert_eq!(offset, chunk.code.len());
+        }
+    }
+
+    #[test]
+    fn names_instructions() {
+        assert_eq!(OpCode::GetLocal.mnemonic(), "OP_GET_LOCAL");
+        assert_eq!(OpCode::Return.mnemonic(), "OP_RETURN");
+    }
+}
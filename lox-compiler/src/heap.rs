@@ -0,0 +1,432 @@
+use std::collections::HashMap;
+use std::mem;
+
+use crate::object::Class;
+use crate::object::Closure;
+use crate::object::Function;
+use crate::object::Instance;
+use crate::object::Obj;
+use crate::object::Upvalue;
+use crate::value::Value;
+
+/// A handle to an object owned by a [`Heap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct ObjRef(usize);
+
+/// How many bytes may be allocated before the first collection.
+const INITIAL_NEXT_GC: usize = 1024 * 1024;
+
+/// The default for [`Heap::growth_factor`].
+const DEFAULT_GROWTH_FACTOR: f64 = 2.0;
+
+/// Owns every object the compiler and VM allocate.
+///
+/// Strings are interned, so two string handles are equal exactly when their
+/// contents are.
+///
+/// Objects are reclaimed by a mark-and-sweep collector. The heap only knows
+/// how to trace and sweep; whoever owns the roots marks them with
+/// [`Heap::mark_value`] and [`Heap::mark_object`] and then calls
+/// [`Heap::collect`]. Allocation never collects on its own, so the compiler
+/// can allocate freely without rooting anything.
+#[derive(Debug)]
+pub(crate) struct Heap {
+    /// Freed slots are `None` until [`Heap::alloc`] reuses them.
+    objects: Vec<Option<Obj>>,
+    marks: Vec<bool>,
+    /// The estimated size of each live object, as of its allocation or its
+    /// last [`Heap::resize`].
+    sizes: Vec<usize>,
+    free: Vec<usize>,
+    /// Marked objects whose references have not been traced yet.
+    gray: Vec<ObjRef>,
+    strings: HashMap<String, ObjRef>,
+    bytes_allocated: usize,
+    next_gc: usize,
+    /// After a collection, the next one is due once the live size has grown
+    /// by this factor.
+    pub(crate) growth_factor: f64,
+    /// Collect before every allocation, to shake out missing roots.
+    pub(crate) stress: bool,
+}
+
+impl Default for Heap {
+    fn default() -> Self {
+        Self {
+            objects: vec![],
+            marks: vec![],
+            sizes: vec![],
+            free: vec![],
+            gray: vec![],
+            strings: HashMap::new(),
+            bytes_allocated: 0,
+            next_gc: INITIAL_NEXT_GC,
+            growth_factor: DEFAULT_GROWTH_FACTOR,
+            stress: false,
+        }
+    }
+}
+
+impl Heap {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn alloc(&mut self, obj: Obj) -> ObjRef {
+        let size = size_of_obj(&obj);
+        self.bytes_allocated += size;
+
+        match self.free.pop() {
+            Some(index) => {
+                self.objects[index] = Some(obj);
+                self.marks[index] = false;
+                self.sizes[index] = size;
+                ObjRef(index)
+            }
+            None => {
+                self.objects.push(Some(obj));
+                self.marks.push(false);
+                self.sizes.push(size);
+                ObjRef(self.objects.len() - 1)
+            }
+        }
+    }
+
+    pub(crate) fn intern(&mut self, s: &str) -> ObjRef {
+        if let Some(&existing) = self.strings.get(s) {
+            return existing;
+        }
+        let obj = self.alloc(Obj::String(s.to_string()));
+        self.strings.insert(s.to_string(), obj);
+        obj
+    }
+
+    pub(crate) fn get(&self, obj: ObjRef) -> &Obj {
+        self.objects[obj.0].as_ref().expect("use of freed object")
+    }
+
+    pub(crate) fn get_mut(&mut self, obj: ObjRef) -> &mut Obj {
+        self.objects[obj.0].as_mut().expect("use of freed object")
+    }
+
+    /// Re-estimates the size of an object that was changed in place, so
+    /// lists, instances and classes that grow count toward the next
+    /// collection.
+    pub(crate) fn resize(&mut self, obj: ObjRef) {
+        let size = size_of_obj(self.get(obj));
+        self.bytes_allocated = self.bytes_allocated - self.sizes[obj.0] + size;
+        self.sizes[obj.0] = size;
+    }
+
+    /// Whether the owner of the roots should run a collection before its
+    /// next allocation.
+    pub(crate) fn should_collect(&self) -> bool {
+        self.stress || self.bytes_allocated > self.next_gc
+    }
+
+    pub(crate) fn mark_value(&mut self, value: Value) {
+        if let Value::Object(obj) = value {
+            self.mark_object(obj);
+        }
+    }
+
+    pub(crate) fn mark_object(&mut self, obj: ObjRef) {
+        mark(&mut self.marks, &mut self.gray, obj);
+    }
+
+    /// Marks everything `obj` refers to, for an object that is about to be
+    /// allocated and so is not reachable from any root yet.
+    pub(crate) fn mark_references(&mut self, obj: &Obj) {
+        let Self { marks, gray, .. } = self;
+        for_each_reference(obj, |child| mark(marks, gray, child));
+    }
+
+    /// Frees every object not reachable from the objects marked so far.
+    pub(crate) fn collect(&mut self) {
+        self.trace_references();
+        self.sweep();
+        self.next_gc =
+            ((self.bytes_allocated as f64 * self.growth_factor) as usize).max(INITIAL_NEXT_GC);
+    }
+
+    fn trace_references(&mut self) {
+        let Self {
+            objects,
+            marks,
+            gray,
+            ..
+        } = self;
+        while let Some(obj) = gray.pop() {
+            let obj = objects[obj.0].as_ref().expect("marked a freed object");
+            for_each_reference(obj, |child| mark(marks, gray, child));
+        }
+    }
+
+    fn sweep(&mut self) {
+        // The string table holds its strings weakly.
+        let marks = &self.marks;
+        self.strings.retain(|_, obj| marks[obj.0]);
+
+        for index in 0..self.objects.len() {
+            if self.marks[index] {
+                self.marks[index] = false;
+            } else if self.objects[index].take().is_some() {
+                self.bytes_allocated -= self.sizes[index];
+                self.free.push(index);
+            }
+        }
+    }
+
+    pub(crate) fn string(&self, obj: ObjRef) -> &str {
+        match self.get(obj) {
+            Obj::String(s) => s,
+            other => panic!("expected string, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn function(&self, obj: ObjRef) -> &Function {
+        match self.get(obj) {
+            Obj::Function(f) => f,
+            other => panic!("expected function, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn closure(&self, obj: ObjRef) -> &Closure {
+        match self.get(obj) {
+            Obj::Closure(c) => c,
+            other => panic!("expected closure, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn upvalue(&self, obj: ObjRef) -> &Upvalue {
+        match self.get(obj) {
+            Obj::Upvalue(u) => u,
+            other => panic!("expected upvalue, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn upvalue_mut(&mut self, obj: ObjRef) -> &mut Upvalue {
+        match self.get_mut(obj) {
+            Obj::Upvalue(u) => u,
+            other => panic!("expected upvalue, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn class(&self, obj: ObjRef) -> &Class {
+        match self.get(obj) {
+            Obj::Class(c) => c,
+            other => panic!("expected class, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn class_mut(&mut self, obj: ObjRef) -> &mut Class {
+        match self.get_mut(obj) {
+            Obj::Class(c) => c,
+            other => panic!("expected class, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn instance_mut(&mut self, obj: ObjRef) -> &mut Instance {
+        match self.get_mut(obj) {
+            Obj::Instance(i) => i,
+            other => panic!("expected instance, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn list(&self, obj: ObjRef) -> &[Value] {
+        match self.get(obj) {
+            Obj::List(l) => l,
+            other => panic!("expected list, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn list_mut(&mut self, obj: ObjRef) -> &mut Vec<Value> {
+        match self.get_mut(obj) {
+            Obj::List(l) => l,
+            other => panic!("expected list, found {:?}", other),
+        }
+    }
+
+    /// Renders a value the way `print` shows it.
+    pub(crate) fn format_value(&self, value: Value) -> String {
+        self.format_inside(value, &mut Vec::new())
+    }
+
+    /// Renders `value` as an element of the `printing` lists, so that a list
+    /// containing itself is shown as `[...]` instead of forever.
+    fn format_inside(&self, value: Value, printing: &mut Vec<ObjRef>) -> String {
+        match value {
+            Value::Nil => String::from("nil"),
+            Value::Boolean(b) => b.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::Object(obj) => match self.get(obj) {
+                Obj::String(s) => s.clone(),
+                Obj::Function(f) => match &f.name {
+                    Some(name) => format!("<fn {}>", name),
+                    None => String::from("<script>"),
+                },
+                Obj::Closure(c) => self.format_value(Value::Object(c.function)),
+                Obj::Upvalue(_) => String::from("upvalue"),
+                Obj::Class(c) => self.string(c.name).to_string(),
+                Obj::Instance(i) => {
+                    format!("{} instance", self.string(self.class(i.class).name))
+                }
+                Obj::BoundMethod(b) => self.format_value(Value::Object(b.method)),
+                Obj::Native(native) => format!("<native fn {}>", native.name),
+                Obj::List(_) if printing.contains(&obj) => String::from("[...]"),
+                Obj::List(elements) => {
+                    printing.push(obj);
+                    let elements = elements
+                        .iter()
+                        .map(|&element| match element {
+                            Value::Object(obj) if matches!(self.get(obj), Obj::String(_)) => {
+                                format!("\"{}\"", self.string(obj))
+                            }
+                            _ => self.format_inside(element, printing),
+                        })
+                        .collect::<Vec<_>>();
+                    printing.pop();
+                    format!("[{}]", elements.join(", "))
+                }
+            },
+        }
+    }
+}
+
+fn mark(marks: &mut [bool], gray: &mut Vec<ObjRef>, obj: ObjRef) {
+    if !marks[obj.0] {
+        marks[obj.0] = true;
+        gray.push(obj);
+    }
+}
+
+fn for_each_reference(obj: &Obj, mut f: impl FnMut(ObjRef)) {
+    let mut value = |value: &Value| {
+        if let Value::Object(obj) = value {
+            f(*obj);
+        }
+    };
+
+    match obj {
+        Obj::String(_) => {}
+        Obj::Function(function) => function.chunk.constants.iter().for_each(value),
+        Obj::Closure(closure) => {
+            value(&Value::Object(closure.function));
+            for upvalue in &closure.upvalues {
+                value(&Value::Object(*upvalue));
+            }
+        }
+        Obj::Upvalue(Upvalue::Open(_)) => {}
+        Obj::Upvalue(Upvalue::Closed(closed)) => value(closed),
+        Obj::Class(class) => {
+            value(&Value::Object(class.name));
+            for (name, method) in &class.methods {
+                value(&Value::Object(*name));
+                value(method);
+            }
+        }
+        Obj::Instance(instance) => {
+            value(&Value::Object(instance.class));
+            for (name, field) in &instance.fields {
+                value(&Value::Object(*name));
+                value(field);
+            }
+        }
+        Obj::BoundMethod(bound) => {
+            value(&bound.receiver);
+            value(&Value::Object(bound.method));
+        }
+        Obj::Native(_) => {}
+        Obj::List(elements) => elements.iter().for_each(value),
+    }
+}
+
+/// A rough estimate of the memory `obj` owns, used to pace collections.
+fn size_of_obj(obj: &Obj) -> usize {
+    let payload = match obj {
+        Obj::String(s) => s.len(),
+        Obj::Function(function) => {
+            let chunk = &function.chunk;
+            chunk.code.len()
+                + chunk.constants.len() * mem::size_of::<Value>()
+                + chunk.lines.len() * mem::size_of::<usize>()
+        }
+        Obj::Closure(closure) => closure.upvalues.len() * mem::size_of::<ObjRef>(),
+        Obj::Upvalue(_) => 0,
+        Obj::Class(class) => class.methods.len() * mem::size_of::<(ObjRef, Value)>(),
+        Obj::Instance(instance) => instance.fields.len() * mem::size_of::<(ObjRef, Value)>(),
+        Obj::BoundMethod(_) | Obj::Native(_) => 0,
+        Obj::List(elements) => elements.len() * mem::size_of::<Value>(),
+    };
+    mem::size_of::<Obj>() + payload
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::Instance;
+
+    fn live(heap: &Heap) -> usize {
+        heap.objects.iter().filter(|obj| obj.is_some()).count()
+    }
+
+    #[test]
+    fn frees_unreachable_objects() {
+        let mut heap = Heap::new();
+        let name = heap.intern("Point");
+        let class = heap.alloc(Obj::Class(Class::new(name)));
+        let instance = heap.alloc(Obj::Instance(Instance::new(class)));
+        heap.intern("garbage");
+        assert_eq!(live(&heap), 4);
+
+        heap.mark_object(instance);
+        heap.collect();
+        assert_eq!(live(&heap), 3);
+        assert_eq!(heap.string(name), "Point");
+
+        // The freed string is no longer interned, so this allocates afresh.
+        let garbage = heap.intern("garbage");
+        assert_eq!(heap.string(garbage), "garbage");
+        assert_eq!(live(&heap), 4);
+    }
+
+    #[test]
+    fn collects_cycles() {
+        let mut heap = Heap::new();
+        let name = heap.intern("Node");
+        let class = heap.alloc(Obj::Class(Class::new(name)));
+        let a = heap.alloc(Obj::Instance(Instance::new(class)));
+        let b = heap.alloc(Obj::Instance(Instance::new(class)));
+        let field = heap.intern("other");
+        heap.instance_mut(a).fields.insert(field, Value::Object(b));
+        heap.instance_mut(b).fields.insert(field, Value::Object(a));
+
+        heap.collect();
+        assert_eq!(live(&heap), 0);
+    }
+
+    #[test]
+    fn counts_objects_growing_in_place() {
+        let mut heap = Heap::new();
+        let list = heap.alloc(Obj::List(vec![]));
+        let empty = heap.bytes_allocated;
+
+        heap.list_mut(list).resize(INITIAL_NEXT_GC, Value::Nil);
+        heap.resize(list);
+        assert_eq!(
+            heap.bytes_allocated,
+            empty + INITIAL_NEXT_GC * mem::size_of::<Value>()
+        );
+        assert!(heap.should_collect());
+
+        // The live size after a collection includes the growth, so the next
+        // one is not due straight away.
+        heap.mark_object(list);
+        heap.collect();
+        assert!(!heap.should_collect());
+
+        heap.list_mut(list).clear();
+        heap.resize(list);
+        assert_eq!(heap.bytes_allocated, empty);
+    }
+}
@@ -0,0 +1,647 @@
+//! The `.loxc` on-disk format for compiled scripts.
+//!
+//! A file is the magic bytes `LOXC`, a little-endian `u16` format version and
+//! then the top-level script function. A function is written as its name,
+//! arity, upvalue count, code, per-byte line numbers and constant pool;
+//! nested functions are written inline in the constant pool of the function
+//! that declares them.
+//!
+//! All integers are little-endian. Strings are a `u32` byte length followed
+//! by UTF-8.
+
+use std::collections::BTreeSet;
+use std::convert::TryInto;
+
+use errors::LoadError;
+
+use crate::chunk::Chunk;
+use crate::chunk::OpCode;
+use crate::heap::Heap;
+use crate::heap::ObjRef;
+use crate::object::Function;
+use crate::object::Obj;
+use crate::value::Value;
+
+pub(crate) mod errors;
+
+pub(crate) const MAGIC: &[u8; 4] = b"LOXC";
+
+/// Bumped whenever the layout below or the instruction set changes.
+pub(crate) const FORMAT_VERSION: u16 = 3;
+
+const TAG_NIL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_NUMBER: u8 = 3;
+const TAG_STRING: u8 = 4;
+const TAG_FUNCTION: u8 = 5;
+
+/// Returns whether `bytes` start like a `.loxc` file rather than source text.
+pub(crate) fn is_loxc(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+pub(crate) fn serialize(heap: &Heap, function: ObjRef) -> Vec<u8> {
+    let mut writer = Writer {
+        heap,
+        bytes: Vec::new(),
+    };
+    writer.bytes.extend_from_slice(MAGIC);
+    writer
+        .bytes
+        .extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    writer.function(heap.function(function));
+    writer.bytes
+}
+
+/// Loads a script function from `bytes`, allocating it and everything it
+/// references in `heap`.
+pub(crate) fn deserialize(bytes: &[u8], heap: &mut Heap) -> Result<ObjRef, LoadError> {
+    let mut reader = Reader {
+        heap,
+        bytes,
+        offset: 0,
+    };
+
+    if reader.take(MAGIC.len())? != MAGIC {
+        return Err(LoadError::BadMagic);
+    }
+    let version = u16::from_le_bytes(reader.take(2)?.try_into().unwrap());
+    if version != FORMAT_VERSION {
+        return Err(LoadError::VersionMismatch {
+            expected: FORMAT_VERSION,
+            found: version,
+        });
+    }
+
+    let function = reader.function()?;
+    if reader.offset != bytes.len() {
+        return Err(LoadError::TrailingBytes);
+    }
+    // The VM runs the script in a closure with nothing to capture.
+    if reader.heap.function(function).upvalue_count != 0 {
+        return Err(LoadError::ScriptHasUpvalues);
+    }
+    Ok(function)
+}
+
+struct Writer<'heap> {
+    heap: &'heap Heap,
+    bytes: Vec<u8>,
+}
+
+impl Writer<'_> {
+    fn function(&mut self, function: &Function) {
+        match &function.name {
+            Some(name) => {
+                self.u8(1);
+                self.string(name);
+            }
+            None => self.u8(0),
+        }
+        self.u8(function.arity as u8);
+        self.u32(function.upvalue_count);
+
+        let chunk = &function.chunk;
+        self.u32(chunk.code.len());
+        self.bytes.extend_from_slice(&chunk.code);
+        for &line in &chunk.lines {
+            self.u32(line);
+        }
+
+        self.u32(chunk.constants.len());
+        for &constant in &chunk.constants {
+            self.value(constant);
+        }
+    }
+
+    fn value(&mut self, value: Value) {
+        match value {
+            Value::Nil => self.u8(TAG_NIL),
+            Value::Boolean(false) => self.u8(TAG_FALSE),
+            Value::Boolean(true) => self.u8(TAG_TRUE),
+            Value::Number(n) => {
+                self.u8(TAG_NUMBER);
+                self.bytes.extend_from_slice(&n.to_le_bytes());
+            }
+            Value::Object(obj) => match self.heap.get(obj) {
+                Obj::String(s) => {
+                    self.u8(TAG_STRING);
+                    self.string(s);
+                }
+                Obj::Function(f) => {
+                    self.u8(TAG_FUNCTION);
+                    self.function(f);
+                }
+                other => panic!("cannot serialize constant {:?}", other),
+            },
+        }
+    }
+
+    fn string(&mut self, s: &str) {
+        self.u32(s.len());
+        self.bytes.extend_from_slice(s.as_bytes());
+    }
+
+    fn u8(&mut self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
+    fn u32(&mut self, n: usize) {
+        self.bytes.extend_from_slice(&(n as u32).to_le_bytes());
+    }
+}
+
+struct Reader<'bytes, 'heap> {
+    heap: &'heap mut Heap,
+    bytes: &'bytes [u8],
+    offset: usize,
+}
+
+impl<'bytes> Reader<'bytes, '_> {
+    fn function(&mut self) -> Result<ObjRef, LoadError> {
+        let name = match self.u8()? {
+            0 => None,
+            1 => Some(self.string()?),
+            tag => return Err(LoadError::UnknownTag(tag)),
+        };
+        let mut function = Function::new(name);
+        function.arity = self.u8()? as usize;
+        function.upvalue_count = self.u32()?;
+
+        let code_len = self.u32()?;
+        let code = self.take(code_len)?.to_vec();
+        let mut lines = Vec::with_capacity(code_len);
+        for _ in 0..code_len {
+            lines.push(self.u32()?);
+        }
+
+        let constant_count = self.u32()?;
+        let mut constants = Vec::new();
+        for _ in 0..constant_count {
+            constants.push(self.value()?);
+        }
+
+        function.chunk = Chunk {
+            code,
+            constants,
+            lines,
+            spans: Vec::new(),
+        };
+        validate(&function, self.heap)?;
+        Ok(self.heap.alloc(Obj::Function(function)))
+    }
+
+    fn value(&mut self) -> Result<Value, LoadError> {
+        Ok(match self.u8()? {
+            TAG_NIL => Value::Nil,
+            TAG_FALSE => Value::Boolean(false),
+            TAG_TRUE => Value::Boolean(true),
+            TAG_NUMBER => Value::Number(f64::from_le_bytes(self.take(8)?.try_into().unwrap())),
+            TAG_STRING => {
+                let s = self.string()?;
+                Value::Object(self.heap.intern(&s))
+            }
+            TAG_FUNCTION => Value::Object(self.function()?),
+            tag => return Err(LoadError::UnknownTag(tag)),
+        })
+    }
+
+    fn string(&mut self) -> Result<String, LoadError> {
+        let len = self.u32()?;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| LoadError::InvalidUtf8)
+    }
+
+    fn u8(&mut self) -> Result<u8, LoadError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<usize, LoadError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()) as usize)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'bytes [u8], LoadError> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(LoadError::UnexpectedEof)?;
+        let bytes = &self.bytes[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+}
+
+/// Checks that every instruction in `function` is well formed and that every
+/// path through the code keeps the stack in shape, so that the VM never reads
+/// past the end of the code, the constant pool, the stack or the closure's
+/// upvalues, and never finds a constant of the wrong kind.
+fn validate(function: &Function, heap: &Heap) -> Result<(), LoadError> {
+    let starts = decode(function, heap)?;
+    check_stack(function, &starts)
+}
+
+/// Walks the code in order, checking each instruction's operands. Returns
+/// which offsets begin an instruction.
+fn decode(function: &Function, heap: &Heap) -> Result<Vec<bool>, LoadError> {
+    let chunk = &function.chunk;
+    let code = &chunk.code;
+    let mut starts = vec![false; code.len()];
+    let mut offset = 0;
+    while offset < code.len() {
+        starts[offset] = true;
+        let op = OpCode::from_byte(code[offset]).ok_or(LoadError::BadOpcode(code[offset]))?;
+        let operand_bytes = op.operand_bytes();
+        if offset + operand_bytes >= code.len() {
+            return Err(LoadError::TruncatedInstruction(offset));
+        }
+
+        match op {
+            OpCode::Constant if code[offset + 1] as usize >= chunk.constants.len() => {
+                return Err(LoadError::BadConstant(offset));
+            }
+            // The VM reads these operands as names.
+            OpCode::GetGlobal
+            | OpCode::DefineGlobal
+            | OpCode::SetGlobal
+            | OpCode::GetProperty
+            | OpCode::SetProperty
+            | OpCode::GetSuper
+            | OpCode::Class
+            | OpCode::Method => match constant_object(chunk, heap, offset)? {
+                Obj::String(_) => {}
+                _ => return Err(LoadError::WrongConstantKind(offset)),
+            },
+            OpCode::Closure => {
+                let upvalue_count = match constant_object(chunk, heap, offset)? {
+                    Obj::Function(nested) => nested.upvalue_count,
+                    _ => return Err(LoadError::WrongConstantKind(offset)),
+                };
+
+                let operands = &code[offset + 2..];
+                if operands.len() < upvalue_count * 2 {
+                    return Err(LoadError::TruncatedInstruction(offset));
+                }
+                for pair in operands[..upvalue_count * 2].chunks(2) {
+                    let (is_local, index) = (pair[0], pair[1] as usize);
+                    if is_local > 1 || (is_local == 0 && index >= function.upvalue_count) {
+                        return Err(LoadError::BadUpvalue(offset));
+                    }
+                }
+                offset += upvalue_count * 2;
+            }
+            OpCode::GetUpvalue | OpCode::SetUpvalue
+                if code[offset + 1] as usize >= function.upvalue_count =>
+            {
+                return Err(LoadError::BadUpvalue(offset));
+            }
+            _ => {}
+        }
+
+        offset += 1 + operand_bytes;
+    }
+    Ok(starts)
+}
+
+/// The object named by the constant operand of the instruction at `offset`.
+fn constant_object<'heap>(
+    chunk: &Chunk,
+    heap: &'heap Heap,
+    offset: usize,
+) -> Result<&'heap Obj, LoadError> {
+    match chunk.constants.get(chunk.code[offset + 1] as usize) {
+        Some(Value::Object(obj)) => Ok(heap.get(*obj)),
+        Some(_) => Err(LoadError::WrongConstantKind(offset)),
+        None => Err(LoadError::BadConstant(offset)),
+    }
+}
+
+/// The shape of the stack on entry to an instruction: how many slots the
+/// current call frame has, and which of them a closure has captured.
+#[derive(Clone, PartialEq)]
+struct StackShape {
+    depth: usize,
+    captured: BTreeSet<usize>,
+}
+
+/// Follows every path through the code from its start, tracking the shape
+/// of the stack. Every instruction must find the operands it pops, locals
+/// must be inside the frame, captured slots must be closed before they are
+/// discarded, jumps must land on an instruction, paths that meet must agree
+/// on the shape of the stack and no path may run off the end of the code.
+fn check_stack(function: &Function, starts: &[bool]) -> Result<(), LoadError> {
+    let code = &function.chunk.code;
+    if code.is_empty() {
+        return Err(LoadError::MissingReturn);
+    }
+
+    let mut shapes: Vec<Option<StackShape>> = vec![None; code.len()];
+    // Slot zero holds the closure or receiver and the arguments follow it.
+    shapes[0] = Some(StackShape {
+        depth: function.arity + 1,
+        captured: BTreeSet::new(),
+    });
+    let mut pending = vec![0];
+
+    while let Some(offset) = pending.pop() {
+        let StackShape {
+            depth,
+            mut captured,
+        } = shapes[offset].clone().unwrap();
+        let op = OpCode::from_byte(code[offset]).unwrap();
+        let operand = code.get(offset + 1).copied().unwrap_or(0);
+
+        // Nothing pops the frame's slot zero, not even `Return`: it is left
+        // for the caller, or the VM when the script ends, to discard.
+        let (pops, pushes) = op.stack_effect(operand);
+        if pops >= depth {
+            return Err(LoadError::StackUnderflow(offset));
+        }
+
+        // `Closure` is followed by a variable number of upvalue operands, so
+        // find the next instruction from the decoded starts.
+        let next = (offset + 1..code.len())
+            .find(|&offset| starts[offset])
+            .unwrap_or(code.len());
+        match op {
+            OpCode::GetLocal | OpCode::SetLocal if operand as usize >= depth => {
+                return Err(LoadError::BadLocal(offset));
+            }
+            OpCode::Closure => {
+                for pair in code[offset + 2..next].chunks(2) {
+                    if pair[0] == 1 {
+                        if pair[1] as usize >= depth {
+                            return Err(LoadError::BadLocal(offset));
+                        }
+                        captured.insert(pair[1] as usize);
+                    }
+                }
+            }
+            OpCode::CloseUpvalue => {
+                captured.remove(&(depth - 1));
+            }
+            _ => {}
+        }
+
+        let new_depth = depth - pops + pushes;
+        if captured.range(new_depth..).next().is_some() {
+            return Err(LoadError::UnclosedUpvalue(offset));
+        }
+
+        let mut targets = Vec::new();
+        match op {
+            OpCode::Return => {}
+            OpCode::Jump | OpCode::JumpIfFalse | OpCode::Loop => {
+                let jump = u16::from_be_bytes([code[offset + 1], code[offset + 2]]) as usize;
+                let target = if op == OpCode::Loop {
+                    next.checked_sub(jump)
+                } else {
+                    Some(next + jump)
+                };
+                match target {
+                    Some(target) if starts.get(target) == Some(&true) => targets.push(target),
+                    _ => return Err(LoadError::BadJump(offset)),
+                }
+                if op == OpCode::JumpIfFalse {
+                    targets.push(next);
+                }
+            }
+            _ => targets.push(next),
+        }
+
+        for target in targets {
+            if target >= code.len() {
+                return Err(LoadError::MissingReturn);
+            }
+            let shape = StackShape {
+                depth: new_depth,
+                captured: captured.clone(),
+            };
+            match &shapes[target] {
+                Some(existing) if *existing != shape => {
+                    return Err(LoadError::InconsistentStack(target));
+                }
+                Some(_) => {}
+                None => {
+                    shapes[target] = Some(shape);
+                    pending.push(target);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Compiler;
+    use crate::vm::Vm;
+
+    fn compile(source: &str, heap: &mut Heap) -> ObjRef {
+        Compiler::compile(source, heap).expect("source should compile")
+    }
+
+    /// Builds the `.loxc` bytes for a hand-written script, which a real
+    /// compiler would never produce.
+    fn assemble(
+        heap: &mut Heap,
+        code: &[OpCode],
+        operands: &[(usize, u8)],
+        constants: Vec<Value>,
+    ) -> Vec<u8> {
+        let mut script = Function::new(None);
+        script.chunk.code = code.iter().map(|&op| op as u8).collect();
+        for &(offset, byte) in operands {
+            script.chunk.code[offset] = byte;
+        }
+        script.chunk.lines = vec![1; script.chunk.code.len()];
+        script.chunk.constants = constants;
+        let script = heap.alloc(Obj::Function(script));
+        serialize(heap, script)
+    }
+
+    fn load_error(code: &[OpCode], operands: &[(usize, u8)], constants: Vec<Value>) -> String {
+        let mut heap = Heap::new();
+        let bytes = assemble(&mut heap, code, operands, constants);
+        deserialize(&bytes, &mut Heap::new())
+            .expect_err("malformed code should be rejected")
+            .to_string()
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut heap = Heap::new();
+        let script = compile(
+            "fun add(a) { fun inner(b) { return a + b; } return inner; } print add(1)(2); print \"hi\";
+             class A { init(x) { this.x = x; } get() { return this.x; } }
+             class B < A { get() { return super.get() * 2; } }
+             var fns = [];
+             for (var i = 0; i < 3; i = i + 1) { var j = i; fun f() { return j; } push(fns, f); }
+             while (false or nil) { var k; { var l = k and 1; } }
+             if (!B(1).get()) print -1; else fns[0] = nil;",
+            &mut heap,
+        );
+        let bytes = serialize(&heap, script);
+
+        let mut loaded_heap = Heap::new();
+        let loaded = deserialize(&bytes, &mut loaded_heap).unwrap();
+        assert_eq!(serialize(&loaded_heap, loaded), bytes);
+    }
+
+    #[test]
+    fn round_trips_lists() {
+        let mut heap = Heap::new();
+        let script = compile(
+            "var xs = [1, [\"a\", nil]];\nxs[1][0] = xs[0];\nprint xs[1][2];",
+            &mut heap,
+        );
+        let bytes = serialize(&heap, script);
+
+        let mut vm = Vm::empty();
+        let loaded = deserialize(&bytes, &mut vm.heap).unwrap();
+        assert_eq!(serialize(&vm.heap, loaded), bytes);
+        let error = vm.interpret(loaded).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Index 2 is out of bounds for a list of length 2.\n[line 3]"
+        );
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut heap = Heap::new();
+        let script = compile("print 1;", &mut heap);
+        let mut bytes = serialize(&heap, script);
+        bytes[4..6].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+
+        assert!(matches!(
+            deserialize(&bytes, &mut Heap::new()),
+            Err(LoadError::VersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let mut heap = Heap::new();
+        let script = compile("var a = \"str\"; print a;", &mut heap);
+        let bytes = serialize(&heap, script);
+
+        for len in 0..bytes.len() {
+            assert!(deserialize(&bytes[..len], &mut Heap::new()).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_names_that_are_not_strings() {
+        use OpCode::*;
+        let message = load_error(
+            &[GetGlobal, Constant, Pop, Nil, Return],
+            &[(1, 0)],
+            vec![Value::Number(1.0)],
+        );
+        assert_eq!(
+            message,
+            "Instruction at offset 0 refers to a constant of the wrong kind."
+        );
+    }
+
+    #[test]
+    fn rejects_stack_underflow() {
+        use OpCode::*;
+        let message = load_error(&[Pop, Pop, Nil, Return], &[], vec![]);
+        assert_eq!(
+            message,
+            "Instruction at offset 0 pops more values than the stack holds."
+        );
+    }
+
+    #[test]
+    fn rejects_missing_locals() {
+        use OpCode::*;
+        let message = load_error(&[GetLocal, Constant, Return], &[(1, 3)], vec![]);
+        assert_eq!(
+            message,
+            "Instruction at offset 0 refers to a missing local slot."
+        );
+    }
+
+    #[test]
+    fn rejects_jumps_into_an_instruction() {
+        use OpCode::*;
+        let message = load_error(
+            &[Jump, Constant, Constant, Constant, Constant, Return],
+            &[(1, 0), (2, 1), (4, 0)],
+            vec![Value::Nil],
+        );
+        assert_eq!(message, "Jump at offset 0 does not land on an instruction.");
+    }
+
+    #[test]
+    fn rejects_paths_that_disagree_on_the_stack() {
+        use OpCode::*;
+        let message = load_error(
+            &[True, JumpIfFalse, Constant, Constant, Nil, Return],
+            &[(2, 0), (3, 1)],
+            vec![],
+        );
+        assert_eq!(
+            message,
+            "Paths into offset 5 disagree on the shape of the stack."
+        );
+    }
+
+    #[test]
+    fn rejects_discarding_captured_variables() {
+        use OpCode::*;
+        let mut heap = Heap::new();
+        let mut inner = Function::new(Some("inner".to_string()));
+        inner.upvalue_count = 1;
+        inner.chunk.code = vec![Nil as u8, Return as u8];
+        inner.chunk.lines = vec![1, 1];
+        let inner = heap.alloc(Obj::Function(inner));
+
+        let bytes = assemble(
+            &mut heap,
+            &[
+                Nil, Closure, Constant, Constant, Constant, Pop, Pop, Nil, Return,
+            ],
+            &[(2, 0), (3, 1), (4, 1)],
+            vec![Value::Object(inner)],
+        );
+        let error = deserialize(&bytes, &mut Heap::new()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Instruction at offset 6 discards a captured variable without closing it."
+        );
+    }
+
+    #[test]
+    fn rejects_running_off_the_end() {
+        use OpCode::*;
+        let message = load_error(&[Nil, Pop], &[], vec![]);
+        assert_eq!(message, "Function can run past the end of its code.");
+    }
+
+    #[test]
+    fn reports_methods_on_other_values() {
+        use OpCode::*;
+        let mut vm = Vm::empty();
+        let name = vm.heap.intern("m");
+        let mut method = Function::new(Some("m".to_string()));
+        method.chunk.code = vec![Nil as u8, Return as u8];
+        method.chunk.lines = vec![1, 1];
+        let method = vm.heap.alloc(Obj::Function(method));
+
+        let bytes = assemble(
+            &mut vm.heap,
+            &[Nil, Closure, Constant, Method, Constant, Pop, Nil, Return],
+            &[(2, 1), (4, 0)],
+            vec![Value::Object(name), Value::Object(method)],
+        );
+        let script = deserialize(&bytes, &mut vm.heap).unwrap();
+        let error = vm.interpret(script).unwrap_err();
+        assert_eq!(error.to_string(), "Only classes have methods.\n[line 1]");
+    }
+}
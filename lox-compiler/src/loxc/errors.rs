@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use lox::diagnostic::codes;
+use lox::diagnostic::Diagnostic;
+
+#[derive(Debug)]
+pub(crate) enum LoadError {
+    BadMagic,
+    VersionMismatch { expected: u16, found: u16 },
+    UnexpectedEof,
+    TrailingBytes,
+    UnknownTag(u8),
+    InvalidUtf8,
+    BadOpcode(u8),
+    TruncatedInstruction(usize),
+    BadConstant(usize),
+    BadJump(usize),
+    BadUpvalue(usize),
+    WrongConstantKind(usize),
+    BadLocal(usize),
+    StackUnderflow(usize),
+    UnclosedUpvalue(usize),
+    InconsistentStack(usize),
+    ScriptHasUpvalues,
+    MissingReturn,
+}
+
+impl LoadError {
+    /// The file as a whole is at fault, so the diagnostic has no location.
+    pub(crate) fn diagnostic(&self) -> Diagnostic {
+        Diagnostic::error(self.to_string()).with_code(codes::INVALID_COMPILED_FILE)
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BadMagic => write!(f, "Not a compiled Lox file."),
+            Self::VersionMismatch { expected, found } => write!(
+                f,
+                "Compiled with format version {}, but this build reads version {}. Rebuild it from source.",
+                found, expected
+            ),
+            Self::UnexpectedEof => write!(f, "Compiled file is truncated."),
+            Self::TrailingBytes => write!(f, "Unexpected data after the end of the script."),
+            Self::UnknownTag(tag) => write!(f, "Unknown tag {}.", tag),
+            Self::InvalidUtf8 => write!(f, "String is not valid UTF-8."),
+            Self::BadOpcode(byte) => write!(f, "Unknown opcode {}.", byte),
+            Self::TruncatedInstruction(offset) => {
+                write!(f, "Instruction at offset {} is missing operands.", offset)
+            }
+            Self::BadConstant(offset) => write!(
+                f,
+                "Instruction at offset {} refers to a missing constant.",
+                offset
+            ),
+            Self::BadJump(offset) => write!(
+                f,
+                "Jump at offset {} does not land on an instruction.",
+                offset
+            ),
+            Self::BadUpvalue(offset) => write!(
+                f,
+                "Instruction at offset {} refers to a missing upvalue.",
+                offset
+            ),
+            Self::WrongConstantKind(offset) => write!(
+                f,
+                "Instruction at offset {} refers to a constant of the wrong kind.",
+                offset
+            ),
+            Self::BadLocal(offset) => write!(
+                f,
+                "Instruction at offset {} refers to a missing local slot.",
+                offset
+            ),
+            Self::StackUnderflow(offset) => write!(
+                f,
+                "Instruction at offset {} pops more values than the stack holds.",
+                offset
+            ),
+            Self::UnclosedUpvalue(offset) => write!(
+                f,
+                "Instruction at offset {} discards a captured variable without closing it.",
+                offset
+            ),
+            Self::InconsistentStack(offset) => write!(
+                f,
+                "Paths into offset {} disagree on the shape of the stack.",
+                offset
+            ),
+            Self::ScriptHasUpvalues => write!(f, "The top-level script can't capture upvalues."),
+            Self::MissingReturn => write!(f, "Function can run past the end of its code."),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
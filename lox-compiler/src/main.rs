@@ -1,5 +1,163 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+
+use compiler::Compiler;
+use heap::Heap;
+use heap::ObjRef;
+use lox::diagnostic::ErrorFormat;
+use structopt::StructOpt;
+use vm::Vm;
+
+mod checker;
+mod chunk;
 mod compiler;
+mod debug;
+mod heap;
+mod loxc;
+mod object;
+mod value;
+mod vm;
+
+#[derive(StructOpt)]
+struct Opt {
+    /// Print the compiled bytecode before running it.
+    #[structopt(long)]
+    disassemble: bool,
+    /// Print the VM stack and each instruction as it executes.
+    #[structopt(long)]
+    trace_execution: bool,
+    /// Run the garbage collector before every allocation.
+    #[structopt(long)]
+    stress_gc: bool,
+    /// How much the heap may grow after a collection before the next one.
+    #[structopt(long, default_value = "2.0")]
+    gc_growth_factor: f64,
+    /// Start with no global functions, not even the standard library.
+    #[structopt(long)]
+    no_stdlib: bool,
+    /// How to print errors: `human` or `json`, one object per line.
+    #[structopt(long, global = true, default_value = "human", possible_values = &["human", "json"])]
+    error_format: ErrorFormat,
+    /// A Lox script or a compiled `.loxc` file to run.
+    script: Option<PathBuf>,
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(StructOpt)]
+enum Command {
+    /// Compile a script to a `.loxc` file without running it.
+    Build {
+        script: PathBuf,
+        /// Where to write the compiled file; defaults to the script with a
+        /// `.loxc` extension.
+        #[structopt(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Report type errors in a script without running it.
+    Check { script: PathBuf },
+}
 
 fn main() {
-    println!("Hello, world!");
+    let mut opt = Opt::from_args();
+
+    match (opt.command.take(), opt.script.take()) {
+        (Some(Command::Build { script, output }), None) => {
+            let output = output.unwrap_or_else(|| script.with_extension("loxc"));
+            build(&script, &output, opt.error_format);
+        }
+        (Some(Command::Check { script }), None) => check(&script, opt.error_format),
+        (None, Some(script)) => run(&script, &opt),
+        _ => {
+            eprintln!("Usage: lox-compiler [build|check] <script>");
+            process::exit(64);
+        }
+    }
+}
+
+fn build(script: &Path, output: &Path, format: ErrorFormat) {
+    let source = read_source(script);
+    let mut heap = Heap::new();
+    let function = compile(script, &source, &mut heap, format);
+
+    fs::write(output, loxc::serialize(&heap, function)).unwrap_or_else(|e| {
+        eprintln!("{}: {}", output.display(), e);
+        process::exit(74)
+    });
+}
+
+fn check(script: &Path, format: ErrorFormat) {
+    let source = read_source(script);
+    if let Err(errors) = checker::check(&source) {
+        let name = script.display().to_string();
+        for error in errors {
+            error.diagnostic().emit(format, &name, Some(&source));
+        }
+        process::exit(65);
+    }
+}
+
+fn run(script: &Path, opt: &Opt) {
+    let bytes = fs::read(script).unwrap_or_else(|e| {
+        eprintln!("{}: {}", script.display(), e);
+        process::exit(74)
+    });
+
+    let mut vm = if opt.no_stdlib {
+        Vm::empty()
+    } else {
+        Vm::new()
+    };
+    vm.trace_execution = opt.trace_execution;
+    vm.heap.stress = opt.stress_gc;
+    vm.heap.growth_factor = opt.gc_growth_factor;
+    // Compiled files don't include their source, so errors from them can't
+    // show it.
+    let (function, source) = if loxc::is_loxc(&bytes) {
+        let function = loxc::deserialize(&bytes, &mut vm.heap).unwrap_or_else(|error| {
+            let name = script.display().to_string();
+            error.diagnostic().emit(opt.error_format, &name, None);
+            process::exit(65)
+        });
+        (function, None)
+    } else {
+        let source = String::from_utf8(bytes).unwrap_or_else(|e| {
+            eprintln!("{}: {}", script.display(), e);
+            process::exit(65)
+        });
+        let function = compile(script, &source, &mut vm.heap, opt.error_format);
+        (function, Some(source))
+    };
+
+    if opt.disassemble {
+        debug::disassemble_function(&vm.heap, vm.heap.function(function));
+        println!();
+    }
+
+    if let Err(error) = vm.interpret(function) {
+        let name = script.display().to_string();
+        error
+            .diagnostic()
+            .emit(opt.error_format, &name, source.as_deref());
+        process::exit(70);
+    }
+}
+
+fn read_source(script: &Path) -> String {
+    fs::read_to_string(script).unwrap_or_else(|e| {
+        eprintln!("{}: {}", script.display(), e);
+        process::exit(74)
+    })
+}
+
+fn compile(script: &Path, source: &str, heap: &mut Heap, format: ErrorFormat) -> ObjRef {
+    Compiler::compile(source, heap).unwrap_or_else(|errors| {
+        let name = script.display().to_string();
+        for error in errors {
+            error.diagnostic().emit(format, &name, Some(source));
+        }
+        process::exit(65)
+    })
 }
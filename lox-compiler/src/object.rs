@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use lox::callable::Arity;
+
+use crate::chunk::Chunk;
+use crate::heap::Heap;
+use crate::heap::ObjRef;
+use crate::value::Value;
+use crate::vm::errors::RuntimeErrorKind;
+
+#[derive(Debug)]
+pub(crate) enum Obj {
+    String(String),
+    Function(Function),
+    Closure(Closure),
+    Upvalue(Upvalue),
+    Class(Class),
+    Instance(Instance),
+    BoundMethod(BoundMethod),
+    Native(Native),
+    /// Lists are shared by reference, so every value holding one sees the
+    /// same elements.
+    List(Vec<Value>),
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Function {
+    /// `None` for the implicit top-level script function.
+    pub(crate) name: Option<String>,
+    pub(crate) arity: usize,
+    pub(crate) upvalue_count: usize,
+    pub(crate) chunk: Chunk,
+}
+
+impl Function {
+    pub(crate) fn new(name: Option<String>) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+}
+
+/// A function together with the variables it captured from enclosing
+/// scopes.
+#[derive(Debug)]
+pub(crate) struct Closure {
+    pub(crate) function: ObjRef,
+    pub(crate) upvalues: Vec<ObjRef>,
+}
+
+/// A variable captured by a closure.
+///
+/// While the variable's scope is still active the upvalue points at its
+/// stack slot; once the scope ends the value moves into the upvalue itself.
+#[derive(Debug)]
+pub(crate) enum Upvalue {
+    Open(usize),
+    Closed(Value),
+}
+
+#[derive(Debug)]
+pub(crate) struct Class {
+    pub(crate) name: ObjRef,
+    pub(crate) methods: HashMap<ObjRef, Value>,
+}
+
+impl Class {
+    pub(crate) fn new(name: ObjRef) -> Self {
+        Self {
+            name,
+            methods: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Instance {
+    pub(crate) class: ObjRef,
+    pub(crate) fields: HashMap<ObjRef, Value>,
+}
+
+impl Instance {
+    pub(crate) fn new(class: ObjRef) -> Self {
+        Self {
+            class,
+            fields: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct BoundMethod {
+    pub(crate) receiver: Value,
+    pub(crate) method: ObjRef,
+}
+
+/// A function written in Rust. It may allocate from the heap, which won't
+/// collect until the VM's next allocation, after the result is on the stack.
+pub(crate) type NativeFn = fn(&mut Heap, &[Value]) -> Result<Value, RuntimeErrorKind>;
+
+#[derive(Debug)]
+pub(crate) struct Native {
+    pub(crate) name: &'static str,
+    pub(crate) arity: Arity,
+    pub(crate) function: NativeFn,
+}
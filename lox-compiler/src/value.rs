@@ -0,0 +1,15 @@
+use crate::heap::ObjRef;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Value {
+    Nil,
+    Boolean(bool),
+    Number(f64),
+    Object(ObjRef),
+}
+
+impl Value {
+    pub(crate) fn is_falsey(self) -> bool {
+        matches!(self, Value::Nil | Value::Boolean(false))
+    }
+}
@@ -0,0 +1,908 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::Write;
+
+use lox::callable::Arity;
+
+use self::errors::RuntimeError;
+use self::errors::RuntimeErrorKind;
+use crate::chunk::OpCode;
+use crate::debug;
+use crate::heap::Heap;
+use crate::heap::ObjRef;
+use crate::object::BoundMethod;
+use crate::object::Class;
+use crate::object::Closure;
+use crate::object::Instance;
+use crate::object::Native;
+use crate::object::NativeFn;
+use crate::object::Obj;
+use crate::object::Upvalue;
+use crate::value::Value;
+
+pub(crate) mod errors;
+mod stdlib;
+
+/// How deeply calls may nest. Frames and the value stack live on the heap,
+/// so this only stops runaway recursion.
+const FRAMES_MAX: usize = 1024;
+
+struct CallFrame {
+    closure: ObjRef,
+    /// The closure's function, cached to save a lookup per instruction.
+    function: ObjRef,
+    ip: usize,
+    /// Index of the frame's first stack slot.
+    slots: usize,
+}
+
+/// A stack-based virtual machine executing compiled chunks.
+pub(crate) struct Vm {
+    pub(crate) heap: Heap,
+    stack: Vec<Value>,
+    frames: Vec<CallFrame>,
+    /// Upvalues still pointing into the stack, ordered by slot.
+    open_upvalues: Vec<ObjRef>,
+    globals: HashMap<ObjRef, Value>,
+    init_string: ObjRef,
+    /// Print the stack and each instruction as it executes.
+    pub(crate) trace_execution: bool,
+    /// Where `print` statements write to.
+    pub(crate) output: Box<dyn Write>,
+}
+
+impl Vm {
+    /// A VM with the [standard library](stdlib) installed.
+    pub(crate) fn new() -> Self {
+        let mut vm = Self::empty();
+        stdlib::install(&mut vm);
+        vm
+    }
+
+    /// A VM with no globals at all.
+    pub(crate) fn empty() -> Self {
+        let mut heap = Heap::new();
+        let init_string = heap.intern("init");
+        Self {
+            heap,
+            stack: vec![],
+            frames: vec![],
+            open_upvalues: vec![],
+            globals: HashMap::new(),
+            init_string,
+            trace_execution: false,
+            output: Box::new(io::stdout()),
+        }
+    }
+
+    fn define_native(&mut self, name: &'static str, arity: Arity, function: NativeFn) {
+        let global = self.heap.intern(name);
+        let native = self.heap.alloc(Obj::Native(Native {
+            name,
+            arity,
+            function,
+        }));
+        self.globals.insert(global, Value::Object(native));
+    }
+
+    /// Runs a top-level script function to completion.
+    pub(crate) fn interpret(&mut self, function: ObjRef) -> Result<(), RuntimeError> {
+        self.push(Value::Object(function));
+        let closure = self.alloc(Obj::Closure(Closure {
+            function,
+            upvalues: vec![],
+        }));
+        self.pop();
+        self.push(Value::Object(closure));
+
+        let result = self.call(closure, 0).and_then(|()| self.run());
+        if result.is_err() {
+            self.stack.clear();
+            self.frames.clear();
+            self.open_upvalues.clear();
+        }
+        result
+    }
+
+    fn run(&mut self) -> Result<(), RuntimeError> {
+        loop {
+            if self.trace_execution {
+                self.trace_instruction();
+            }
+
+            let instruction = self.read_byte();
+            let op = OpCode::from_byte(instruction)
+                .unwrap_or_else(|| panic!("unknown opcode {}", instruction));
+            match op {
+                OpCode::Constant => {
+                    let constant = self.read_constant();
+                    self.push(constant);
+                }
+                OpCode::Nil => self.push(Value::Nil),
+                OpCode::True => self.push(Value::Boolean(true)),
+                OpCode::False => self.push(Value::Boolean(false)),
+                OpCode::Pop => {
+                    self.pop();
+                }
+                OpCode::GetLocal => {
+                    let slot = self.read_byte() as usize;
+                    let value = self.stack[self.frame().slots + slot];
+                    self.push(value);
+                }
+                OpCode::SetLocal => {
+                    let slot = self.read_byte() as usize;
+                    let slots = self.frame().slots;
+                    self.stack[slots + slot] = self.peek(0);
+                }
+                OpCode::GetGlobal => {
+                    let name = self.read_string();
+                    match self.globals.get(&name) {
+                        Some(&value) => self.push(value),
+                        None => {
+                            let name = self.heap.string(name).to_string();
+                            return Err(self.error(RuntimeErrorKind::UndefinedVariable(name)));
+                        }
+                    }
+                }
+                OpCode::DefineGlobal => {
+                    let name = self.read_string();
+                    let value = self.pop();
+                    self.globals.insert(name, value);
+                }
+                OpCode::SetGlobal => {
+                    let name = self.read_string();
+                    if !self.globals.contains_key(&name) {
+                        let name = self.heap.string(name).to_string();
+                        return Err(self.error(RuntimeErrorKind::UndefinedVariable(name)));
+                    }
+                    self.globals.insert(name, self.peek(0));
+                }
+                OpCode::GetUpvalue => {
+                    let index = self.read_byte() as usize;
+                    let upvalue = self.heap.closure(self.frame().closure).upvalues[index];
+                    let value = match *self.heap.upvalue(upvalue) {
+                        Upvalue::Open(slot) => self.stack[slot],
+                        Upvalue::Closed(value) => value,
+                    };
+                    self.push(value);
+                }
+                OpCode::SetUpvalue => {
+                    let index = self.read_byte() as usize;
+                    let upvalue = self.heap.closure(self.frame().closure).upvalues[index];
+                    let value = self.peek(0);
+                    match self.heap.upvalue_mut(upvalue) {
+                        Upvalue::Open(slot) => {
+                            let slot = *slot;
+                            self.stack[slot] = value;
+                        }
+                        Upvalue::Closed(closed) => *closed = value,
+                    }
+                }
+                OpCode::GetProperty => {
+                    let name = self.read_string();
+                    let instance = match self.peek(0) {
+                        Value::Object(obj) => match self.heap.get(obj) {
+                            Obj::Instance(instance) => instance,
+                            _ => {
+                                return Err(
+                                    self.error(RuntimeErrorKind::OnlyInstancesHaveProperties)
+                                )
+                            }
+                        },
+                        _ => return Err(self.error(RuntimeErrorKind::OnlyInstancesHaveProperties)),
+                    };
+
+                    if let Some(&value) = instance.fields.get(&name) {
+                        self.pop();
+                        self.push(value);
+                    } else {
+                        let class = instance.class;
+                        self.bind_method(class, name)?;
+                    }
+                }
+                OpCode::SetProperty => {
+                    let name = self.read_string();
+                    let instance = match self.peek(1) {
+                        Value::Object(obj) if matches!(self.heap.get(obj), Obj::Instance(_)) => obj,
+                        _ => return Err(self.error(RuntimeErrorKind::OnlyInstancesHaveFields)),
+                    };
+
+                    let value = self.pop();
+                    self.heap.instance_mut(instance).fields.insert(name, value);
+                    self.heap.resize(instance);
+                    self.pop();
+                    self.push(value);
+                }
+                OpCode::GetSuper => {
+                    let name = self.read_string();
+                    let superclass = match self.pop() {
+                        Value::Object(obj) if matches!(self.heap.get(obj), Obj::Class(_)) => obj,
+                        _ => return Err(self.error(RuntimeErrorKind::SuperclassMustBeClass)),
+                    };
+                    self.bind_method(superclass, name)?;
+                }
+                OpCode::Equal => {
+                    let b = self.pop();
+                    let a = self.pop();
+                    self.push(Value::Boolean(a == b));
+                }
+                OpCode::Greater => {
+                    let (a, b) = self.number_operands()?;
+                    self.push(Value::Boolean(a > b));
+                }
+                OpCode::Less => {
+                    let (a, b) = self.number_operands()?;
+                    self.push(Value::Boolean(a < b));
+                }
+                OpCode::Add => match (self.peek(1), self.peek(0)) {
+                    (Value::Number(a), Value::Number(b)) => {
+                        self.pop();
+                        self.pop();
+                        self.push(Value::Number(a + b));
+                    }
+                    (Value::Object(a), Value::Object(b))
+                        if matches!(self.heap.get(a), Obj::String(_))
+                            && matches!(self.heap.get(b), Obj::String(_)) =>
+                    {
+                        let result = format!("{}{}", self.heap.string(a), self.heap.string(b));
+                        let result = self.intern(&result);
+                        self.pop();
+                        self.pop();
+                        self.push(Value::Object(result));
+                    }
+                    _ => return Err(self.error(RuntimeErrorKind::OperandsMustBeNumbersOrStrings)),
+                },
+                OpCode::Subtract => {
+                    let (a, b) = self.number_operands()?;
+                    self.push(Value::Number(a - b));
+                }
+                OpCode::Multiply => {
+                    let (a, b) = self.number_operands()?;
+                    self.push(Value::Number(a * b));
+                }
+                OpCode::Divide => {
+                    let (a, b) = self.number_operands()?;
+                    self.push(Value::Number(a / b));
+                }
+                OpCode::Not => {
+                    let value = self.pop();
+                    self.push(Value::Boolean(value.is_falsey()));
+                }
+                OpCode::Negate => match self.peek(0) {
+                    Value::Number(n) => {
+                        self.pop();
+                        self.push(Value::Number(-n));
+                    }
+                    _ => return Err(self.error(RuntimeErrorKind::OperandMustBeNumber)),
+                },
+                OpCode::Print => {
+                    let value = self.pop();
+                    writeln!(self.output, "{}", self.heap.format_value(value))
+                        .expect("failed printing to output");
+                }
+                OpCode::Jump => {
+                    let offset = self.read_short();
+                    self.frame_mut().ip += offset as usize;
+                }
+                OpCode::JumpIfFalse => {
+                    let offset = self.read_short();
+                    if self.peek(0).is_falsey() {
+                        self.frame_mut().ip += offset as usize;
+                    }
+                }
+                OpCode::Loop => {
+                    let offset = self.read_short();
+                    self.frame_mut().ip -= offset as usize;
+                }
+                OpCode::Call => {
+                    let arg_count = self.read_byte() as usize;
+                    self.call_value(self.peek(arg_count), arg_count)?;
+                }
+                OpCode::Closure => {
+                    let function = match self.read_constant() {
+                        Value::Object(function) => function,
+                        other => panic!("expected function constant, found {:?}", other),
+                    };
+
+                    let upvalue_count = self.heap.function(function).upvalue_count;
+                    let mut upvalues = Vec::with_capacity(upvalue_count);
+                    for _ in 0..upvalue_count {
+                        let is_local = self.read_byte() != 0;
+                        let index = self.read_byte() as usize;
+                        let upvalue = if is_local {
+                            self.capture_upvalue(self.frame().slots + index)
+                        } else {
+                            self.heap.closure(self.frame().closure).upvalues[index]
+                        };
+                        upvalues.push(upvalue);
+                    }
+
+                    let closure = self.alloc(Obj::Closure(Closure { function, upvalues }));
+                    self.push(Value::Object(closure));
+                }
+                OpCode::CloseUpvalue => {
+                    self.close_upvalues(self.stack.len() - 1);
+                    self.pop();
+                }
+                OpCode::Return => {
+                    let result = self.pop();
+                    let frame = self.frames.pop().expect("returned with no active frame");
+                    self.close_upvalues(frame.slots);
+                    if self.frames.is_empty() {
+                        self.pop();
+                        return Ok(());
+                    }
+
+                    self.stack.truncate(frame.slots);
+                    self.push(result);
+                }
+                OpCode::Class => {
+                    let name = self.read_string();
+                    let class = self.alloc(Obj::Class(Class::new(name)));
+                    self.push(Value::Object(class));
+                }
+                OpCode::Inherit => {
+                    let superclass = match self.peek(1) {
+                        Value::Object(obj) if matches!(self.heap.get(obj), Obj::Class(_)) => obj,
+                        _ => return Err(self.error(RuntimeErrorKind::SuperclassMustBeClass)),
+                    };
+                    let subclass = match self.peek(0) {
+                        Value::Object(obj) if matches!(self.heap.get(obj), Obj::Class(_)) => obj,
+                        _ => return Err(self.error(RuntimeErrorKind::OnlyClassesHaveMethods)),
+                    };
+
+                    let methods = self.heap.class(superclass).methods.clone();
+                    self.heap.class_mut(subclass).methods.extend(methods);
+                    self.heap.resize(subclass);
+                    self.pop();
+                }
+                OpCode::Method => {
+                    let name = self.read_string();
+                    let method = match self.peek(0) {
+                        Value::Object(obj) if matches!(self.heap.get(obj), Obj::Closure(_)) => {
+                            Value::Object(obj)
+                        }
+                        _ => return Err(self.error(RuntimeErrorKind::MethodMustBeFunction)),
+                    };
+                    let class = match self.peek(1) {
+                        Value::Object(obj) if matches!(self.heap.get(obj), Obj::Class(_)) => obj,
+                        _ => return Err(self.error(RuntimeErrorKind::OnlyClassesHaveMethods)),
+                    };
+                    self.heap.class_mut(class).methods.insert(name, method);
+                    self.heap.resize(class);
+                    self.pop();
+                }
+                OpCode::BuildList => {
+                    let count = self.read_byte() as usize;
+                    let first = self.stack.len() - count;
+                    let list = self.alloc(Obj::List(self.stack[first..].to_vec()));
+                    self.stack.truncate(first);
+                    self.push(Value::Object(list));
+                }
+                OpCode::GetIndex => {
+                    let (list, index) = self.list_element(self.peek(1), self.peek(0))?;
+                    let value = self.heap.list(list)[index];
+                    self.pop();
+                    self.pop();
+                    self.push(value);
+                }
+                OpCode::SetIndex => {
+                    let (list, index) = self.list_element(self.peek(2), self.peek(1))?;
+                    let value = self.pop();
+                    self.heap.list_mut(list)[index] = value;
+                    self.pop();
+                    self.pop();
+                    self.push(value);
+                }
+            }
+        }
+    }
+
+    fn trace_instruction(&self) {
+        print!("          ");
+        for &value in &self.stack {
+            print!("[ {} ]", self.heap.format_value(value));
+        }
+        println!();
+
+        let frame = self.frame();
+        let chunk = &self.heap.function(frame.function).chunk;
+        debug::disassemble_instruction(&self.heap, chunk, frame.ip);
+    }
+
+    fn call_value(&mut self, callee: Value, arg_count: usize) -> Result<(), RuntimeError> {
+        let callee = match callee {
+            Value::Object(obj) => obj,
+            _ => return Err(self.error(RuntimeErrorKind::NotCallable)),
+        };
+
+        match self.heap.get(callee) {
+            Obj::BoundMethod(bound) => {
+                let method = bound.method;
+                let receiver_slot = self.stack.len() - arg_count - 1;
+                self.stack[receiver_slot] = bound.receiver;
+                self.call(method, arg_count)
+            }
+            Obj::Class(class) => {
+                let initializer = class.methods.get(&self.init_string).copied();
+                let instance = self.alloc(Obj::Instance(Instance::new(callee)));
+                let receiver_slot = self.stack.len() - arg_count - 1;
+                self.stack[receiver_slot] = Value::Object(instance);
+
+                match initializer {
+                    Some(Value::Object(initializer)) => self.call(initializer, arg_count),
+                    _ if arg_count != 0 => Err(self.error(RuntimeErrorKind::ArityMismatch {
+                        expected: Arity::Exactly(0),
+                        got: arg_count,
+                    })),
+                    _ => Ok(()),
+                }
+            }
+            Obj::Closure(_) => self.call(callee, arg_count),
+            Obj::Native(native) => {
+                if !native.arity.accepts(arg_count) {
+                    return Err(self.error(RuntimeErrorKind::ArityMismatch {
+                        expected: native.arity,
+                        got: arg_count,
+                    }));
+                }
+
+                let function = native.function;
+                let arguments = self.stack.len() - arg_count;
+                let result = function(&mut self.heap, &self.stack[arguments..])
+                    .map_err(|kind| self.error(kind))?;
+                self.stack.truncate(arguments - 1);
+                self.push(result);
+                Ok(())
+            }
+            _ => Err(self.error(RuntimeErrorKind::NotCallable)),
+        }
+    }
+
+    fn call(&mut self, closure: ObjRef, arg_count: usize) -> Result<(), RuntimeError> {
+        let function = self.heap.closure(closure).function;
+        let arity = self.heap.function(function).arity;
+        if arg_count != arity {
+            return Err(self.error(RuntimeErrorKind::ArityMismatch {
+                expected: Arity::Exactly(arity),
+                got: arg_count,
+            }));
+        }
+
+        if self.frames.len() == FRAMES_MAX {
+            return Err(self.error(RuntimeErrorKind::StackOverflow));
+        }
+
+        self.frames.push(CallFrame {
+            closure,
+            function,
+            ip: 0,
+            slots: self.stack.len() - arg_count - 1,
+        });
+        Ok(())
+    }
+
+    /// Returns the open upvalue for stack `slot`, creating it if no closure
+    /// has captured the slot yet.
+    fn capture_upvalue(&mut self, slot: usize) -> ObjRef {
+        let heap = &self.heap;
+        let position = self.open_upvalues.binary_search_by_key(&slot, |&upvalue| {
+            match heap.upvalue(upvalue) {
+                Upvalue::Open(slot) => *slot,
+                Upvalue::Closed(_) => unreachable!("closed upvalues are never open"),
+            }
+        });
+
+        match position {
+            Ok(index) => self.open_upvalues[index],
+            Err(index) => {
+                let upvalue = self.alloc(Obj::Upvalue(Upvalue::Open(slot)));
+                self.open_upvalues.insert(index, upvalue);
+                upvalue
+            }
+        }
+    }
+
+    /// Moves every captured variable at or above stack slot `last` off the
+    /// stack and into its upvalue.
+    fn close_upvalues(&mut self, last: usize) {
+        while let Some(&upvalue) = self.open_upvalues.last() {
+            let slot = match *self.heap.upvalue(upvalue) {
+                Upvalue::Open(slot) if slot >= last => slot,
+                _ => break,
+            };
+            *self.heap.upvalue_mut(upvalue) = Upvalue::Closed(self.stack[slot]);
+            self.open_upvalues.pop();
+        }
+    }
+
+    /// Replaces the receiver on top of the stack with its method `name`.
+    fn bind_method(&mut self, class: ObjRef, name: ObjRef) -> Result<(), RuntimeError> {
+        let method = match self.heap.class(class).methods.get(&name) {
+            Some(Value::Object(method)) => *method,
+            _ => {
+                let name = self.heap.string(name).to_string();
+                return Err(self.error(RuntimeErrorKind::UndefinedProperty(name)));
+            }
+        };
+
+        let bound = self.alloc(Obj::BoundMethod(BoundMethod {
+            receiver: self.peek(0),
+            method,
+        }));
+        self.pop();
+        self.push(Value::Object(bound));
+        Ok(())
+    }
+
+    /// Allocates `obj`, collecting garbage first if the heap asks for it.
+    fn alloc(&mut self, obj: Obj) -> ObjRef {
+        if self.heap.should_collect() {
+            self.heap.mark_references(&obj);
+            self.collect_garbage();
+        }
+        self.heap.alloc(obj)
+    }
+
+    fn intern(&mut self, s: &str) -> ObjRef {
+        if self.heap.should_collect() {
+            self.collect_garbage();
+        }
+        self.heap.intern(s)
+    }
+
+    fn collect_garbage(&mut self) {
+        for &value in &self.stack {
+            self.heap.mark_value(value);
+        }
+        for (&name, &value) in &self.globals {
+            self.heap.mark_object(name);
+            self.heap.mark_value(value);
+        }
+        for frame in &self.frames {
+            self.heap.mark_object(frame.closure);
+        }
+        for &upvalue in &self.open_upvalues {
+            self.heap.mark_object(upvalue);
+        }
+        self.heap.mark_object(self.init_string);
+        self.heap.collect();
+    }
+
+    /// Checks that `list` is a list and `index` picks out one of its
+    /// elements.
+    fn list_element(&self, list: Value, index: Value) -> Result<(ObjRef, usize), RuntimeError> {
+        let list = match list {
+            Value::Object(obj) if matches!(self.heap.get(obj), Obj::List(_)) => obj,
+            _ => return Err(self.error(RuntimeErrorKind::OnlyListsCanBeIndexed)),
+        };
+        let index =
+            list_index(index, self.heap.list(list).len()).map_err(|kind| self.error(kind))?;
+        Ok((list, index))
+    }
+
+    fn number_operands(&mut self) -> Result<(f64, f64), RuntimeError> {
+        match (self.peek(1), self.peek(0)) {
+            (Value::Number(a), Value::Number(b)) => {
+                self.pop();
+                self.pop();
+                Ok((a, b))
+            }
+            _ => Err(self.error(RuntimeErrorKind::OperandsMustBeNumbers)),
+        }
+    }
+
+    fn read_byte(&mut self) -> u8 {
+        let frame = self.frames.last_mut().expect("no active call frame");
+        let byte = self.heap.function(frame.function).chunk.code[frame.ip];
+        frame.ip += 1;
+        byte
+    }
+
+    fn read_short(&mut self) -> u16 {
+        let high = self.read_byte() as u16;
+        let low = self.read_byte() as u16;
+        (high << 8) | low
+    }
+
+    fn read_constant(&mut self) -> Value {
+        let index = self.read_byte() as usize;
+        self.heap.function(self.frame().function).chunk.constants[index]
+    }
+
+    fn read_string(&mut self) -> ObjRef {
+        match self.read_constant() {
+            Value::Object(obj) => obj,
+            other => panic!("expected string constant, found {:?}", other),
+        }
+    }
+
+    fn frame(&self) -> &CallFrame {
+        self.frames.last().expect("no active call frame")
+    }
+
+    fn frame_mut(&mut self) -> &mut CallFrame {
+        self.frames.last_mut().expect("no active call frame")
+    }
+
+    fn push(&mut self, value: Value) {
+        self.stack.push(value);
+    }
+
+    fn pop(&mut self) -> Value {
+        self.stack.pop().expect("stack underflow")
+    }
+
+    fn peek(&self, distance: usize) -> Value {
+        self.stack[self.stack.len() - 1 - distance]
+    }
+
+    fn error(&self, kind: RuntimeErrorKind) -> RuntimeError {
+        let (line, span) = match self.frames.last() {
+            Some(frame) => {
+                let chunk = &self.heap.function(frame.function).chunk;
+                let offset = frame.ip.saturating_sub(1);
+                (chunk.lines[offset], chunk.spans.get(offset).copied())
+            }
+            None => (0, None),
+        };
+        RuntimeError { kind, line, span }
+    }
+}
+
+/// Checks that `index` picks out one of `len` elements.
+fn list_index(index: Value, len: usize) -> Result<usize, RuntimeErrorKind> {
+    match index {
+        Value::Number(n) if n >= 0.0 && n.fract() == 0.0 => {
+            if n < len as f64 {
+                Ok(n as usize)
+            } else {
+                Err(RuntimeErrorKind::IndexOutOfBounds { index: n, len })
+            }
+        }
+        _ => Err(RuntimeErrorKind::IndexMustBeWholeNumber),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::compiler::Compiler;
+
+    fn run(vm: &mut Vm, source: &str) -> Result<(), RuntimeError> {
+        let function = Compiler::compile(source, &mut vm.heap).unwrap();
+        vm.interpret(function)
+    }
+
+    /// Collects everything a VM prints.
+    #[derive(Clone, Default)]
+    struct Output(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Output {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn global(vm: &mut Vm, name: &str) -> String {
+        let name = vm.heap.intern(name);
+        vm.heap.format_value(vm.globals[&name])
+    }
+
+    #[test]
+    fn runs_programs() {
+        let mut vm = Vm::empty();
+        run(
+            &mut vm,
+            "var sum = 0;
+             for (var i = 0; i < 5; i = i + 1) {
+                 if (i == 2) sum = sum + 10; else sum = sum + i;
+             }
+             var s = \"a\" + \"b\";
+             var both = true and nil;
+             var either = nil or 2;
+             fun sign(n) { if (n < 0) return -1; else if (n == 0) return 0; else return 1; }
+             var signs = sign(-5) + sign(0) * 10 + sign(3) * 100;
+             var dangling = 0;
+             if (true) if (false) dangling = 1; else dangling = 2;
+             fun fib(n) { if (n < 2) return n; return fib(n - 1) + fib(n - 2); }
+             var f = fib(10);
+             class Pair {
+                 init(a, b) { this.a = a; this.b = b; }
+                 sum() { return this.a + this.b; }
+             }
+             var p = Pair(1, 2).sum();",
+        )
+        .unwrap();
+        assert_eq!(global(&mut vm, "sum"), "18");
+        assert_eq!(global(&mut vm, "s"), "ab");
+        assert_eq!(global(&mut vm, "both"), "nil");
+        assert_eq!(global(&mut vm, "either"), "2");
+        assert_eq!(global(&mut vm, "signs"), "99");
+        assert_eq!(global(&mut vm, "dangling"), "2");
+        assert_eq!(global(&mut vm, "f"), "55");
+        assert_eq!(global(&mut vm, "p"), "3");
+    }
+
+    #[test]
+    fn closures_capture_variables() {
+        let mut vm = Vm::empty();
+        run(
+            &mut vm,
+            "fun counter() {
+                 var count = 0;
+                 fun increment() { count = count + 1; return count; }
+                 return increment;
+             }
+             var c = counter();
+             c();
+             var counted = c();
+             var other = counter()();
+
+             var get;
+             var set;
+             {
+                 var shared = \"before\";
+                 fun g() { return shared; }
+                 fun s(value) { shared = value; }
+                 get = g;
+                 set = s;
+             }
+             set(\"after\");
+             var read = get();
+
+             fun outer() {
+                 var x = \"outer\";
+                 fun middle() { fun inner() { return x; } return inner; }
+                 return middle;
+             }
+             var nested = outer()()();
+
+             var first;
+             var second;
+             for (var i = 1; i < 3; i = i + 1) {
+                 var j = i;
+                 fun f() { return j; }
+                 if (j == 1) { first = f; }
+                 if (j == 2) { second = f; }
+             }
+             var captured = first() + second() * 10;",
+        )
+        .unwrap();
+        assert_eq!(global(&mut vm, "counted"), "2");
+        assert_eq!(global(&mut vm, "other"), "1");
+        assert_eq!(global(&mut vm, "read"), "after");
+        assert_eq!(global(&mut vm, "nested"), "outer");
+        assert_eq!(global(&mut vm, "captured"), "21");
+    }
+
+    #[test]
+    fn recurses_up_to_the_frame_limit() {
+        let mut vm = Vm::empty();
+        run(
+            &mut vm,
+            "fun depth(n) { if (n == 0) return 0; return depth(n - 1) + 1; }
+             var deep = depth(1020);
+             class Node {
+                 init(next) { this.next = next; }
+                 length() { if (this.next == nil) return 1; return this.next.length() + 1; }
+             }
+             class Counted < Node {
+                 length() { return super.length(); }
+             }
+             var list = nil;
+             for (var i = 0; i < 40; i = i + 1) { list = Counted(list); }
+             var length = list.length();",
+        )
+        .unwrap();
+        assert_eq!(global(&mut vm, "deep"), "1020");
+        assert_eq!(global(&mut vm, "length"), "40");
+
+        let error = run(&mut vm, "fun f() { f(); }\nf();").unwrap_err();
+        assert_eq!(error.to_string(), "Stack overflow.\n[line 1]");
+    }
+
+    #[test]
+    fn collects_garbage_while_running() {
+        let source = "fun counter() {
+                 var count = 0;
+                 fun increment() { count = count + 1; return count; }
+                 return increment;
+             }
+             class Shape {
+                 init(name) { this.name = name; }
+                 describe() { return this.name + \" shape\"; }
+             }
+             class Square < Shape {
+                 init(side) { super.init(\"square\"); this.side = side; }
+                 describe() { return super.describe() + \" of side \" + str(this.side); }
+             }
+             var shapes = [];
+             var c = counter();
+             for (var i = 0; i < 20; i = i + 1) {
+                 push(shapes, Square(c()));
+                 var garbage = [\"a\" + str(i), [i]];
+             }
+             print len(shapes);
+             print shapes[19].describe();
+             print c();
+             print shapes[0];
+             {
+                 var s = \"x\";
+                 fun grow() { s = s + \"y\"; }
+                 grow();
+                 grow();
+                 print s;
+             }";
+
+        let run_with = |stress| {
+            let output = Output::default();
+            let mut vm = Vm::new();
+            vm.heap.stress = stress;
+            vm.output = Box::new(output.clone());
+            run(&mut vm, source).unwrap();
+            let printed = output.0.borrow().clone();
+            String::from_utf8(printed).unwrap()
+        };
+
+        let normal = run_with(false);
+        assert_eq!(
+            normal,
+            "20\nsquare shape of side 20\n21\nSquare instance\nxyy\n"
+        );
+        assert_eq!(run_with(true), normal);
+    }
+
+    #[test]
+    fn reports_runtime_errors() {
+        let mut vm = Vm::empty();
+        let error = run(&mut vm, "var a = 1;\nprint a - \"b\";").unwrap_err();
+        assert_eq!(error.to_string(), "Operands must be numbers.\n[line 2]");
+        let error = run(&mut vm, "fun f(a) {}\nf();").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Expected 1 arguments but got 0.\n[line 2]"
+        );
+        let error = run(&mut vm, "print missing;").unwrap_err();
+        assert_eq!(error.to_string(), "Undefined variable 'missing'.\n[line 1]");
+
+        // The VM is left ready to run more code.
+        run(&mut vm, "var b = a + 1;").unwrap();
+        assert_eq!(global(&mut vm, "b"), "2");
+    }
+
+    #[test]
+    fn points_runtime_errors_at_their_source() {
+        let mut vm = Vm::empty();
+        let source = "var xs = [1];\nprint xs[-1];";
+        let span = run(&mut vm, source).unwrap_err().span.unwrap();
+        assert_eq!(&source[span.start..span.end], "]");
+
+        let source = "var xs = [1];\nxs[5] = 1;";
+        let span = run(&mut vm, source).unwrap_err().span.unwrap();
+        assert_eq!(&source[span.start..span.end], "]");
+
+        let source = "fun f() {}\nprint 1 + f(1);";
+        let span = run(&mut vm, source).unwrap_err().span.unwrap();
+        assert_eq!(&source[span.start..span.end], ")");
+    }
+
+    #[test]
+    fn prints_lists_that_contain_themselves() {
+        let mut vm = Vm::empty();
+        run(
+            &mut vm,
+            "var xs = [1, 2]; xs[1] = xs; var a = [1]; var twice = [a, a];",
+        )
+        .unwrap();
+        assert_eq!(global(&mut vm, "xs"), "[1, [...]]");
+        assert_eq!(global(&mut vm, "twice"), "[[1], [1]]");
+    }
+}
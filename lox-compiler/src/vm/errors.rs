@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use lox::callable::Arity;
+use lox::diagnostic::codes;
+use lox::diagnostic::Diagnostic;
+use lox_generated::span::Span;
+
+#[derive(Debug)]
+pub(crate) struct RuntimeError {
+    pub(crate) kind: RuntimeErrorKind,
+    pub(crate) line: usize,
+    /// The source of the failing instruction, unless it was loaded from a
+    /// `.loxc` file.
+    pub(crate) span: Option<Span>,
+}
+
+#[derive(Debug)]
+pub(crate) enum RuntimeErrorKind {
+    UndefinedVariable(String),
+    UndefinedProperty(String),
+    OperandMustBeNumber,
+    OperandsMustBeNumbers,
+    OperandsMustBeNumbersOrStrings,
+    NotCallable,
+    ArityMismatch {
+        expected: Arity,
+        got: usize,
+    },
+    /// A native function given the wrong type of value as its `index`th
+    /// argument, counting from zero.
+    ArgumentType {
+        index: usize,
+        expected: &'static str,
+    },
+    StackOverflow,
+    OnlyListsCanBeIndexed,
+    IndexMustBeWholeNumber,
+    IndexOutOfBounds {
+        index: f64,
+        len: usize,
+    },
+    PopFromEmptyList,
+    SliceEndsBeforeStart,
+    UnsortableList,
+    OnlyInstancesHaveProperties,
+    OnlyInstancesHaveFields,
+    SuperclassMustBeClass,
+    /// Only reachable from a hand-built `.loxc` file; compiled source always
+    /// defines methods on the class it just created.
+    OnlyClassesHaveMethods,
+    MethodMustBeFunction,
+}
+
+impl RuntimeError {
+    /// Points at the failing instruction's source, or at its whole line when
+    /// only the line is known.
+    pub(crate) fn diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::error(self.kind.to_string()).with_code(self.kind.code());
+        let diagnostic = match self.span {
+            Some(span) => diagnostic.with_span(span.start, span.end),
+            None => diagnostic.with_line(self.line),
+        };
+        match self.kind {
+            RuntimeErrorKind::StackOverflow => {
+                diagnostic.with_help("Check for recursion that never ends.")
+            }
+            _ => diagnostic,
+        }
+    }
+}
+
+impl RuntimeErrorKind {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::UndefinedVariable(_) => codes::UNDEFINED_VARIABLE,
+            Self::UndefinedProperty(_) => codes::UNDEFINED_PROPERTY,
+            Self::OperandMustBeNumber
+            | Self::OperandsMustBeNumbers
+            | Self::OperandsMustBeNumbersOrStrings => codes::OPERAND_TYPE,
+            Self::NotCallable => codes::NOT_CALLABLE,
+            Self::ArityMismatch { .. } => codes::ARITY_MISMATCH,
+            Self::ArgumentType { .. } | Self::UnsortableList => codes::ARGUMENT_TYPE,
+            Self::StackOverflow => codes::STACK_OVERFLOW,
+            Self::OnlyListsCanBeIndexed => codes::NOT_A_LIST,
+            Self::IndexMustBeWholeNumber => codes::OPERAND_TYPE,
+            Self::IndexOutOfBounds { .. } | Self::PopFromEmptyList | Self::SliceEndsBeforeStart => {
+                codes::INDEX_OUT_OF_BOUNDS
+            }
+            Self::OnlyInstancesHaveProperties | Self::OnlyInstancesHaveFields => {
+                codes::NOT_AN_INSTANCE
+            }
+            Self::SuperclassMustBeClass => codes::SUPERCLASS_NOT_CLASS,
+            Self::OnlyClassesHaveMethods => codes::NOT_A_CLASS,
+            Self::MethodMustBeFunction => codes::NOT_CALLABLE,
+        }
+    }
+}
+
+impl Display for RuntimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n[line {}]", self.kind, self.line)
+    }
+}
+
+impl Display for RuntimeErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UndefinedVariable(name) => write!(f, "Undefined variable '{}'.", name),
+            Self::UndefinedProperty(name) => write!(f, "Undefined property '{}'.", name),
+            Self::OperandMustBeNumber => write!(f, "Operand must be a number."),
+            Self::OperandsMustBeNumbers => write!(f, "Operands must be numbers."),
+            Self::OperandsMustBeNumbersOrStrings => {
+                write!(f, "Operands must be two numbers or two strings.")
+            }
+            Self::NotCallable => write!(f, "Can only call functions and classes."),
+            Self::ArityMismatch { expected, got } => {
+                write!(f, "Expected {} arguments but got {}.", expected, got)
+            }
+            Self::ArgumentType { index, expected } => {
+                write!(f, "Argument {} must be {}.", index + 1, expected)
+            }
+            Self::StackOverflow => write!(f, "Stack overflow."),
+            Self::OnlyListsCanBeIndexed => write!(f, "Only lists can be indexed."),
+            Self::IndexMustBeWholeNumber => write!(f, "Index must be a whole number."),
+            Self::IndexOutOfBounds { index, len } => write!(
+                f,
+                "Index {} is out of bounds for a list of length {}.",
+                index, len
+            ),
+            Self::PopFromEmptyList => write!(f, "Can't pop from an empty list."),
+            Self::SliceEndsBeforeStart => write!(f, "Slice can't end before it starts."),
+            Self::UnsortableList => {
+                write!(f, "Can only sort lists of all numbers or all strings.")
+            }
+            Self::OnlyInstancesHaveProperties => write!(f, "Only instances have properties."),
+            Self::OnlyInstancesHaveFields => write!(f, "Only instances have fields."),
+            Self::SuperclassMustBeClass => write!(f, "Superclass must be a class."),
+            Self::OnlyClassesHaveMethods => write!(f, "Only classes have methods."),
+            Self::MethodMustBeFunction => write!(f, "Methods must be functions."),
+        }
+    }
+}
+
+impl std::error::Error for RuntimeError {}
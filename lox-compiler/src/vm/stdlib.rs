@@ -0,0 +1,370 @@
+//! The native functions every [`Vm`] starts with, unless it's made with
+//! [`Vm::empty`]. They match the tree-walking interpreter's.
+
+use std::cmp::Ordering;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use lox::callable::Arity;
+use lox::stdlib::parse_number;
+
+use super::errors::RuntimeErrorKind;
+use super::list_index;
+use super::Vm;
+use crate::heap::Heap;
+use crate::heap::ObjRef;
+use crate::object::NativeFn;
+use crate::object::Obj;
+use crate::value::Value;
+
+const NATIVES: &[(&str, Arity, NativeFn)] = &[
+    ("clock", Arity::Exactly(0), clock),
+    ("type", Arity::Exactly(1), type_of),
+    ("str", Arity::Exactly(1), str),
+    ("num", Arity::Exactly(1), num),
+    ("len", Arity::Exactly(1), len),
+    ("substr", Arity::Exactly(3), substr),
+    ("upper", Arity::Exactly(1), upper),
+    ("lower", Arity::Exactly(1), lower),
+    ("floor", Arity::Exactly(1), floor),
+    ("ceil", Arity::Exactly(1), ceil),
+    ("sqrt", Arity::Exactly(1), sqrt),
+    ("abs", Arity::Exactly(1), abs),
+    ("pow", Arity::Exactly(2), pow),
+    ("min", Arity::AtLeast(1), min),
+    ("max", Arity::AtLeast(1), max),
+    ("push", Arity::AtLeast(2), push),
+    ("pop", Arity::Exactly(1), pop),
+    ("insert", Arity::Exactly(3), insert),
+    ("remove", Arity::Exactly(2), remove),
+    ("slice", Arity::Exactly(3), slice),
+    ("sort", Arity::Exactly(1), sort),
+    ("reverse", Arity::Exactly(1), reverse),
+];
+
+pub(super) fn install(vm: &mut Vm) {
+    for &(name, arity, function) in NATIVES {
+        vm.define_native(name, arity, function);
+    }
+}
+
+fn clock(_: &mut Heap, _: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs_f64())
+        .unwrap_or(0.0);
+    Ok(Value::Number(elapsed))
+}
+
+fn type_of(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let name = match arguments[0] {
+        Value::Nil => "nil",
+        Value::Boolean(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::Object(obj) => match heap.get(obj) {
+            Obj::String(_) => "string",
+            Obj::Function(_) | Obj::Closure(_) | Obj::BoundMethod(_) | Obj::Native(_) => "function",
+            Obj::Class(_) => "class",
+            Obj::Instance(_) => "instance",
+            Obj::List(_) => "list",
+            Obj::Upvalue(_) => unreachable!("upvalues are never values"),
+        },
+    };
+    Ok(Value::Object(heap.intern(name)))
+}
+
+fn str(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let s = heap.format_value(arguments[0]);
+    Ok(Value::Object(heap.intern(&s)))
+}
+
+/// Returns `nil` for strings that aren't numbers, so scripts can check.
+fn num(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    Ok(match parse_number(string(heap, arguments, 0)?) {
+        Some(n) => Value::Number(n),
+        None => Value::Nil,
+    })
+}
+
+fn len(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let len = match arguments[0] {
+        Value::Object(obj) => match heap.get(obj) {
+            Obj::String(s) => Some(s.chars().count()),
+            Obj::List(elements) => Some(elements.len()),
+            _ => None,
+        },
+        _ => None,
+    };
+    match len {
+        Some(len) => Ok(Value::Number(len as f64)),
+        None => Err(RuntimeErrorKind::ArgumentType {
+            index: 0,
+            expected: "a string or a list",
+        }),
+    }
+}
+
+/// Counts in characters, and stops early at the end of the string.
+fn substr(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let start = index(arguments, 1)?;
+    let length = index(arguments, 2)?;
+    let s = string(heap, arguments, 0)?
+        .chars()
+        .skip(start)
+        .take(length)
+        .collect::<String>();
+    Ok(Value::Object(heap.intern(&s)))
+}
+
+fn upper(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let s = string(heap, arguments, 0)?.to_uppercase();
+    Ok(Value::Object(heap.intern(&s)))
+}
+
+fn lower(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let s = string(heap, arguments, 0)?.to_lowercase();
+    Ok(Value::Object(heap.intern(&s)))
+}
+
+fn floor(_: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    Ok(Value::Number(number(arguments, 0)?.floor()))
+}
+
+fn ceil(_: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    Ok(Value::Number(number(arguments, 0)?.ceil()))
+}
+
+fn sqrt(_: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    Ok(Value::Number(number(arguments, 0)?.sqrt()))
+}
+
+fn abs(_: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    Ok(Value::Number(number(arguments, 0)?.abs()))
+}
+
+fn pow(_: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    Ok(Value::Number(
+        number(arguments, 0)?.powf(number(arguments, 1)?),
+    ))
+}
+
+fn min(_: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let mut min = f64::INFINITY;
+    for i in 0..arguments.len() {
+        min = min.min(number(arguments, i)?);
+    }
+    Ok(Value::Number(min))
+}
+
+fn max(_: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let mut max = f64::NEG_INFINITY;
+    for i in 0..arguments.len() {
+        max = max.max(number(arguments, i)?);
+    }
+    Ok(Value::Number(max))
+}
+
+fn push(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let list = list(heap, arguments, 0)?;
+    heap.list_mut(list).extend_from_slice(&arguments[1..]);
+    heap.resize(list);
+    Ok(Value::Nil)
+}
+
+fn pop(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let list = list(heap, arguments, 0)?;
+    let element = heap
+        .list_mut(list)
+        .pop()
+        .ok_or(RuntimeErrorKind::PopFromEmptyList)?;
+    heap.resize(list);
+    Ok(element)
+}
+
+/// The index may be the list's length, to add to the end.
+fn insert(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let list = list(heap, arguments, 0)?;
+    let elements = heap.list_mut(list);
+    let index = list_index(arguments[1], elements.len() + 1)?;
+    elements.insert(index, arguments[2]);
+    heap.resize(list);
+    Ok(Value::Nil)
+}
+
+fn remove(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let list = list(heap, arguments, 0)?;
+    let elements = heap.list_mut(list);
+    let index = list_index(arguments[1], elements.len())?;
+    let element = elements.remove(index);
+    heap.resize(list);
+    Ok(element)
+}
+
+/// Copies the elements from `start` up to but not including `end`.
+fn slice(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let elements = heap.list(list(heap, arguments, 0)?);
+    let start = list_index(arguments[1], elements.len() + 1)?;
+    let end = list_index(arguments[2], elements.len() + 1)?;
+    if start > end {
+        return Err(RuntimeErrorKind::SliceEndsBeforeStart);
+    }
+    let slice = elements[start..end].to_vec();
+    Ok(Value::Object(heap.alloc(Obj::List(slice))))
+}
+
+/// Sorts in place. Only numbers, or only strings, can be compared.
+fn sort(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let list = list(heap, arguments, 0)?;
+    let mut elements = heap.list(list).to_vec();
+    let all_numbers = elements.iter().all(|e| matches!(e, Value::Number(_)));
+    let all_strings = elements
+        .iter()
+        .all(|&e| matches!(e, Value::Object(obj) if matches!(heap.get(obj), Obj::String(_))));
+    if all_numbers {
+        elements.sort_by(|a, b| match (a, b) {
+            (Value::Number(a), Value::Number(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            _ => unreachable!("checked that every element is a number"),
+        });
+    } else if all_strings {
+        elements.sort_by(|&a, &b| match (a, b) {
+            (Value::Object(a), Value::Object(b)) => heap.string(a).cmp(heap.string(b)),
+            _ => unreachable!("checked that every element is a string"),
+        });
+    } else {
+        return Err(RuntimeErrorKind::UnsortableList);
+    }
+    *heap.list_mut(list) = elements;
+    Ok(Value::Nil)
+}
+
+fn reverse(heap: &mut Heap, arguments: &[Value]) -> Result<Value, RuntimeErrorKind> {
+    let list = list(heap, arguments, 0)?;
+    heap.list_mut(list).reverse();
+    Ok(Value::Nil)
+}
+
+fn number(arguments: &[Value], index: usize) -> Result<f64, RuntimeErrorKind> {
+    match arguments[index] {
+        Value::Number(n) => Ok(n),
+        _ => Err(RuntimeErrorKind::ArgumentType {
+            index,
+            expected: "a number",
+        }),
+    }
+}
+
+fn string<'h>(
+    heap: &'h Heap,
+    arguments: &[Value],
+    index: usize,
+) -> Result<&'h str, RuntimeErrorKind> {
+    if let Value::Object(obj) = arguments[index] {
+        if let Obj::String(s) = heap.get(obj) {
+            return Ok(s);
+        }
+    }
+    Err(RuntimeErrorKind::ArgumentType {
+        index,
+        expected: "a string",
+    })
+}
+
+fn list(heap: &Heap, arguments: &[Value], index: usize) -> Result<ObjRef, RuntimeErrorKind> {
+    match arguments[index] {
+        Value::Object(obj) if matches!(heap.get(obj), Obj::List(_)) => Ok(obj),
+        _ => Err(RuntimeErrorKind::ArgumentType {
+            index,
+            expected: "a list",
+        }),
+    }
+}
+
+fn index(arguments: &[Value], index: usize) -> Result<usize, RuntimeErrorKind> {
+    match arguments[index] {
+        Value::Number(n) if n >= 0.0 && n.fract() == 0.0 => Ok(n as usize),
+        _ => Err(RuntimeErrorKind::ArgumentType {
+            index,
+            expected: "a whole number",
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(heap: &mut Heap, s: &str) -> Value {
+        Value::Object(heap.intern(s))
+    }
+
+    #[test]
+    fn strings() {
+        let mut heap = Heap::new();
+        let hello = string(&mut heap, "héllo");
+        let result = substr(&mut heap, &[hello, Value::Number(1.0), Value::Number(10.0)]);
+        assert_eq!(result.unwrap(), string(&mut heap, "éllo"));
+        assert_eq!(len(&mut heap, &[hello]).unwrap(), Value::Number(5.0));
+
+        let four = string(&mut heap, "four");
+        assert_eq!(num(&mut heap, &[four]).unwrap(), Value::Nil);
+        let half = string(&mut heap, " 0.5 ");
+        assert_eq!(num(&mut heap, &[half]).unwrap(), Value::Number(0.5));
+        for input in ["inf", "NaN", "1e3", "+5"] {
+            let input = string(&mut heap, input);
+            assert_eq!(num(&mut heap, &[input]).unwrap(), Value::Nil);
+        }
+        assert_eq!(
+            str(&mut heap, &[Value::Nil]).unwrap(),
+            string(&mut heap, "nil")
+        );
+    }
+
+    #[test]
+    fn edits_lists() {
+        let mut heap = Heap::new();
+        let xs = Value::Object(heap.alloc(Obj::List(vec![Value::Number(3.0)])));
+        push(&mut heap, &[xs, Value::Number(1.0), Value::Number(2.0)]).unwrap();
+        insert(&mut heap, &[xs, Value::Number(3.0), Value::Nil]).unwrap();
+        assert_eq!(pop(&mut heap, &[xs]).unwrap(), Value::Nil);
+        sort(&mut heap, &[xs]).unwrap();
+        assert_eq!(heap.format_value(xs), "[1, 2, 3]");
+
+        let ys = slice(&mut heap, &[xs, Value::Number(1.0), Value::Number(3.0)]).unwrap();
+        reverse(&mut heap, &[ys]).unwrap();
+        assert_eq!(heap.format_value(ys), "[3, 2]");
+        assert_eq!(heap.format_value(xs), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn reports_bad_indices() {
+        let mut heap = Heap::new();
+        let xs = Value::Object(heap.alloc(Obj::List(vec![Value::Number(1.0)])));
+        let error = remove(&mut heap, &[xs, Value::Number(1.0)]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Index 1 is out of bounds for a list of length 1."
+        );
+        let a = string(&mut heap, "a");
+        push(&mut heap, &[xs, a]).unwrap();
+        let error = sort(&mut heap, &[xs]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Can only sort lists of all numbers or all strings."
+        );
+    }
+
+    #[test]
+    fn reports_argument_types() {
+        let mut heap = Heap::new();
+        let a = string(&mut heap, "a");
+        let error = max(&mut heap, &[Value::Number(1.0), a]).unwrap_err();
+        assert_eq!(error.to_string(), "Argument 2 must be a number.");
+    }
+
+    #[test]
+    fn type_names() {
+        let mut heap = Heap::new();
+        let result = type_of(&mut heap, &[Value::Boolean(true)]);
+        assert_eq!(result.unwrap(), string(&mut heap, "boolean"));
+    }
+}
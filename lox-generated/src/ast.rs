@@ -1,31 +1,62 @@
+pub use crate::span::Span;
+pub use crate::span::Spanned;
+
 #[derive(Debug, PartialEq)]
 pub struct Program<'input>(pub Vec<Declaration<'input>>);
 
+/// A name in the source, such as a variable, property or class.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Identifier<'input> {
+    pub name: &'input str,
+    pub span: Span,
+}
+
 #[derive(Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum Declaration<'input> {
     Class(ClassDeclaration<'input>),
     Function(FunctionDeclaration<'input>),
     Variable(VariableDeclaration<'input>),
     Statement(Statement<'input>),
+    /// A declaration that failed to parse. Only produced along with a syntax
+    /// error.
+    Error(Span),
+}
+
+impl Declaration<'_> {
+    pub fn span(&self) -> Span {
+        match self {
+            Declaration::Class(class_decl) => class_decl.span,
+            Declaration::Function(fun_decl) => fun_decl.1,
+            Declaration::Variable(var_decl) => var_decl.span,
+            Declaration::Statement(stmt) => stmt.span(),
+            Declaration::Error(span) => *span,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
 pub struct ClassDeclaration<'input> {
-    pub class_name: &'input str,
-    pub base: Option<&'input str>,
+    pub class_name: Identifier<'input>,
+    pub base: Option<Identifier<'input>>,
     pub members: Vec<Function<'input>>,
+    pub span: Span,
 }
 
+/// A function declared with `fun`; the span includes the keyword.
 #[derive(Debug, PartialEq)]
-pub struct FunctionDeclaration<'input>(pub Function<'input>);
+pub struct FunctionDeclaration<'input>(pub Function<'input>, pub Span);
 
 #[derive(Debug, PartialEq)]
 pub struct VariableDeclaration<'input> {
-    pub name: &'input str,
+    pub name: Identifier<'input>,
+    pub annotation: Option<TypeAnnotation<'input>>,
     pub initializer: Option<Expression<'input>>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum Statement<'input> {
     Expression(ExprStatement<'input>),
     For(ForStatement<'input>),
@@ -36,8 +67,22 @@ pub enum Statement<'input> {
     Block(Block<'input>),
 }
 
+impl Statement<'_> {
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::Expression(expr_stmt) => expr_stmt.1,
+            Statement::For(for_stmt) => for_stmt.span,
+            Statement::If(if_stmt) => if_stmt.span,
+            Statement::Print(print_stmt) => print_stmt.1,
+            Statement::Return(return_stmt) => return_stmt.1,
+            Statement::While(while_stmt) => while_stmt.span,
+            Statement::Block(block) => block.1,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
-pub struct ExprStatement<'input>(pub Expression<'input>);
+pub struct ExprStatement<'input>(pub Expression<'input>, pub Span);
 
 #[derive(Debug, PartialEq)]
 pub struct ForStatement<'input> {
@@ -45,9 +90,11 @@ pub struct ForStatement<'input> {
     pub condition: Option<Expression<'input>>,
     pub increment: Option<Expression<'input>>,
     pub body: Box<Statement<'input>>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum ForInitializer<'input> {
     Declaration(VariableDeclaration<'input>),
     Expression(Box<ExprStatement<'input>>),
@@ -58,54 +105,87 @@ pub enum ForInitializer<'input> {
 pub struct IfStatement<'input> {
     pub condition: Expression<'input>,
     pub body: Box<Statement<'input>>,
+    pub else_body: Option<Box<Statement<'input>>>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct PrintStatement<'input>(pub Expression<'input>);
+pub struct PrintStatement<'input>(pub Expression<'input>, pub Span);
 
 #[derive(Debug, PartialEq)]
-pub struct ReturnStatement<'input>(pub Option<Expression<'input>>);
+pub struct ReturnStatement<'input>(pub Option<Expression<'input>>, pub Span);
 
 #[derive(Debug, PartialEq)]
 pub struct WhileStatement<'input> {
     pub condition: Expression<'input>,
     pub body: Box<Statement<'input>>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
-pub struct Block<'input>(pub Vec<Declaration<'input>>);
+pub struct Block<'input>(pub Vec<Declaration<'input>>, pub Span);
 
 #[derive(Debug, PartialEq)]
 pub enum Expression<'input> {
     Assignment(Assignment<'input>),
 }
 
+impl Expression<'_> {
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::Assignment(assignment) => assignment.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Assignment<'input> {
     Assignment {
         object: Option<Call<'input>>,
-        target: &'input str,
+        target: Identifier<'input>,
+        value: Box<Assignment<'input>>,
+        span: Span,
+    },
+    /// Assigns to one element of a list, as in `xs[i] = v`.
+    Index {
+        object: Call<'input>,
+        index: Box<Expression<'input>>,
         value: Box<Assignment<'input>>,
+        span: Span,
+        /// The closing bracket of the index, where runtime errors point.
+        bracket: Span,
     },
     LogicOr(LogicOr<'input>),
 }
 
+impl Assignment<'_> {
+    pub fn span(&self) -> Span {
+        match self {
+            Assignment::Assignment { span, .. } | Assignment::Index { span, .. } => *span,
+            Assignment::LogicOr(logic_or) => logic_or.span,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct LogicOr<'input> {
     pub left: LogicAnd<'input>,
     pub rest: Vec<LogicAnd<'input>>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct LogicAnd<'input> {
     pub left: Equality<'input>,
     pub rest: Vec<Equality<'input>>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct Equality<'input> {
     pub left: Comparison<'input>,
-    pub rest: Vec<(EqualityOperator, Comparison<'input>)>,
+    pub rest: Vec<(Spanned<EqualityOperator>, Comparison<'input>)>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -117,7 +197,8 @@ pub enum EqualityOperator {
 #[derive(Debug, PartialEq)]
 pub struct Comparison<'input> {
     pub left: Term<'input>,
-    pub rest: Vec<(ComparisonOperator, Term<'input>)>,
+    pub rest: Vec<(Spanned<ComparisonOperator>, Term<'input>)>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -131,7 +212,8 @@ pub enum ComparisonOperator {
 #[derive(Debug, PartialEq)]
 pub struct Term<'input> {
     pub left: Factor<'input>,
-    pub rest: Vec<(TermOperator, Factor<'input>)>,
+    pub rest: Vec<(Spanned<TermOperator>, Factor<'input>)>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -143,7 +225,8 @@ pub enum TermOperator {
 #[derive(Debug, PartialEq)]
 pub struct Factor<'input> {
     pub left: Unary<'input>,
-    pub rest: Vec<(FactorOperator, Unary<'input>)>,
+    pub rest: Vec<(Spanned<FactorOperator>, Unary<'input>)>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -155,12 +238,22 @@ pub enum FactorOperator {
 #[derive(Debug, PartialEq)]
 pub enum Unary<'input> {
     Unary {
-        operator: UnaryOperator,
+        operator: Spanned<UnaryOperator>,
         right: Box<Unary<'input>>,
+        span: Span,
     },
     Call(Call<'input>),
 }
 
+impl Unary<'_> {
+    pub fn span(&self) -> Span {
+        match self {
+            Unary::Unary { span, .. } => *span,
+            Unary::Call(call) => call.span,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum UnaryOperator {
     LogicNot,
@@ -171,54 +264,108 @@ pub enum UnaryOperator {
 pub struct Call<'input> {
     pub target: Primary<'input>,
     pub rhs: Vec<CallRhs<'input>>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum CallRhs<'input> {
-    Call(Arguments<'input>),
-    Member(&'input str),
+    /// The span covers the parentheses and the arguments between them.
+    Call(Option<Arguments<'input>>, Span),
+    Member(Identifier<'input>),
+    /// The span covers the brackets and the index between them.
+    Index(Box<Expression<'input>>, Span),
 }
 
 #[derive(Debug, PartialEq)]
 pub enum Primary<'input> {
-    True,
-    False,
-    Nil,
-    This,
-    Number(&'input str),
-    String(&'input str),
-    Identifier(&'input str),
-    Parenthesized(Box<Expression<'input>>),
-    SuperMember(&'input str),
+    True(Span),
+    False(Span),
+    Nil(Span),
+    This(Span),
+    Number(Spanned<&'input str>),
+    String(Spanned<&'input str>),
+    Identifier(Identifier<'input>),
+    Parenthesized(Box<Expression<'input>>, Span),
+    /// The span covers the whole `super.method`.
+    SuperMember(Identifier<'input>, Span),
+    List(Vec<Expression<'input>>, Span),
+}
+
+impl Primary<'_> {
+    pub fn span(&self) -> Span {
+        match self {
+            Primary::True(span)
+            | Primary::False(span)
+            | Primary::Nil(span)
+            | Primary::This(span)
+            | Primary::Parenthesized(_, span)
+            | Primary::SuperMember(_, span)
+            | Primary::List(_, span) => *span,
+            Primary::Number(n) => n.span,
+            Primary::String(s) => s.span,
+            Primary::Identifier(i) => i.span,
+        }
+    }
 }
 
 impl<'input> Clone for Primary<'input> {
     fn clone(&self) -> Self {
         match self {
-            Primary::True => Primary::True,
-            Primary::False => Primary::False,
-            Primary::Nil => Primary::Nil,
-            Primary::This => Primary::This,
-            Primary::Number(n) => Primary::Number(n),
-            Primary::String(s) => Primary::String(s),
-            Primary::Identifier(i) => Primary::Identifier(i),
-            Primary::Parenthesized(_) => panic!("cannot clone parenthesized expression!"),
-            Primary::SuperMember(m) => Primary::SuperMember(m),
+            Primary::True(span) => Primary::True(*span),
+            Primary::False(span) => Primary::False(*span),
+            Primary::Nil(span) => Primary::Nil(*span),
+            Primary::This(span) => Primary::This(*span),
+            Primary::Number(n) => Primary::Number(*n),
+            Primary::String(s) => Primary::String(*s),
+            Primary::Identifier(i) => Primary::Identifier(*i),
+            Primary::Parenthesized(..) => panic!("cannot clone parenthesized expression!"),
+            Primary::SuperMember(m, span) => Primary::SuperMember(*m, *span),
+            Primary::List(..) => panic!("cannot clone list expression!"),
         }
     }
 }
 
 #[derive(Debug, PartialEq)]
 pub struct Function<'input> {
-    pub name: &'input str,
+    pub name: Identifier<'input>,
     pub parameters: Option<Parameters<'input>>,
+    pub return_type: Option<TypeAnnotation<'input>>,
     pub body: Block<'input>,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct Parameters<'input> {
-    pub first: &'input str,
-    pub rest: Vec<&'input str>,
+    pub first: Parameter<'input>,
+    pub rest: Vec<Parameter<'input>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Parameter<'input> {
+    pub name: Identifier<'input>,
+    pub annotation: Option<TypeAnnotation<'input>>,
+}
+
+/// An optional `: type` annotation. The interpreters ignore these; only the
+/// type checker reads them.
+#[derive(Debug, PartialEq)]
+pub enum TypeAnnotation<'input> {
+    /// `number`, `string`, `boolean`, `nil`, `any` or a class name.
+    Named(Identifier<'input>),
+    Function {
+        parameters: Vec<TypeAnnotation<'input>>,
+        returns: Box<TypeAnnotation<'input>>,
+        span: Span,
+    },
+}
+
+impl TypeAnnotation<'_> {
+    pub fn span(&self) -> Span {
+        match self {
+            TypeAnnotation::Named(name) => name.span,
+            TypeAnnotation::Function { span, .. } => *span,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
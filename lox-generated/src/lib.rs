@@ -1,157 +1,369 @@
 pub mod ast;
+#[allow(clippy::all)]
+#[rustfmt::skip]
 pub mod lox;
+pub mod span;
+
+use lalrpop_util::lexer::Token;
+
+use crate::ast::Program;
+use crate::lox::ProgramParser;
+
+pub type ParseError<'input> = lalrpop_util::ParseError<usize, Token<'input>, &'static str>;
+
+/// Parses `source`, recovering at statement boundaries so that every syntax
+/// error is reported at once.
+///
+/// The program holds whatever could be parsed, with
+/// [`Declaration::Error`](ast::Declaration::Error) in place of the rest. It
+/// is `None` if the parser could not recover, such as at an unexpected end of
+/// input.
+pub fn parse(source: &str) -> (Option<Program<'_>>, Vec<ParseError<'_>>) {
+    let mut recovered = vec![];
+    let result = ProgramParser::new().parse(&mut recovered, source);
+    let mut errors = recovered
+        .into_iter()
+        .map(|recovery| recovery.error)
+        .collect::<Vec<_>>();
+    match result {
+        Ok(program) => (Some(program), errors),
+        Err(error) => {
+            errors.push(error);
+            (None, errors)
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use crate::ast::*;
     use crate::lox::ProgramParser;
+    use crate::ParseError;
+
+    fn ident(name: &str, start: usize) -> Identifier<'_> {
+        Identifier {
+            name,
+            span: Span::new(start, start + name.len()),
+        }
+    }
+
+    fn factor(primary: Primary<'_>) -> Factor<'_> {
+        let span = primary.span();
+        Factor {
+            left: Unary::Call(Call {
+                target: primary,
+                rhs: vec![],
+                span,
+            }),
+            rest: vec![],
+            span,
+        }
+    }
+
+    /// Wraps `term` in the chain of single-operand nodes above it.
+    fn expression(term: Term<'_>) -> Expression<'_> {
+        let span = term.span;
+        Expression::Assignment(Assignment::LogicOr(LogicOr {
+            left: LogicAnd {
+                left: Equality {
+                    left: Comparison {
+                        left: term,
+                        rest: vec![],
+                        span,
+                    },
+                    rest: vec![],
+                    span,
+                },
+                rest: vec![],
+                span,
+            },
+            rest: vec![],
+            span,
+        }))
+    }
+
+    fn number(text: &str, start: usize) -> Expression<'_> {
+        let primary = Primary::Number(Spanned {
+            node: text,
+            span: Span::new(start, start + text.len()),
+        });
+        let span = primary.span();
+        expression(Term {
+            left: factor(primary),
+            rest: vec![],
+            span,
+        })
+    }
+
     #[test]
     fn empty_program() {
-        let program = ProgramParser::new().parse("").unwrap();
+        let program = ProgramParser::new().parse(&mut vec![], "").unwrap();
         assert!(program.0.is_empty());
     }
 
     #[test]
     fn var_decl() {
-        let program = ProgramParser::new().parse("var x;").unwrap();
+        let program = ProgramParser::new().parse(&mut vec![], "var x;").unwrap();
         assert_eq!(
             program,
             Program(vec![Declaration::Variable(VariableDeclaration {
-                name: "x",
-                initializer: None
+                name: ident("x", 4),
+                annotation: None,
+                initializer: None,
+                span: Span::new(0, 6),
             })])
         )
     }
 
     #[test]
     fn var_decl_with_initializer() {
-        let program = ProgramParser::new().parse("var x = 3;").unwrap();
+        let program = ProgramParser::new()
+            .parse(&mut vec![], "var x = 3;")
+            .unwrap();
         assert_eq!(
             program,
             Program(vec![Declaration::Variable(VariableDeclaration {
-                name: "x",
-                initializer: Some(Expression::Assignment(Assignment::LogicOr(LogicOr {
-                    left: LogicAnd {
-                        left: Equality {
-                            left: Comparison {
-                                left: Term {
-                                    left: Factor {
-                                        left: Unary::Call(Call {
-                                            target: Primary::Number("3"),
-                                            rhs: vec![]
-                                        }),
-                                        rest: vec![]
-                                    },
-                                    rest: vec![]
-                                },
-                                rest: vec![]
-                            },
-                            rest: vec![]
-                        },
-                        rest: vec![]
-                    },
-                    rest: vec![]
-                },)))
+                name: ident("x", 4),
+                annotation: None,
+                initializer: Some(number("3", 8)),
+                span: Span::new(0, 10),
+            })])
+        )
+    }
+
+    #[test]
+    fn decimal_numbers() {
+        let program = ProgramParser::new()
+            .parse(&mut vec![], "var x = 1.25;")
+            .unwrap();
+        assert_eq!(
+            program,
+            Program(vec![Declaration::Variable(VariableDeclaration {
+                name: ident("x", 4),
+                annotation: None,
+                initializer: Some(number("1.25", 8)),
+                span: Span::new(0, 13),
             })])
         )
     }
 
     #[test]
     fn fun_decl() {
-        let program = ProgramParser::new().parse("fun test() { }").unwrap();
+        let program = ProgramParser::new()
+            .parse(&mut vec![], "fun test() { }")
+            .unwrap();
         assert_eq!(
             program,
-            Program(vec![Declaration::Function(FunctionDeclaration(Function {
-                name: "test",
-                parameters: None,
-                body: Block(vec![]),
-            }))])
+            Program(vec![Declaration::Function(FunctionDeclaration(
+                Function {
+                    name: ident("test", 4),
+                    parameters: None,
+                    return_type: None,
+                    body: Block(vec![], Span::new(11, 14)),
+                    span: Span::new(4, 14),
+                },
+                Span::new(0, 14),
+            ))])
         )
     }
 
     #[test]
     fn class_decl() {
-        let program = ProgramParser::new().parse("class Test < Base { }").unwrap();
+        let program = ProgramParser::new()
+            .parse(&mut vec![], "class Test < Base { }")
+            .unwrap();
         assert_eq!(
             program,
             Program(vec![Declaration::Class(ClassDeclaration {
-                class_name: "Test",
-                base: Some("Base"),
-                members: vec![]
+                class_name: ident("Test", 6),
+                base: Some(ident("Base", 13)),
+                members: vec![],
+                span: Span::new(0, 21),
             })])
         )
     }
 
     #[test]
     fn top_level_statement() {
-        let program = ProgramParser::new().parse("3;").unwrap();
+        let program = ProgramParser::new().parse(&mut vec![], "3;").unwrap();
         assert_eq!(
             program,
             Program(vec![Declaration::Statement(Statement::Expression(
-                ExprStatement(Expression::Assignment(Assignment::LogicOr(LogicOr {
-                    left: LogicAnd {
-                        left: Equality {
-                            left: Comparison {
-                                left: Term {
-                                    left: Factor {
-                                        left: Unary::Call(Call {
-                                            target: Primary::Number("3"),
-                                            rhs: vec![],
-                                        }),
-                                        rest: vec![]
-                                    },
-                                    rest: vec![]
-                                },
-                                rest: vec![]
-                            },
-                            rest: vec![]
-                        },
-                        rest: vec![]
-                    },
-                    rest: vec![]
-                })))
+                ExprStatement(number("3", 0), Span::new(0, 2))
             ))])
         )
     }
 
     #[test]
     fn addition_expr() {
-        let program = ProgramParser::new().parse("3 + 4;").unwrap();
+        let program = ProgramParser::new().parse(&mut vec![], "3 + 4;").unwrap();
+        let three = Primary::Number(Spanned {
+            node: "3",
+            span: Span::new(0, 1),
+        });
+        let four = Primary::Number(Spanned {
+            node: "4",
+            span: Span::new(4, 5),
+        });
         assert_eq!(
             program.0[0],
             Declaration::Statement(Statement::Expression(ExprStatement(
-                Expression::Assignment(Assignment::LogicOr(LogicOr {
-                    rest: vec![],
-                    left: LogicAnd {
-                        rest: vec![],
-                        left: Equality {
-                            rest: vec![],
-                            left: Comparison {
-                                rest: vec![],
-                                left: Term {
-                                    rest: vec![(
-                                        TermOperator::Plus,
-                                        Factor {
-                                            rest: vec![],
-                                            left: Unary::Call(Call {
-                                                rhs: vec![],
-                                                target: Primary::Number("4")
-                                            })
-                                        }
-                                    )],
-                                    left: Factor {
-                                        rest: vec![],
-                                        left: Unary::Call(Call {
-                                            rhs: vec![],
-                                            target: Primary::Number("3")
-                                        })
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }))
+                expression(Term {
+                    left: factor(three),
+                    rest: vec![(
+                        Spanned {
+                            node: TermOperator::Plus,
+                            span: Span::new(2, 3),
+                        },
+                        factor(four),
+                    )],
+                    span: Span::new(0, 5),
+                }),
+                Span::new(0, 6),
             )))
         )
     }
+
+    #[test]
+    fn annotations() {
+        let program = ProgramParser::new()
+            .parse(
+                &mut vec![],
+                "var f: fun(number, nil): Point; fun g(a: string, b) : any { }",
+            )
+            .unwrap();
+        assert_eq!(
+            program,
+            Program(vec![
+                Declaration::Variable(VariableDeclaration {
+                    name: ident("f", 4),
+                    annotation: Some(TypeAnnotation::Function {
+                        parameters: vec![
+                            TypeAnnotation::Named(ident("number", 11)),
+                            TypeAnnotation::Named(ident("nil", 19)),
+                        ],
+                        returns: Box::new(TypeAnnotation::Named(ident("Point", 25))),
+                        span: Span::new(7, 30),
+                    }),
+                    initializer: None,
+                    span: Span::new(0, 31),
+                }),
+                Declaration::Function(FunctionDeclaration(
+                    Function {
+                        name: ident("g", 36),
+                        parameters: Some(Parameters {
+                            first: Parameter {
+                                name: ident("a", 38),
+                                annotation: Some(TypeAnnotation::Named(ident("string", 41))),
+                            },
+                            rest: vec![Parameter {
+                                name: ident("b", 49),
+                                annotation: None,
+                            }],
+                        }),
+                        return_type: Some(TypeAnnotation::Named(ident("any", 54))),
+                        body: Block(vec![], Span::new(58, 61)),
+                        span: Span::new(36, 61),
+                    },
+                    Span::new(32, 61),
+                )),
+            ])
+        )
+    }
+
+    #[test]
+    fn call_without_arguments() {
+        let program = ProgramParser::new().parse(&mut vec![], "f();").unwrap();
+        match &program.0[0] {
+            Declaration::Statement(Statement::Expression(ExprStatement(
+                Expression::Assignment(Assignment::LogicOr(logic_or)),
+                _,
+            ))) => {
+                let call = match &logic_or.left.left.left.left.left.left {
+                    Unary::Call(call) => call,
+                    other => panic!("expected call, found {:?}", other),
+                };
+                assert_eq!(call.target, Primary::Identifier(ident("f", 0)));
+                assert_eq!(call.rhs, vec![CallRhs::Call(None, Span::new(1, 3))]);
+                assert_eq!(call.span, Span::new(0, 3));
+            }
+            other => panic!("expected expression statement, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn recovers_at_statement_boundaries() {
+        let (program, errors) = crate::parse("var = 1;\nprint 2;\nprint (;\n{ 3 + ; }");
+        let program = program.unwrap();
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| match error {
+                    ParseError::UnrecognizedToken { token, .. } => token.0,
+                    other => panic!("unexpected error {:?}", other),
+                })
+                .collect::<Vec<_>>(),
+            vec![4, 25, 33]
+        );
+        assert_eq!(program.0.len(), 4);
+        assert_eq!(program.0[0], Declaration::Error(Span::new(0, 8)));
+        assert!(matches!(
+            program.0[1],
+            Declaration::Statement(Statement::Print(_))
+        ));
+
+        let (program, errors) = crate::parse("fun f() {");
+        assert!(program.is_none());
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn spans_skip_whitespace() {
+        let program = ProgramParser::new()
+            .parse(&mut vec![], "  print  1 ;\n")
+            .unwrap();
+        assert_eq!(program.0[0].span(), Span::new(2, 12));
+    }
+
+    #[test]
+    fn list_indexing() {
+        let program = ProgramParser::new()
+            .parse(&mut vec![], "xs[0] = [1, xs[1]];")
+            .unwrap();
+        let assignment = match &program.0[0] {
+            Declaration::Statement(Statement::Expression(ExprStatement(expr, _))) => expr,
+            other => panic!("unexpected declaration {:?}", other),
+        };
+        assert!(matches!(
+            assignment,
+            Expression::Assignment(Assignment::Index { span, .. }) if *span == Span::new(0, 18)
+        ));
+    }
+
+    #[test]
+    fn list_trailing_comma() {
+        let parser = ProgramParser::new();
+        // Padded to the same length, so that the spans match too.
+        assert_eq!(
+            parser.parse(&mut vec![], "[1, 2,];").unwrap(),
+            parser.parse(&mut vec![], "[1, 2 ];").unwrap()
+        );
+        assert!(!super::parse("[,];").1.is_empty());
+    }
+
+    #[test]
+    fn else_binds_to_nearest_if() {
+        let program = ProgramParser::new()
+            .parse(&mut vec![], "if (a) if (b) 1; else 2;")
+            .unwrap();
+        let outer = match &program.0[0] {
+            Declaration::Statement(Statement::If(outer)) => outer,
+            other => panic!("unexpected declaration {:?}", other),
+        };
+        assert!(outer.else_body.is_none());
+        assert!(matches!(
+            &*outer.body,
+            Statement::If(inner) if inner.else_body.is_some()
+        ));
+    }
 }
@@ -1,6 +1,7 @@
-// auto-generated: "lalrpop 0.19.5"
-// sha3: 50d74d04d18e0666aee2e1a34f6829e0f44a455cf2ea3fd90f5bedcd6e14
+// auto-generated: "lalrpop 0.19.12"
+// sha3: 46dc56bb6753315765f389faff934ff13293bbb6030ed68a31bffb55c97a8192
 use crate::ast::*;
+use lalrpop_util::ErrorRecovery;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -10,9 +11,10 @@ extern crate alloc;
 
 #[cfg_attr(rustfmt, rustfmt_skip)]
 mod __parse__Program {
-    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
 
     use crate::ast::*;
+    use lalrpop_util::ErrorRecovery;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
@@ -24,459 +26,628 @@ mod __parse__Program {
     pub(crate) enum __Symbol<'input>
      {
         Variant0(&'input str),
-        Variant1(Expression<'input>),
-        Variant2(alloc::vec::Vec<Expression<'input>>),
-        Variant3(alloc::vec::Vec<&'input str>),
-        Variant4(core::option::Option<&'input str>),
-        Variant5(core::option::Option<Expression<'input>>),
-        Variant6(Equality<'input>),
-        Variant7(alloc::vec::Vec<Equality<'input>>),
-        Variant8(LogicAnd<'input>),
-        Variant9(alloc::vec::Vec<LogicAnd<'input>>),
-        Variant10(Call<'input>),
-        Variant11(core::option::Option<Call<'input>>),
-        Variant12((ComparisonOperator, Term<'input>)),
-        Variant13(alloc::vec::Vec<(ComparisonOperator, Term<'input>)>),
-        Variant14((EqualityOperator, Comparison<'input>)),
-        Variant15(alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>),
-        Variant16((FactorOperator, Unary<'input>)),
-        Variant17(alloc::vec::Vec<(FactorOperator, Unary<'input>)>),
-        Variant18((TermOperator, Factor<'input>)),
-        Variant19(alloc::vec::Vec<(TermOperator, Factor<'input>)>),
-        Variant20(Arguments<'input>),
-        Variant21(Assignment<'input>),
-        Variant22(Box<Assignment<'input>>),
-        Variant23(Box<ExprStatement<'input>>),
-        Variant24(Box<Expression<'input>>),
-        Variant25(Box<Statement<'input>>),
-        Variant26(Box<Unary<'input>>),
-        Variant27(Block<'input>),
-        Variant28(CallRhs<'input>),
-        Variant29(alloc::vec::Vec<CallRhs<'input>>),
-        Variant30(ClassDeclaration<'input>),
-        Variant31(Comparison<'input>),
-        Variant32(ComparisonOperator),
-        Variant33(Declaration<'input>),
-        Variant34(alloc::vec::Vec<Declaration<'input>>),
-        Variant35(EqualityOperator),
-        Variant36(ExprStatement<'input>),
-        Variant37(Factor<'input>),
-        Variant38(FactorOperator),
-        Variant39(ForInitializer<'input>),
-        Variant40(ForStatement<'input>),
-        Variant41(Function<'input>),
-        Variant42(alloc::vec::Vec<Function<'input>>),
-        Variant43(FunctionDeclaration<'input>),
-        Variant44(IfStatement<'input>),
-        Variant45(LogicOr<'input>),
-        Variant46(Parameters<'input>),
-        Variant47(core::option::Option<Parameters<'input>>),
-        Variant48(Primary<'input>),
-        Variant49(PrintStatement<'input>),
-        Variant50(Program<'input>),
-        Variant51(ReturnStatement<'input>),
-        Variant52(Statement<'input>),
-        Variant53(Term<'input>),
-        Variant54(TermOperator),
-        Variant55(Unary<'input>),
-        Variant56(UnaryOperator),
-        Variant57(VariableDeclaration<'input>),
-        Variant58(WhileStatement<'input>),
+        Variant1(__lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>),
+        Variant2(Expression<'input>),
+        Variant3(alloc::vec::Vec<Expression<'input>>),
+        Variant4(Parameter<'input>),
+        Variant5(alloc::vec::Vec<Parameter<'input>>),
+        Variant6(Identifier<'input>),
+        Variant7(core::option::Option<Identifier<'input>>),
+        Variant8(core::option::Option<Expression<'input>>),
+        Variant9(Equality<'input>),
+        Variant10(alloc::vec::Vec<Equality<'input>>),
+        Variant11(LogicAnd<'input>),
+        Variant12(alloc::vec::Vec<LogicAnd<'input>>),
+        Variant13(Call<'input>),
+        Variant14(core::option::Option<Call<'input>>),
+        Variant15((Spanned<ComparisonOperator>, Term<'input>)),
+        Variant16(alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>),
+        Variant17((Spanned<EqualityOperator>, Comparison<'input>)),
+        Variant18(alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>),
+        Variant19((Spanned<FactorOperator>, Unary<'input>)),
+        Variant20(alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>),
+        Variant21((Spanned<TermOperator>, Factor<'input>)),
+        Variant22(alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>),
+        Variant23(TypeAnnotation<'input>),
+        Variant24(alloc::vec::Vec<TypeAnnotation<'input>>),
+        Variant25(usize),
+        Variant26(core::option::Option<TypeAnnotation<'input>>),
+        Variant27(Arguments<'input>),
+        Variant28(core::option::Option<Arguments<'input>>),
+        Variant29(Assignment<'input>),
+        Variant30(Box<Assignment<'input>>),
+        Variant31(Box<Statement<'input>>),
+        Variant32(Box<ExprStatement<'input>>),
+        Variant33(Box<Expression<'input>>),
+        Variant34(Box<TypeAnnotation<'input>>),
+        Variant35(Box<Unary<'input>>),
+        Variant36(Block<'input>),
+        Variant37(CallRhs<'input>),
+        Variant38(alloc::vec::Vec<CallRhs<'input>>),
+        Variant39(ClassDeclaration<'input>),
+        Variant40(Statement<'input>),
+        Variant41(Vec<Expression<'input>>),
+        Variant42(Vec<TypeAnnotation<'input>>),
+        Variant43(Comparison<'input>),
+        Variant44(ComparisonOperator),
+        Variant45(Declaration<'input>),
+        Variant46(alloc::vec::Vec<Declaration<'input>>),
+        Variant47(EqualityOperator),
+        Variant48(ExprStatement<'input>),
+        Variant49(Factor<'input>),
+        Variant50(FactorOperator),
+        Variant51(ForInitializer<'input>),
+        Variant52(ForStatement<'input>),
+        Variant53(Function<'input>),
+        Variant54(alloc::vec::Vec<Function<'input>>),
+        Variant55(FunctionDeclaration<'input>),
+        Variant56(IfStatement<'input>),
+        Variant57(LogicOr<'input>),
+        Variant58(Parameters<'input>),
+        Variant59(core::option::Option<Parameters<'input>>),
+        Variant60(Primary<'input>),
+        Variant61(PrintStatement<'input>),
+        Variant62(Program<'input>),
+        Variant63(ReturnStatement<'input>),
+        Variant64(Spanned<ComparisonOperator>),
+        Variant65(Spanned<EqualityOperator>),
+        Variant66(Spanned<FactorOperator>),
+        Variant67(Spanned<&'input str>),
+        Variant68(Spanned<TermOperator>),
+        Variant69(Spanned<UnaryOperator>),
+        Variant70(Term<'input>),
+        Variant71(TermOperator),
+        Variant72(Unary<'input>),
+        Variant73(UnaryOperator),
+        Variant74(VariableDeclaration<'input>),
+        Variant75(WhileStatement<'input>),
     }
     const __ACTION: &[i16] = &[
         // State 0
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 83, 84, 12, 85, 86, 0, 13, 14, 87, 88, 89, 90, 91, 15, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 13, 0, 117, 118, 14, 119, 120, 0, 15, 16, 121, 122, 123, 17, 124, 18, 0, 125, 126, 127, 128,
         // State 1
-        0, 95, 0, -91, 0, 0, -91, 0, 0, 0, -91, 0, 0, 0, 96, 0, 0, -91, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 130, 0, -131, 0, 0, -131, 0, 0, 0, 0, -131, 0, 0, 0, 131, 0, 0, 0, -131, -131, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 83, 84, 12, 85, 86, 0, 13, 14, 87, 88, 89, 90, 91, 15, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 13, 0, 117, 118, 14, 119, 120, 0, 15, 16, 121, 122, 123, 17, 124, 18, 0, 125, 126, 127, 128,
         // State 3
-        0, 0, 0, -118, 0, 0, -118, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -166, 0, 0, -166, 0, 0, 0, 0, -166, 0, 0, 0, 0, 0, 0, 0, -166, 21, 0, 0, 0, 0, 0, 0, 0, -166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, -147, 0, -147, 0, 100, -147, 101, 0, 0, -147, -147, -147, 0, -147, -147, -147, -147, 0, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -204, 0, -204, 0, 136, -204, 137, 0, 0, 0, -204, -204, -204, 0, -204, -204, -204, 0, -204, -204, 0, 0, 0, 0, 0, 0, 0, -204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, -120, 0, 0, -120, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -168, 0, 0, -168, 0, 0, 0, 0, -168, 0, 0, 0, 0, 0, 0, 0, -168, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, -152, 23, -152, -152, -152, -152, -152, 104, -152, -152, -152, -152, 0, -152, -152, -152, -152, 0, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -214, 27, -214, -214, -214, -214, -214, 28, -214, 0, -214, -214, -214, 0, -214, -214, -214, 29, -214, -214, 0, 0, 0, 0, 0, 0, 0, -214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, -77, 0, -77, 0, 0, -77, 0, 0, 0, -77, 105, 106, 0, -77, 107, 108, -77, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 8
-        0, -99, 0, -99, 109, -99, -99, -99, 0, 110, -99, -99, -99, 0, -99, -99, -99, -99, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -116, 0, -116, 0, 0, -116, 0, 0, 0, 0, -116, 144, 145, 0, -116, 146, 147, 0, -116, -116, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        0, -139, 0, -139, 149, -139, -139, -139, 0, 150, 0, -139, -139, -139, 0, -139, -139, -139, 0, -139, -139, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, -109, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 12
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 13
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 14
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 83, 84, 12, 85, 86, 0, 13, 14, 87, 88, 89, 90, 91, 15, 124, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 15
-        0, 95, 0, -92, 0, 0, -92, 0, 0, 0, -92, 0, 0, 0, 96, 0, 0, -92, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 16
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 17
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 13, 0, 117, 118, 14, 119, 120, 0, 15, 16, 121, 122, 123, 17, 124, 18, 161, 125, 126, 127, 128,
         // State 18
-        0, -148, 0, -148, 0, 100, -148, 101, 0, 0, -148, -148, -148, 0, -148, -148, -148, -148, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 130, 0, -132, 0, 0, -132, 0, 0, 0, 0, -132, 0, 0, 0, 131, 0, 0, 0, -132, -132, 0, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 20
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 21
-        0, -153, 23, -153, -153, -153, -153, -153, 130, -153, -153, -153, -153, 0, -153, -153, -153, -153, 0, 0, 0, 0, 0, 0, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -205, 0, -205, 0, 136, -205, 137, 0, 0, 0, -205, -205, -205, 0, -205, -205, -205, 0, -205, -205, 0, 0, 0, 0, 0, 0, 0, -205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 23
-        0, -78, 0, -78, 0, 0, -78, 0, 0, 0, -78, 105, 106, 0, -78, 107, 108, -78, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 24
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 25
-        0, -100, 0, -100, 109, -100, -100, -100, 0, 110, -100, -100, -100, 0, -100, -100, -100, -100, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -215, 27, -215, -215, -215, -215, -215, 46, -215, 0, -215, -215, -215, 0, -215, -215, -215, 47, -215, -215, 0, 0, 0, 0, 0, 0, 0, -215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 171, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 27
-        0, -152, 23, -152, -152, -152, -152, -152, 135, -152, -152, -152, -152, 0, -152, -152, -152, -152, 0, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 28
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 90, 0, 0, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 29
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, -214, 27, -214, -214, -214, -214, -214, 50, -214, 0, -214, -214, -214, 0, -214, -214, -214, 51, -214, -214, 0, 0, 0, 0, 0, 0, 0, -214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 30
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, -117, 0, -117, 0, 0, -117, 0, 0, 0, 0, -117, 144, 145, 0, -117, 146, 147, 0, -117, -117, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 83, 84, 12, 85, 86, 0, 13, 14, 87, 88, 89, 90, 91, 15, 148, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 32
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, -140, 0, -140, 149, -140, -140, -140, 0, 150, 0, -140, -140, -140, 0, -140, -140, -140, 0, -140, -140, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 33
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 34
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, -111, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 35
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 17, 0, 0, 0, 125, 126, 127, 0,
         // State 36
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 37
-        0, 0, 0, -52, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 38
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 189, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 39
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 113, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 40
-        0, -153, 23, -153, -153, -153, -153, -153, 135, -153, -153, -153, -153, 0, -153, -153, -153, -153, 0, 0, 0, 0, 0, 0, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 13, 0, 117, 118, 14, 119, 120, 0, 15, 16, 121, 122, 123, 17, 124, 18, 191, 125, 126, 127, 128,
         // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 118, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 42
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 43
-        0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 44
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 45
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 46
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 170, 118, 0, 0,
+        0, 0, 0, -67, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 48
-        80, 0, 11, 58, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, -215, 27, -215, -215, -215, -215, -215, 50, -215, 0, -215, -215, -215, 0, -215, -215, -215, 51, -215, -215, 0, 0, 0, 0, 0, 0, 0, -215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 50
-        0, 0, 0, -122, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 51
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 0, 85, 86, 0, 13, 14, 87, 88, 89, 0, 91, 15, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 52
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 0, 85, 86, 0, 13, 14, 87, 88, 89, 0, 91, 15, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 53
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 54
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 208, 125, 0, 0, 0,
         // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 181, 118, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 56
-        80, 0, 11, 61, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 86, 0, 0, 0, 87, 88, 89, 0, 0, 0, 0, 92, 93, 94,
+        0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 57
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 0, 85, 86, 0, 13, 14, 87, 88, 89, 0, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 214, 0, 215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 187, 118, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 60
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 0, 85, 86, 0, 13, 14, 87, 88, 89, 0, 91, 15, 0, 92, 93, 94,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 61
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 0, 85, 86, 0, 13, 14, 87, 88, 89, 0, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 222, 125, 0, 0, 0,
         // State 62
-        80, 0, 11, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 84, 0, 85, 86, 0, 13, 14, 87, 88, 89, 0, 91, 15, 0, 92, 93, 94,
+        115, 0, 11, 75, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 63
-        0, 0, 0, -96, 0, 0, -96, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -175, 0, 0, -175, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 64
-        -146, 0, -146, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, -146, -146, -146, -146, -146, 0, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146,
+        0, 0, 0, -176, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        -83, 0, -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, -83, -83, -83, -83, -83, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0,
         // State 66
-        -89, 0, -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, -89, -89, -89, -89, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 118, 0, 119, 120, 0, 15, 16, 121, 122, 123, 0, 124, 18, 0, 125, 126, 127, 0,
         // State 67
-        -140, 0, -140, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, -140, -140, -140, -140, -140, 0, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 68
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 118, 0, 119, 120, 0, 15, 16, 121, 122, 123, 0, 124, 18, 0, 125, 126, 127, 0,
         // State 69
-        -141, 0, -141, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, -141, -141, -141, -141, -141, 0, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 70
-        -84, 0, -84, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, -84, -84, -84, -84, -84, 0, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 71
-        -142, 0, -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, -142, -142, -142, -142, -142, 0, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 72
-        0, 0, 0, -57, 0, 0, -57, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 125, 0, 0, 0,
         // State 73
-        -143, 0, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, -143, -143, -143, -143, -143, 0, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143,
+        115, 0, 11, 82, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 74
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 118, 0, 119, 120, 0, 15, 16, 121, 122, 123, 0, 124, 18, 0, 125, 126, 127, 0,
         // State 75
-        -144, 0, -144, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, -144, -144, -144, -144, -144, 0, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 76
-        -86, 0, -86, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, -86, -86, -86, -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0,
         // State 77
-        -85, 0, -85, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, -85, -85, -85, -85, -85, 0, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0,
         // State 78
-        -145, 0, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, -145, -145, -145, -145, -145, 0, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145,
+        0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 214, 0, 215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 79
-        -154, 0, -154, 0, 0, 0, 0, -154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -154, 0, 0, 0, -154, 0, 0, 0, -154, -154, -154, 0, 0, 0, 0, -154, -154, -154,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 0, 0, 0, 120, 0, 0, 0, 121, 122, 123, 0, 0, 0, 0, 125, 126, 127, 0,
         // State 80
-        -155, 0, -155, 0, 0, 0, 0, -155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -155, 0, 0, 0, -155, 0, 0, 0, -155, -155, -155, 0, 0, 0, 0, -155, -155, -155,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 251, 125, 0, 0, 0,
         // State 81
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 118, 0, 119, 120, 0, 15, 16, 121, 122, 123, 0, 124, 18, 0, 125, 126, 127, 0,
         // State 82
-        0, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, 0, -127, -127, -127, -127, 0, 0, 0, 0, 0, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 118, 0, 119, 120, 0, 15, 16, 121, 122, 123, 0, 124, 18, 0, 125, 126, 127, 0,
         // State 83
-        0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 84
-        0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0,
         // State 85
-        0, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, 0, -128, -128, -128, -128, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 118, 0, 119, 120, 0, 15, 16, 121, 122, 123, 0, 124, 18, 0, 125, 126, 127, 0,
         // State 86
-        0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 214, 0, 215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 87
-        0, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, 0, -129, -129, -129, -129, 0, 0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        115, 0, 11, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 117, 118, 0, 119, 120, 0, 15, 16, 121, 122, 123, 0, 124, 18, 0, 125, 126, 127, 0,
         // State 88
-        0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, 0, -126, -126, -126, -126, 0, 0, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 214, 0, 215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0,
         // State 89
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0,
+        0, 0, 0, -136, 0, 0, -136, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 90
-        0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -107, 0, -107, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, -107, -107, -107, -107, -107, -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107,
         // State 91
-        0, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, 33, -132, -132, -132, -132, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -122, 0, -122, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, -122, 0, -122, -122, -122, -122, -122, 0, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122,
         // State 92
-        0, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, 0, -130, -130, -130, -130, 0, 0, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -203, 0, -203, 0, 0, 0, 0, -203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -203, 0, 0, -203, 0, -203, -203, -203, -203, -203, 0, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203,
         // State 93
-        0, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, 0, -131, -131, -131, -131, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -129, 0, -129, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, 0, 0, -129, 0, -129, -129, -129, -129, -129, 0, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129,
         // State 94
-        -93, 0, -93, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, -93, 0, 0, 0, -93, -93, -93, 0, 0, 0, 0, -93, -93, -93,
+        -101, 0, -101, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, -101, -101, -101, -101, -101, -101, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101,
         // State 95
-        -94, 0, -94, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, -94, 0, 0, 0, -94, -94, -94, 0, 0, 0, 0, -94, -94, -94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 96
-        -90, 0, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, -90, -90, -90, -90, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90,
+        -102, 0, -102, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, -102, -102, -102, -102, -102, -102, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102,
         // State 97
-        0, 0, 0, -119, 0, 0, -119, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -172, 0, -172, 0, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -172, 0, 0, -172, 0, -172, -172, -172, -172, -172, 0, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172,
         // State 98
-        -95, 0, -95, 0, 0, 0, 0, -95, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, -95, -95, -95, -95, -95, -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95,
+        -123, 0, -123, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, -123, 0, -123, -123, -123, -123, -123, 0, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123,
         // State 99
-        -150, 0, -150, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150, 0, 0, 0, -150, 0, 0, 0, -150, -150, -150, 0, 0, 0, 0, -150, -150, -150,
+        0, -186, -186, -186, -186, -186, -186, -186, -186, -186, 0, -186, -186, -186, 24, -186, -186, -186, -186, -186, -186, 0, 0, 0, 0, 0, 0, 0, -186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 100
-        -149, 0, -149, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, -149, 0, 0, 0, -149, -149, -149, 0, 0, 0, 0, -149, -149, -149,
+        -103, 0, -103, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, -103, -103, -103, -103, -103, -103, -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103,
         // State 101
-        0, 0, 0, -121, 0, 0, -121, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -171, 0, -171, 0, 0, 0, 0, -171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -171, 0, 0, -171, 0, -171, -171, -171, -171, -171, 0, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171,
         // State 102
-        0, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, 0, -71, -71, -71, -71, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -76, 0, 0, -76, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 103
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0,
+        -202, 0, -202, 0, 0, 0, 0, -202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -202, 0, 0, -202, 0, -202, -202, -202, -202, -202, 0, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202,
         // State 104
-        -81, 0, -81, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, -81, 0, 0, 0, -81, -81, -81, 0, 0, 0, 0, -81, -81, -81,
+        -104, 0, -104, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, -104, -104, -104, -104, -104, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104,
         // State 105
-        -82, 0, -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, -82, 0, 0, 0, -82, -82, -82, 0, 0, 0, 0, -82, -82, -82,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 106
-        -79, 0, -79, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, -79, 0, 0, 0, -79, -79, -79, 0, 0, 0, 0, -79, -79, -79,
+        -105, 0, -105, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, -105, -105, -105, -105, -105, -105, -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105,
         // State 107
-        -80, 0, -80, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, -80, 0, 0, 0, -80, -80, -80, 0, 0, 0, 0, -80, -80, -80,
+        0, -184, -184, -184, -184, -184, -184, -184, -184, -184, 0, -184, -184, -184, 0, -184, -184, -184, -184, -184, -184, 0, 0, 0, 0, 0, 0, 0, -184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 108
-        -102, 0, -102, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, -102, 0, 0, 0, -102, -102, -102, 0, 0, 0, 0, -102, -102, -102,
+        0, -185, -185, -185, -185, -185, -185, -185, -185, -185, 0, -185, -185, -185, 0, -185, -185, -185, -185, -185, -185, 0, 0, 0, 0, 0, 0, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 109
-        -101, 0, -101, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, -101, 0, 0, 0, -101, -101, -101, 0, 0, 0, 0, -101, -101, -101,
+        -125, 0, -125, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, 0, 0, -125, 0, -125, -125, -125, -125, -125, 0, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125,
         // State 110
-        0, -151, 0, -151, -151, -151, -151, -151, 0, -151, -151, -151, -151, 0, -151, -151, -151, -151, 0, 0, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -201, 0, -201, 0, 0, 0, 0, -201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -201, 0, 0, 0, 0, -201, 0, 0, 0, -201, 0, 0, 0, -201, -201, -201, 0, 0, 0, 0, -201, -201, -201, 0,
         // State 111
-        0, -62, 0, -62, -62, -62, -62, -62, 0, -62, -62, -62, -62, 0, -62, -62, -62, -62, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -124, 0, -124, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0, -124, 0, -124, -124, -124, -124, -124, 0, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124,
         // State 112
-        0, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, 0, -132, -132, -132, -132, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -106, 0, -106, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, -106, -106, -106, -106, -106, -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106,
         // State 113
-        0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -173, 0, -173, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -173, 0, 0, -173, 0, -173, -173, -173, -173, -173, 0, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173,
         // State 114
-        0, 0, 0, -60, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -216, 0, -216, 0, 0, 0, 0, -216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -216, 0, 0, 0, 0, -216, 0, 0, 0, -216, 0, 0, 0, -216, -216, -216, 0, 0, 0, 0, -216, -216, -216, 0,
         // State 115
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0,
+        -217, 0, -217, 0, 0, 0, 0, -217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -217, 0, 0, 0, 0, -217, 0, 0, 0, -217, 0, 0, 0, -217, -217, -217, 0, 0, 0, 0, -217, -217, -217, 0,
         // State 116
-        -116, 0, -116, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, -116, -116, -116, -116, -116, 0, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116,
+        0, -181, -181, -181, -181, -181, -181, -181, -181, -181, 0, -181, -181, -181, 0, -181, -181, -181, -181, -181, -181, 0, 0, 0, 0, 0, 0, 0, -181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 117
-        0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 118
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 119
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -182, -182, -182, -182, -182, -182, -182, -182, -182, 0, -182, -182, -182, 0, -182, -182, -182, -182, -182, -182, 0, 0, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 120
-        -139, 0, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, -139, -139, -139, -139, -139, 0, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139,
+        0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 121
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 145, 0, 0,
+        0, -183, -183, -183, -183, -183, -183, -183, -183, -183, 0, -183, -183, -183, 0, -183, -183, -183, -183, -183, -183, 0, 0, 0, 0, 0, 0, 0, -183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 122
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -180, -180, -180, -180, -180, -180, -180, -180, -180, 0, -180, -180, -180, 0, -180, -180, -180, -180, -180, -180, 0, 0, 0, 0, 0, 0, 0, -180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 123
-        -63, 0, -63, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, -63, -63, -63, -63, -63, 0, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63,
+        0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 124
-        0, -40, 0, -40, 0, 0, -40, 0, 0, 0, -40, 0, 0, 0, -40, 0, 0, -40, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, 0, 0, 0, 0, 0, 0, 0, -163, 0, 0, 0, 0, 0, 0, 0, -163, 0, 0, 0, 0, 0,
         // State 125
-        0, 0, 0, -20, 0, 0, -20, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -198, -198, -198, -198, -198, -198, -198, -198, -198, 0, -198, -198, -198, 0, -198, -198, -198, -198, -198, -198, 0, 0, 0, 0, 0, 0, 0, -198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 126
-        0, -50, 0, -50, 0, -50, -50, -50, 0, 0, -50, -50, -50, 0, -50, -50, -50, -50, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -199, -199, -199, -199, -199, -199, -199, -199, -199, 0, -199, -199, -199, 0, -199, -199, -199, -199, -199, -199, 0, 0, 0, 0, 0, 0, 0, -199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 127
-        0, 0, 0, -25, 0, 0, -25, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 128
-        0, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, 0, -72, -72, -72, -72, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -196, 0, -196, 0, 0, 0, 0, -196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -196, 0, 0, 0, 0, -196, 0, 0, 0, -196, 0, 0, 0, -196, -196, -196, 0, 0, 0, 0, -196, -196, -196, 0,
         // State 129
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 0, 0,
+        -133, 0, -133, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, 0, -133, 0, 0, 0, -133, 0, 0, 0, -133, -133, -133, 0, 0, 0, 0, -133, -133, -133, 0,
         // State 130
-        0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -134, 0, -134, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, 0, 0, 0, -134, 0, 0, 0, -134, 0, 0, 0, -134, -134, -134, 0, 0, 0, 0, -134, -134, -134, 0,
         // State 131
-        0, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, 47, -68, -68, -68, -68, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -130, 0, -130, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, 0, 0, -130, 0, -130, -130, -130, -130, -130, 0, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130,
         // State 132
-        0, -35, 0, -35, 0, 0, -35, 0, 0, 0, -35, -35, -35, 0, -35, -35, -35, -35, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -167, 0, 0, -167, 0, 0, 0, 0, -167, 0, 0, 0, 0, 0, 0, 0, -167, 43, 0, 0, 0, 0, 0, 0, 0, -167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 133
-        0, -45, 0, -45, -45, -45, -45, -45, 0, -45, -45, -45, -45, 0, -45, -45, -45, -45, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -135, 0, -135, 0, 0, 0, 0, -135, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, -135, 0, 0, -135, -135, -135, -135, -135, -135, -135, 0, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135,
         // State 134
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 0, 0,
+        -200, 0, -200, 0, 0, 0, 0, -200, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -200, 0, 0, 0, 0, -200, 0, 0, 0, -200, 0, 0, 0, -200, -200, -200, 0, 0, 0, 0, -200, -200, -200, 0,
         // State 135
-        0, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, 0, -133, -133, -133, -133, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -207, 0, -207, 0, 0, 0, 0, -207, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -207, 0, 0, 0, 0, -207, 0, 0, 0, -207, 0, 0, 0, -207, -207, -207, 0, 0, 0, 0, -207, -207, -207, 0,
         // State 136
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0,
+        -206, 0, -206, 0, 0, 0, 0, -206, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -206, 0, 0, 0, 0, -206, 0, 0, 0, -206, 0, 0, 0, -206, -206, -206, 0, 0, 0, 0, -206, -206, -206, 0,
         // State 137
-        -104, 0, -104, 0, 0, 0, 0, -104, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, -104, 0, 0, 0, -104, -104, -104, 0, 0, 0, 0, -104, -104, -104,
+        0, 0, 0, -169, 0, 0, -169, 0, 0, 0, 0, -169, 0, 0, 0, 0, 0, 0, 0, -169, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 138
-        -59, 0, -59, 0, 0, 0, 0, -59, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, 0, -59, 0, 0, 0, -59, -59, -59, 0, 0, 0, 0, -59, -59, -59,
+        0, -95, -95, -95, -95, -95, -95, -95, -95, -95, 0, -95, -95, -95, 0, -95, -95, -95, -95, -95, -95, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 139
-        -103, 0, -103, 0, 0, 0, 0, -103, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, -103, 0, 0, 0, -103, -103, -103, 0, 0, 0, 0, -103, -103, -103,
+        0, -213, 0, -213, -213, -213, -213, -213, 0, -213, 0, -213, -213, -213, 0, -213, -213, -213, 0, -213, -213, 0, 0, 0, 0, 0, 0, 0, -213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 140
-        -105, 0, -105, 0, 0, 0, 0, -105, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, -105, 0, 0, 0, -105, -105, -105, 0, 0, 0, 0, -105, -105, -105,
+        0, -186, -186, -186, -186, -186, -186, -186, -186, -186, 0, -186, -186, -186, 0, -186, -186, -186, -186, -186, -186, 0, 0, 0, 0, 0, 0, 0, -186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 141
-        0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -84, 0, -84, -84, -84, -84, -84, 0, -84, 0, -84, -84, -84, 0, -84, -84, -84, 0, -84, -84, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 142
-        -135, 0, -135, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, -135, -135, -135, -135, -135, 0, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135,
+        -195, 0, -195, 0, 0, 0, 0, -195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -195, 0, 0, 0, 0, -195, 0, 0, 0, -195, 0, 0, 0, -195, -195, -195, 0, 0, 0, 0, -195, -195, -195, 0,
         // State 143
-        -138, 0, -138, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, -138, -138, -138, -138, -138, 0, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138,
+        -120, 0, -120, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, -120, 0, 0, 0, -120, 0, 0, 0, -120, -120, -120, 0, 0, 0, 0, -120, -120, -120, 0,
         // State 144
-        0, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, 0, -134, -134, -134, -134, 0, 0, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -121, 0, -121, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, -121, 0, 0, 0, -121, 0, 0, 0, -121, -121, -121, 0, 0, 0, 0, -121, -121, -121, 0,
         // State 145
-        -157, 0, -157, 0, 0, 0, 0, -157, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, -157, -157, -157, -157, -157, -157, 0, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157,
+        -118, 0, -118, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, -118, 0, 0, 0, -118, 0, 0, 0, -118, -118, -118, 0, 0, 0, 0, -118, -118, -118, 0,
         // State 146
-        0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -119, 0, -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, -119, 0, 0, 0, -119, 0, 0, 0, -119, -119, -119, 0, 0, 0, 0, -119, -119, -119, 0,
         // State 147
-        -64, 0, -64, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, -64, -64, -64, -64, -64, 0, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64,
+        -197, 0, -197, 0, 0, 0, 0, -197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -197, 0, 0, 0, 0, -197, 0, 0, 0, -197, 0, 0, 0, -197, -197, -197, 0, 0, 0, 0, -197, -197, -197, 0,
         // State 148
-        0, 0, 0, -58, 0, 0, -58, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -142, 0, -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, -142, 0, 0, 0, -142, 0, 0, 0, -142, -142, -142, 0, 0, 0, 0, -142, -142, -142, 0,
         // State 149
-        0, 0, 0, -56, 0, 0, -56, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -141, 0, -141, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, -141, 0, 0, 0, -141, 0, 0, 0, -141, -141, -141, 0, 0, 0, 0, -141, -141, -141, 0,
         // State 150
-        0, -41, 0, -41, 0, 0, -41, 0, 0, 0, -41, 0, 0, 0, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 151
-        0, 0, 0, -21, 0, 0, -21, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 152
-        0, -51, 0, -51, 0, -51, -51, -51, 0, 0, -51, -51, -51, 0, -51, -51, -51, -51, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 153
-        0, 0, 0, -26, 0, 0, -26, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 154
-        0, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, 54, -68, -68, -68, -68, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0,
         // State 155
-        0, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, 0, -67, -67, -67, -67, 0, 0, 0, 0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -162, 0, -162, 0, 0, 0, 0, -162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -162, 0, 0, -162, 0, -162, -162, -162, -162, -162, 0, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162,
         // State 156
-        0, 0, 0, -53, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 157
-        0, -36, 0, -36, 0, 0, -36, 0, 0, 0, -36, -36, -36, 0, -36, -36, -36, -36, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 158
-        0, -46, 0, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46, 0, -46, -46, -46, -46, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 159
-        0, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, -68, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -194, 0, -194, 0, 0, 0, 0, -194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -194, 0, 0, -194, -194, -194, -194, -194, -194, -194, 0, -194, -194, -194, -194, -194, -194, -194, -194, -194, -194, -194, -194, -194,
         // State 160
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0,
+        -85, 0, -85, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, -85, -85, -85, -85, -85, -85, -85, 0, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85,
         // State 161
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, -114, 0, 0,
+        -126, 0, -126, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, 0, 0, -126, 0, -126, -126, -126, -126, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126,
         // State 162
-        -75, 0, -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, -75, -75, -75, -75, 0, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75,
+        0, -45, 0, -45, 0, 0, -45, 0, 0, 0, 0, -45, 0, 0, 0, -45, 0, 0, 0, -45, -45, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 163
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -20, 0, 0, -20, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, -20, -20, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 164
-        0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -55, 0, -55, 0, -55, -55, -55, 0, 0, 0, -55, -55, -55, 0, -55, -55, -55, 0, -55, -55, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 165
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -77, 0, 0, -77, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 166
-        0, 0, 0, -4, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -73, 0, 0, -73, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 167
-        0, 0, 0, -54, 0, 0, -54, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -25, 0, 0, -25, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 168
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, -115, 0, 0,
+        0, -96, -96, -96, -96, -96, -96, -96, -96, -96, 0, -96, -96, -96, 0, -96, -96, -96, -96, -96, -96, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 169
-        -76, 0, -76, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, -76, -76, -76, -76, 0, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76,
+        0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 170
-        0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, -90, -90, -90, 0, -90, -90, -90, -90, -90, -90, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 171
-        -111, 0, -111, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, -111, -111, -111, -111, -111, 0, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111,
+        0, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, -91, -91, -91, 61, -91, -91, -91, -91, -91, -91, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 172
-        0, 0, 0, -123, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 173
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0,
+        0, -40, 0, -40, 0, 0, -40, 0, 0, 0, 0, -40, -40, -40, 0, -40, -40, -40, 0, -40, -40, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 174
-        -117, 0, -117, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, -117, -117, -117, -117, -117, 0, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117,
+        0, -50, 0, -50, -50, -50, -50, -50, 0, -50, 0, -50, -50, -50, 0, -50, -50, -50, 0, -50, -50, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 175
-        -61, 0, -61, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, -61, -61, -61, -61, -61, 0, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61,
+        0, -187, -187, -187, -187, -187, -187, -187, -187, -187, 0, -187, -187, -187, 0, -187, -187, -187, -187, -187, -187, 0, 0, 0, 0, 0, 0, 0, -187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 176
-        -156, 0, -156, 0, 0, 0, 0, -156, 0, 0, -156, 0, 0, 0, 0, 0, 0, 0, -156, -156, -156, -156, -156, -156, 0, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156,
+        0, 0, 0, 0, 0, 0, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 177
-        -158, 0, -158, 0, 0, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -158, -158, -158, -158, -158, -158, 0, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158,
+        0, -189, -189, -189, -189, -189, -189, -189, -189, -189, 0, -189, -189, -189, 0, -189, -189, -189, -189, -189, -189, 0, 0, 0, 0, 0, 0, 0, -189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 178
-        0, 0, 0, -55, 0, 0, -55, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -35, 0, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, -35, 0, 0, 0, -35, 0, 0, 0, -35, 0, 0, 0, -35, -35, -35, 0, 0, 0, 0, -35, -35, -35, 0,
         // State 179
-        0, 0, 0, -5, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -144, 0, -144, 0, 0, 0, 0, -144, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, -144, 0, 0, 0, -144, 0, 0, 0, -144, -144, -144, 0, 0, 0, 0, -144, -144, -144, 0,
         // State 180
-        -73, 0, -73, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, -73, -73, -73, -73, -73, 0, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73,
+        -79, 0, -79, 0, 0, 0, 0, -79, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, -79, 0, 0, 0, -79, 0, 0, 0, -79, -79, -79, 0, 0, 0, 0, -79, -79, -79, 0,
         // State 181
-        0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -143, 0, -143, 0, 0, 0, 0, -143, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, -143, 0, 0, 0, -143, 0, 0, 0, -143, -143, -143, 0, 0, 0, 0, -143, -143, -143, 0,
         // State 182
-        -109, 0, -109, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, -109, -109, -109, -109, -109, 0, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109,
+        -145, 0, -145, 0, 0, 0, 0, -145, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, -145, 0, 0, 0, -145, 0, 0, 0, -145, -145, -145, 0, 0, 0, 0, -145, -145, -145, 0,
         // State 183
-        -110, 0, -110, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, -110, -110, -110, -110, -110, 0, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110,
+        0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 184
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 190, 0, 0,
+        -190, 0, -190, 0, 0, 0, 0, -190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -190, 0, 0, -190, -190, -190, -190, -190, -190, -190, 0, -190, -190, -190, -190, -190, -190, -190, -190, -190, -190, -190, -190, -190,
         // State 185
-        0, 0, 0, -9, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -193, 0, -193, 0, 0, 0, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -193, 0, 0, -193, -193, -193, -193, -193, -193, -193, 0, -193, -193, -193, -193, -193, -193, -193, -193, -193, -193, -193, -193, -193,
         // State 186
-        -74, 0, -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, -74, -74, -74, -74, 0, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74,
+        0, -188, -188, -188, -188, -188, -188, -188, -188, -188, 0, -188, -188, -188, 0, -188, -188, -188, -188, -188, -188, 0, 0, 0, 0, 0, 0, 0, -188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 187
-        -107, 0, -107, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, -107, -107, -107, -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 211, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 188
-        -108, 0, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, -108, -108, -108, -108, -108, 0, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108,
+        -221, 0, -221, 0, 0, 0, 0, -221, 0, 0, 0, -221, 0, 0, 0, 0, 0, 0, -221, 0, 0, -221, 0, -221, -221, -221, -221, -221, 0, -221, -221, -221, -221, -221, -221, -221, -221, -221, -221, -221, -221, -221,
         // State 189
-        0, 0, 0, -10, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 190
-        -106, 0, -106, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, -106, -106, -106, -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106,
+        -86, 0, -86, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, -86, -86, -86, -86, -86, -86, -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86,
+        // State 191
+        0, -46, 0, -46, 0, 0, -46, 0, 0, 0, 0, -46, 0, 0, 0, -46, 0, 0, 0, -46, -46, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 192
+        0, 0, 0, -21, 0, 0, -21, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, -21, -21, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 193
+        0, -56, 0, -56, 0, -56, -56, -56, 0, 0, 0, -56, -56, -56, 0, -56, -56, -56, 0, -56, -56, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 194
+        0, 0, 0, -26, 0, 0, -26, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 195
+        0, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, -91, -91, -91, 70, -91, -91, -91, -91, -91, -91, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 197
+        0, -89, -89, -89, -89, -89, -89, -89, -89, -89, 0, -89, -89, -89, 0, -89, -89, -89, -89, -89, -89, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 198
+        0, 0, 0, -68, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 199
+        0, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, -92, -92, -92, 72, -92, -92, -92, -92, -92, -92, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, -91, -91, -91, 0, -91, -91, -91, -91, -91, -91, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 201
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 202
+        0, -41, 0, -41, 0, 0, -41, 0, 0, 0, 0, -41, -41, -41, 0, -41, -41, -41, 0, -41, -41, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 203
+        0, -51, 0, -51, -51, -51, -51, -51, 0, -51, 0, -51, -51, -51, 0, -51, -51, -51, 0, -51, -51, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        -36, 0, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, -36, 0, 0, 0, -36, 0, 0, 0, -36, 0, 0, 0, -36, -36, -36, 0, 0, 0, 0, -36, -36, -36, 0,
+        // State 205
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0,
+        // State 206
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -160, -160, 0, 0, 0,
+        // State 207
+        -99, 0, -99, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, 0, 0, -99, 0, -99, -99, -99, -99, -99, 0, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99,
+        // State 208
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 209
+        0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 210
+        -220, 0, -220, 0, 0, 0, 0, -220, 0, 0, 0, -220, 0, 0, 0, 0, 0, 0, -220, 0, 0, -220, 0, -220, -220, -220, -220, -220, 0, -220, -220, -220, -220, -220, -220, -220, -220, -220, -220, -220, -220, -220,
+        // State 211
+        0, 0, 0, -208, 0, 0, -208, 0, 0, 0, 0, -208, 0, 0, -208, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -208, 0, 0, 0, 0, 0,
+        // State 212
+        0, 0, 0, -64, 0, 0, -64, 0, 0, 0, 0, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0,
+        // State 213
+        0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 214
+        0, 0, 0, -209, 0, 0, -209, 0, 0, 0, 0, -209, 0, 0, -209, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -209, 0, 0, 0, 0, 0,
+        // State 215
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 216
+        0, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, -92, -92, -92, 80, -92, -92, -92, -92, -92, -92, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 217
+        0, 0, 0, -4, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 218
+        0, 0, 0, -71, 0, 0, -71, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 219
+        0, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, -92, -92, -92, 0, -92, -92, -92, -92, -92, -92, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 220
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -161, -161, 0, 0, 0,
+        // State 221
+        -100, 0, -100, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, 0, 0, -100, 0, -100, -100, -100, -100, -100, 0, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100,
+        // State 222
+        0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 223
+        0, 0, 0, -174, 0, 0, -174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 224
+        0, 0, 0, -177, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 225
+        -157, 0, -157, 0, 0, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -157, 0, 0, -157, 0, -157, -157, -157, -157, -157, 0, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157,
+        // State 226
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 227
+        -170, 0, -170, 0, 0, 0, 0, -170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -170, 0, 0, -170, 0, -170, -170, -170, -170, -170, 0, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170,
+        // State 228
+        -203, 0, -203, 0, 0, 0, 0, -203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -203, 0, 0, -203, -78, -203, -203, -203, -203, -203, 0, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203,
+        // State 229
+        -82, 0, -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, -82, 0, -82, -82, -82, -82, -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82,
+        // State 230
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 231
+        -219, 0, -219, 0, 0, 0, 0, -219, 0, 0, 0, -219, 0, 0, 0, 0, 0, 0, -219, 0, 0, -219, 0, -219, -219, -219, -219, -219, 0, -219, -219, -219, -219, -219, -219, -219, -219, -219, -219, -219, -219, -219,
+        // State 232
+        -222, 0, -222, 0, 0, 0, 0, -222, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -222, 0, 0, -222, -222, -222, -222, -222, -222, -222, 0, -222, -222, -222, -222, -222, -222, -222, -222, -222, -222, -222, -222, -222,
+        // State 233
+        -223, 0, -223, 0, 0, 0, 0, -223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -223, 0, 0, -223, 0, -223, -223, -223, -223, -223, 0, -223, -223, -223, -223, -223, -223, -223, -223, -223, -223, -223, -223, -223,
+        // State 234
+        -78, 0, -78, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, -78, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78,
+        // State 235
+        -81, 0, -81, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, -81, 0, -81, -81, -81, -81, -81, 0, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81,
+        // State 236
+        0, 0, 0, -72, 0, 0, -72, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 237
+        0, 0, 0, -5, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 238
+        0, 0, 0, -74, 0, 0, -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 239
+        -97, 0, -97, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, -97, 0, -97, -97, -97, -97, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97,
+        // State 240
+        0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 241
+        -149, 0, -149, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, 0, -149, -149, -149, -149, -149, -149, -149, 0, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149,
+        // State 242
+        -153, 0, -153, 0, 0, 0, 0, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -153, 0, 0, -153, 0, -153, -153, -153, -153, -153, 0, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153,
+        // State 243
+        0, 0, 0, -9, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 244
+        -156, 0, -156, 0, 0, 0, 0, -156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -156, 0, 0, -156, 0, -156, -156, -156, -156, -156, 0, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156,
+        // State 245
+        -155, 0, -155, 0, 0, 0, 0, -155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -155, 0, 0, -155, 0, -155, -155, -155, -155, -155, 0, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155,
+        // State 246
+        -218, 0, -218, 0, 0, 0, 0, -218, 0, 0, 0, -218, 0, 0, 0, 0, 0, 0, -218, 0, 0, -218, 0, -218, -218, -218, -218, -218, 0, -218, -218, -218, -218, -218, -218, -218, -218, -218, -218, -218, -218, -218,
+        // State 247
+        0, 0, 0, 261, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 248
+        0, 0, 0, -112, 0, 0, 262, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 249
+        0, 0, 0, -75, 0, 0, -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 250
+        -98, 0, -98, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, -98, 0, -98, -98, -98, -98, -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98,
+        // State 251
+        -147, 0, -147, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, 0, -147, -147, -147, -147, -147, -147, -147, 0, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147,
+        // State 252
+        -151, 0, -151, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -151, 0, 0, -151, 0, -151, -151, -151, -151, -151, 0, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151,
+        // State 253
+        -148, 0, -148, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, -148, -148, -148, -148, -148, -148, -148, 0, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148,
+        // State 254
+        -152, 0, -152, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, 0, -152, 0, -152, -152, -152, -152, -152, 0, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152,
+        // State 255
+        0, 0, 0, -10, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 256
+        -154, 0, -154, 0, 0, 0, 0, -154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -154, 0, 0, -154, 0, -154, -154, -154, -154, -154, 0, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154,
+        // State 257
+        -164, 0, -164, 0, 0, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -164, 0, 0, -164, -164, -164, -164, -164, -164, -164, 0, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164,
+        // State 258
+        -165, 0, -165, 0, 0, 0, 0, -165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -165, 0, 0, -165, 0, -165, -165, -165, -165, -165, 0, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165,
+        // State 259
+        0, 0, 0, -114, 0, 0, 265, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 260
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 261
+        0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0,
+        // State 262
+        -146, 0, -146, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, -146, -146, -146, -146, -146, -146, -146, 0, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146,
+        // State 263
+        -150, 0, -150, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150, 0, 0, -150, 0, -150, -150, -150, -150, -150, 0, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150,
+        // State 264
+        0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0,
+        // State 265
+        0, 0, 0, -210, 0, 0, -210, 0, 0, 0, 0, -210, 0, 0, -210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -210, 0, 0, 0, 0, 0,
+        // State 266
+        0, 0, 0, -83, 0, 0, -83, 0, 0, 0, 0, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 37 + integer]
+        __ACTION[(state as usize) * 42 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
-        -136,
+        -191,
         // State 1
         0,
         // State 2
-        -137,
+        -192,
         // State 3
         0,
         // State 4
@@ -600,35 +771,35 @@ mod __parse__Program {
         // State 63
         0,
         // State 64
-        -146,
+        0,
         // State 65
-        -83,
+        0,
         // State 66
-        -89,
+        0,
         // State 67
-        -140,
+        0,
         // State 68
         0,
         // State 69
-        -141,
+        0,
         // State 70
-        -84,
+        0,
         // State 71
-        -142,
+        0,
         // State 72
         0,
         // State 73
-        -143,
+        0,
         // State 74
-        -159,
+        0,
         // State 75
-        -144,
+        0,
         // State 76
-        -86,
+        0,
         // State 77
-        -85,
+        0,
         // State 78
-        -145,
+        0,
         // State 79
         0,
         // State 80
@@ -652,59 +823,59 @@ mod __parse__Program {
         // State 89
         0,
         // State 90
-        0,
+        -107,
         // State 91
-        0,
+        -122,
         // State 92
-        0,
+        -203,
         // State 93
-        0,
+        -129,
         // State 94
-        0,
+        -101,
         // State 95
         0,
         // State 96
-        -90,
+        -102,
         // State 97
-        0,
+        -172,
         // State 98
-        -95,
+        -123,
         // State 99
         0,
         // State 100
-        0,
+        -103,
         // State 101
-        0,
+        -171,
         // State 102
         0,
         // State 103
-        0,
+        -202,
         // State 104
-        0,
+        -104,
         // State 105
-        0,
+        -224,
         // State 106
-        0,
+        -105,
         // State 107
         0,
         // State 108
         0,
         // State 109
-        0,
+        -125,
         // State 110
         0,
         // State 111
-        0,
+        -124,
         // State 112
-        0,
+        -106,
         // State 113
-        0,
+        -173,
         // State 114
         0,
         // State 115
         0,
         // State 116
-        -116,
+        0,
         // State 117
         0,
         // State 118
@@ -712,13 +883,13 @@ mod __parse__Program {
         // State 119
         0,
         // State 120
-        -139,
+        0,
         // State 121
         0,
         // State 122
         0,
         // State 123
-        -63,
+        0,
         // State 124
         0,
         // State 125
@@ -734,11 +905,11 @@ mod __parse__Program {
         // State 130
         0,
         // State 131
-        0,
+        -130,
         // State 132
         0,
         // State 133
-        0,
+        -135,
         // State 134
         0,
         // State 135
@@ -756,17 +927,17 @@ mod __parse__Program {
         // State 141
         0,
         // State 142
-        -135,
+        0,
         // State 143
-        -138,
+        0,
         // State 144
         0,
         // State 145
-        -157,
+        0,
         // State 146
         0,
         // State 147
-        -64,
+        0,
         // State 148
         0,
         // State 149
@@ -782,7 +953,7 @@ mod __parse__Program {
         // State 154
         0,
         // State 155
-        0,
+        -162,
         // State 156
         0,
         // State 157
@@ -790,13 +961,13 @@ mod __parse__Program {
         // State 158
         0,
         // State 159
-        0,
+        -194,
         // State 160
-        0,
+        -85,
         // State 161
-        0,
+        -126,
         // State 162
-        -75,
+        0,
         // State 163
         0,
         // State 164
@@ -810,203 +981,430 @@ mod __parse__Program {
         // State 168
         0,
         // State 169
-        -76,
+        0,
         // State 170
         0,
         // State 171
-        -111,
+        0,
         // State 172
         0,
         // State 173
         0,
         // State 174
-        -117,
+        0,
         // State 175
-        -61,
+        0,
         // State 176
-        -156,
+        0,
         // State 177
-        -158,
+        0,
         // State 178
         0,
         // State 179
         0,
         // State 180
-        -73,
+        0,
         // State 181
         0,
         // State 182
-        -109,
+        0,
         // State 183
-        -110,
-        // State 184
         0,
+        // State 184
+        -190,
         // State 185
-        0,
+        -193,
         // State 186
-        -74,
+        0,
         // State 187
-        -107,
+        0,
         // State 188
-        -108,
+        -221,
         // State 189
         0,
         // State 190
-        -106,
+        -86,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        0,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        0,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        0,
+        // State 206
+        0,
+        // State 207
+        -99,
+        // State 208
+        0,
+        // State 209
+        0,
+        // State 210
+        -220,
+        // State 211
+        0,
+        // State 212
+        0,
+        // State 213
+        0,
+        // State 214
+        0,
+        // State 215
+        0,
+        // State 216
+        0,
+        // State 217
+        0,
+        // State 218
+        0,
+        // State 219
+        0,
+        // State 220
+        0,
+        // State 221
+        -100,
+        // State 222
+        0,
+        // State 223
+        0,
+        // State 224
+        0,
+        // State 225
+        -157,
+        // State 226
+        0,
+        // State 227
+        -170,
+        // State 228
+        -203,
+        // State 229
+        -82,
+        // State 230
+        0,
+        // State 231
+        -219,
+        // State 232
+        -222,
+        // State 233
+        -223,
+        // State 234
+        -78,
+        // State 235
+        -81,
+        // State 236
+        0,
+        // State 237
+        0,
+        // State 238
+        0,
+        // State 239
+        -97,
+        // State 240
+        0,
+        // State 241
+        -149,
+        // State 242
+        -153,
+        // State 243
+        0,
+        // State 244
+        -156,
+        // State 245
+        -155,
+        // State 246
+        -218,
+        // State 247
+        0,
+        // State 248
+        0,
+        // State 249
+        0,
+        // State 250
+        -98,
+        // State 251
+        -147,
+        // State 252
+        -151,
+        // State 253
+        -148,
+        // State 254
+        -152,
+        // State 255
+        0,
+        // State 256
+        -154,
+        // State 257
+        -164,
+        // State 258
+        -165,
+        // State 259
+        0,
+        // State 260
+        0,
+        // State 261
+        0,
+        // State 262
+        -146,
+        // State 263
+        -150,
+        // State 264
+        0,
+        // State 265
+        0,
+        // State 266
+        0,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 156,
-            5 => 172,
-            12 => 97,
-            15 => 101,
-            20 => 23,
-            23 => 15,
-            26 => 25,
-            29 => 18,
-            30 => 130,
-            31 => match state {
-                32 | 46 | 53 => 148,
-                _ => 63,
+            2 => 198,
+            5 => 224,
+            12 => 132,
+            15 => 137,
+            20 => 34,
+            23 => 30,
+            26 => 18,
+            29 => 32,
+            32 => 21,
+            35 => 86,
+            38 => match state {
+                76 => 84,
+                38 => 187,
+                63 => 223,
+                _ => 77,
             },
-            32 => match state {
-                46 => 167,
-                53 => 178,
-                _ => 149,
+            40 => 169,
+            42 => match state {
+                23 | 60 | 69 | 71 | 79 => 165,
+                _ => 89,
             },
-            33 => 137,
-            34 => match state {
-                10 => 113,
-                _ => 37,
+            43 => match state {
+                60 => 218,
+                69 => 236,
+                71 => 238,
+                79 => 249,
+                _ => 166,
             },
-            35 => match state {
-                52 => 177,
-                57 => 182,
-                60 => 187,
-                61 => 188,
-                62 => 190,
-                _ => 174,
+            44 => match state {
+                68 => 232,
+                74 => 241,
+                81 => 251,
+                82 => 253,
+                85 => 257,
+                87 => 262,
+                _ => 226,
             },
-            36 => 110,
-            37 => match state {
-                49 => 171,
-                58 => 183,
-                _ => 64,
+            45 => 179,
+            46 => match state {
+                10 => 150,
+                28 => 172,
+                46 => 196,
+                50 => 201,
+                _ => 47,
             },
-            39 => match state {
-                21 | 40 => 128,
-                _ => 102,
+            47 => match state {
+                74 => 242,
+                81 => 252,
+                82 => 254,
+                85 => 258,
+                87 => 263,
+                _ => 233,
             },
-            41 => match state {
-                27 => 40,
-                _ => 21,
+            48 => 227,
+            49 => 265,
+            50 => 139,
+            51 => match state {
+                65 => 225,
+                76 => 244,
+                77 => 245,
+                84 => 256,
+                _ => 90,
             },
-            42 => 65,
-            43 => match state {
-                16 => 124,
-                33 => 150,
-                _ => 1,
+            53 => match state {
+                25 | 48 => 168,
+                _ => 138,
             },
-            44 => match state {
-                23 => 38,
-                _ => 24,
+            55 => match state {
+                29 => 48,
+                _ => 25,
+            },
+            56 => 91,
+            57 => match state {
+                0 | 2 | 17 | 40 => 92,
+                66 => 228,
+                _ => 234,
             },
-            45 => match state {
-                2 | 31 => 96,
-                _ => 66,
+            58 => 152,
+            59 => 247,
+            60 => match state {
+                19 => 162,
+                41 => 191,
+                _ => 1,
             },
-            47 => match state {
-                14 => 31,
+            61 => 142,
+            62 => match state {
+                2 | 40 => 131,
+                _ => 93,
+            },
+            64 => match state {
+                17 => 40,
                 _ => 2,
             },
-            48 => match state {
-                17 => 125,
-                34 => 151,
+            65 => match state {
+                20 => 163,
+                42 => 192,
                 _ => 3,
             },
-            49 => match state {
-                15 => 33,
-                _ => 16,
-            },
-            50 => match state {
-                28 => 138,
-                _ => 67,
+            66 => 128,
+            67 => match state {
+                35 => 180,
+                _ => 94,
             },
-            51 => match state {
-                10 | 22 => 114,
-                12 => 118,
-                13 => 119,
-                29 => 141,
-                30 => 146,
-                42 => 163,
-                44 => 165,
-                45 => 166,
-                48 => 170,
-                54 => 179,
-                56 => 181,
-                _ => 68,
+            68 => match state {
+                10 | 26 | 28 | 46 | 50 => 151,
+                11 => 153,
+                14 => 157,
+                15 => 158,
+                34 => 176,
+                36 => 183,
+                39 => 189,
+                55 => 208,
+                58 => 215,
+                59 => 217,
+                62 => 222,
+                67 => 230,
+                70 => 237,
+                73 => 240,
+                _ => 95,
             },
-            53 => match state {
-                19 => 126,
-                35 => 152,
+            70 => match state {
+                22 => 164,
+                43 => 193,
                 _ => 4,
             },
-            54 => match state {
-                25 => 39,
-                _ => 26,
+            71 => 147,
+            72 => 55,
+            73 => 96,
+            74 => 97,
+            75 => match state {
+                13 => 155,
+                61 | 80 => 220,
+                _ => 206,
             },
-            55 => 42,
-            56 => 69,
-            57 => match state {
-                11 => 116,
-                47 | 59 => 168,
-                _ => 161,
+            77 => match state {
+                72 => 80,
+                _ => 61,
             },
-            59 => match state {
-                55 => 59,
-                _ => 47,
+            78 => 98,
+            79 => match state {
+                16 => 38,
+                56 | 75 | 83 => 63,
+                7 | 19..=20 | 22 | 24 | 31 | 33 | 41..=44 | 51..=52 => 140,
+                12 => 154,
+                13 | 54 | 61 | 72 | 80 => 156,
+                27 => 171,
+                37 => 186,
+                45 => 195,
+                49 => 200,
+                53 => 205,
+                57 | 78 | 86 | 88 => 211,
+                _ => 99,
             },
-            60 => 70,
-            61 => 71,
-            62 => match state {
-                20 => 127,
-                36 => 153,
+            80 => 100,
+            81 => 101,
+            82 => match state {
+                24 => 167,
+                44 => 194,
                 _ => 5,
             },
-            63 => 72,
-            64 => 164,
-            66 => match state {
-                9 | 16..=17 | 19..=20 | 24 | 26 | 33..=36 | 38..=39 => 27,
-                _ => 6,
+            83 => 102,
+            84 => match state {
+                68 | 74 | 81..=82 | 85 | 87 => 235,
+                _ => 103,
             },
-            67 => 73,
-            68 => 74,
-            69 => 75,
-            70 => match state {
-                51..=52 | 57 | 60..=62 => 175,
-                _ => 76,
+            85 => match state {
+                75 => 243,
+                83 => 255,
+                _ => 64,
             },
-            71 => match state {
-                24 => 132,
-                38 => 157,
-                _ => 7,
+            86 => 209,
+            88 => match state {
+                7 | 19..=20 | 22 | 24 | 31 | 33 | 41..=44 | 51..=52 => 29,
+                _ => 6,
+            },
+            89 => 104,
+            90 => 105,
+            91 => 106,
+            92 => match state {
+                30 => 51,
+                _ => 31,
             },
-            72 => match state {
-                18 => 35,
+            93 => match state {
+                18 => 41,
                 _ => 19,
             },
-            73 => match state {
-                9 => 111,
-                26 => 133,
-                39 => 158,
+            94 => match state {
+                32 => 52,
+                _ => 33,
+            },
+            95 => 107,
+            96 => 108,
+            97 => match state {
+                21 => 43,
+                _ => 22,
+            },
+            98 => 7,
+            99 => match state {
+                66 => 229,
+                _ => 109,
+            },
+            100 => match state {
+                31 => 173,
+                51 => 202,
                 _ => 8,
             },
-            74 => 9,
-            75 => match state {
-                28 => 139,
-                _ => 77,
+            101 => 134,
+            102 => match state {
+                78 => 248,
+                86 => 259,
+                88 => 266,
+                _ => 212,
+            },
+            104 => match state {
+                7 => 141,
+                33 => 174,
+                52 => 203,
+                _ => 9,
             },
-            76 => 78,
+            105 => 110,
+            106 => match state {
+                35 => 181,
+                _ => 111,
+            },
+            107 => 112,
+            108 => 113,
             _ => 0,
         }
     }
@@ -1022,6 +1420,7 @@ mod __parse__Program {
             r###""-""###,
             r###"".""###,
             r###""/""###,
+            r###"":""###,
             r###"";""###,
             r###""<""###,
             r###""<=""###,
@@ -1029,8 +1428,11 @@ mod __parse__Program {
             r###""==""###,
             r###"">""###,
             r###"">=""###,
+            r###""[""###,
+            r###""]""###,
             r###""and""###,
             r###""class""###,
+            r###""else""###,
             r###""false""###,
             r###""for""###,
             r###""fun""###,
@@ -1059,14 +1461,15 @@ mod __parse__Program {
             }
         }).collect()
     }
-    pub(crate) struct __StateMachine<'input>
-    where 
+    pub(crate) struct __StateMachine<'input, 'err>
+    where 'input: 'err, 'static: 'err
     {
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        __phantom: core::marker::PhantomData<(&'input (), &'err ())>,
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
-    where 
+    impl<'input, 'err> __state_machine::ParserDefinition for __StateMachine<'input, 'err>
+    where 'input: 'err, 'static: 'err
     {
         type Location = usize;
         type Error = &'static str;
@@ -1091,7 +1494,7 @@ mod __parse__Program {
 
         #[inline]
         fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+            __token_to_integer(token, core::marker::PhantomData::<(&(), &())>)
         }
 
         #[inline]
@@ -1101,7 +1504,7 @@ mod __parse__Program {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 37 - 1)
+            __action(state, 42 - 1)
         }
 
         #[inline]
@@ -1115,7 +1518,7 @@ mod __parse__Program {
         }
 
         fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&(), &())>)
         }
 
         fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
@@ -1124,7 +1527,7 @@ mod __parse__Program {
 
         #[inline]
         fn uses_error_recovery(&self) -> bool {
-            false
+            true
         }
 
         #[inline]
@@ -1132,7 +1535,7 @@ mod __parse__Program {
             &self,
             recovery: __state_machine::ErrorRecovery<Self>,
         ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
+            __Symbol::Variant1(recovery)
         }
 
         fn reduce(
@@ -1143,24 +1546,26 @@ mod __parse__Program {
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
+                self.errors,
                 self.input,
                 action,
                 start_location,
                 states,
                 symbols,
-                core::marker::PhantomData::<(&())>,
+                core::marker::PhantomData::<(&(), &())>,
             )
         }
 
         fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
-            panic!("error recovery not enabled for this grammar")
+            __simulate_reduce(action, core::marker::PhantomData::<(&(), &())>)
         }
     }
     fn __token_to_integer<
         'input,
+        'err,
     >(
         __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> Option<usize>
     {
         match *__token {
@@ -1198,6127 +1603,14668 @@ mod __parse__Program {
             Token(34, _) if true => Some(31),
             Token(35, _) if true => Some(32),
             Token(36, _) if true => Some(33),
-            Token(2, _) if true => Some(34),
-            Token(1, _) if true => Some(35),
-            Token(0, _) if true => Some(36),
+            Token(37, _) if true => Some(34),
+            Token(38, _) if true => Some(35),
+            Token(39, _) if true => Some(36),
+            Token(40, _) if true => Some(37),
+            Token(2, _) if true => Some(38),
+            Token(1, _) if true => Some(39),
+            Token(0, _) if true => Some(40),
             _ => None,
         }
     }
     fn __token_to_symbol<
         'input,
+        'err,
     >(
         __token_index: usize,
         __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 => match __token {
-                Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(2, __tok0) | Token(1, __tok0) | Token(0, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 => match __token {
+                Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(37, __tok0) | Token(38, __tok0) | Token(39, __tok0) | Token(40, __tok0) | Token(2, __tok0) | Token(1, __tok0) | Token(0, __tok0) if true => __Symbol::Variant0(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
         }
     }
-    pub struct ProgramParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
-        _priv: (),
-    }
-
-    impl ProgramParser {
-        pub fn new() -> ProgramParser {
-            let __builder = super::__intern_token::new_builder();
-            ProgramParser {
-                builder: __builder,
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            'input,
-        >(
-            &self,
-            input: &'input str,
-        ) -> Result<Program<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
-        {
-            let mut __tokens = self.builder.matcher(input);
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    input,
-                    __phantom: core::marker::PhantomData::<(&())>,
-                },
-                __tokens,
-            )
-        }
-    }
-    pub(crate) fn __reduce<
+    fn __simulate_reduce<
         'input,
+        'err,
     >(
-        input: &'input str,
-        __action: i16,
-        __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i16>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<Program<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+        __reduce_index: i16,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'input, 'err>>
+    where
+        'input: 'err,
+        'static: 'err,
     {
-        let (__pop_states, __nonterminal) = match __action {
+        match __reduce_index {
             0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 0,
+                }
             }
             1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 1,
+                }
             }
             2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 1,
+                }
             }
             3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 2,
+                }
             }
             4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 2,
+                }
             }
             5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 3,
+                }
             }
             6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 4,
+                }
             }
             7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 4,
+                }
             }
             8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 5,
+                }
             }
             9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 5,
+                }
             }
             10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 6,
+                }
             }
             11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 7,
+                }
             }
             12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 7,
+                }
             }
             13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 8,
+                }
             }
             14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 9,
+                }
             }
             15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 9,
+                }
             }
             16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 10,
+                }
             }
             17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 11,
+                }
             }
             18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
+                }
             }
             19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 12,
+                }
             }
             20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 12,
+                }
             }
             21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 13,
+                }
             }
             22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 14,
+                }
             }
             23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
+                }
             }
             24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 15,
+                }
             }
             25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 15,
+                }
             }
             26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 16,
+                }
             }
             27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 16,
+                }
             }
             28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 17,
+                }
             }
             29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 17,
+                }
             }
             30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 17,
+                }
             }
             31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 18,
+                }
             }
             32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 19,
+                }
             }
             33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
+                }
             }
             34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 20,
+                }
             }
             35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 20,
+                }
             }
             36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 21,
+                }
             }
             37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 22,
+                }
             }
             38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
             }
             39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 23,
+                }
             }
             40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 23,
+                }
             }
             41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 24,
+                }
             }
             42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 25,
+                }
             }
             43 => {
-                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
             }
             44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 26,
+                }
             }
             45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 26,
+                }
             }
             46 => {
-                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 27,
+                }
             }
             47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 28,
+                }
             }
             48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
             }
             49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 29,
+                }
             }
             50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
+                }
             }
             51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 30,
+                }
             }
             52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 31,
+                }
             }
             53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
             }
             54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 32,
+                }
             }
             55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
+                }
             }
             56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 33,
+                }
             }
             57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 34,
+                }
             }
             58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
+                }
             }
             59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 35,
+                }
             }
             60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 35,
+                }
             }
             61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 36,
+                }
             }
             62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 37,
+                }
             }
             63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 38,
+                }
             }
             64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
             65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 39,
+                }
             }
             66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 40,
+                }
             }
             67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 40,
+                }
             }
             68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 41,
+                }
             }
             69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 41,
+                }
             }
             70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 42,
+                }
             }
             71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 42,
+                }
             }
             72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 42,
+                }
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 42,
+                }
             }
             74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 42,
+                }
             }
             75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 42,
+                }
             }
             76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 43,
+                }
             }
             77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
+                }
             }
             78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 45,
+                }
             }
             79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
+                }
             }
             80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 47,
+                }
             }
             81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 48,
+                }
             }
             82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 49,
+                }
             }
             83 => {
-                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
             }
             84 => {
-                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 51,
+                }
             }
             85 => {
-                __reduce85(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 51,
+                }
             }
             86 => {
-                __reduce86(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
             }
             87 => {
-                __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 52,
+                }
             }
             88 => {
-                __reduce88(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 53,
+                }
             }
             89 => {
-                __reduce89(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 53,
+                }
             }
             90 => {
-                __reduce90(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 53,
+                }
             }
             91 => {
-                __reduce91(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 53,
+                }
             }
             92 => {
-                __reduce92(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 54,
+                }
             }
             93 => {
-                __reduce93(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
             }
             94 => {
-                __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
             }
             95 => {
-                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 55,
+                }
             }
             96 => {
-                __reduce96(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 56,
+                }
             }
             97 => {
-                __reduce97(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 56,
+                }
             }
             98 => {
-                __reduce98(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 56,
+                }
             }
             99 => {
-                __reduce99(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 56,
+                }
             }
             100 => {
-                __reduce100(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
             }
             101 => {
-                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
             }
             102 => {
-                __reduce102(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
             }
             103 => {
-                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
             }
             104 => {
-                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
             }
             105 => {
-                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
             }
             106 => {
-                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
             }
             107 => {
-                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 58,
+                }
             }
             108 => {
-                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 58,
+                }
             }
             109 => {
-                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 58,
+                }
             }
             110 => {
-                __reduce110(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 58,
+                }
             }
             111 => {
-                __reduce111(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
+                }
             }
             112 => {
-                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 59,
+                }
             }
             113 => {
-                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 59,
+                }
             }
             114 => {
-                __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
+                }
             }
             115 => {
-                __reduce115(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
+                }
             }
             116 => {
-                __reduce116(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 60,
+                }
             }
             117 => {
-                __reduce117(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 61,
+                }
             }
             118 => {
-                __reduce118(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 61,
+                }
             }
             119 => {
-                __reduce119(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 61,
+                }
             }
             120 => {
-                __reduce120(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 61,
+                }
             }
             121 => {
-                __reduce121(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
             }
             122 => {
-                __reduce122(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
             }
             123 => {
-                __reduce123(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
             }
             124 => {
-                __reduce124(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
             }
             125 => {
-                __reduce125(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 62,
+                }
             }
             126 => {
-                __reduce126(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 63,
+                }
             }
             127 => {
-                __reduce127(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 63,
+                }
             }
             128 => {
-                __reduce128(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 64,
+                }
             }
             129 => {
-                __reduce129(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 64,
+                }
             }
             130 => {
-                __reduce130(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 65,
+                }
             }
             131 => {
-                __reduce131(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 65,
+                }
             }
             132 => {
-                __reduce132(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 66,
+                }
             }
             133 => {
-                __reduce133(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 66,
+                }
             }
             134 => {
-                __reduce134(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 67,
+                }
             }
             135 => {
-                __reduce135(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
             }
             136 => {
-                __reduce136(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 69,
+                }
             }
             137 => {
-                __reduce137(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 69,
+                }
             }
             138 => {
-                __reduce138(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 70,
+                }
             }
             139 => {
-                __reduce139(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 70,
+                }
             }
             140 => {
-                __reduce140(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 71,
+                }
             }
             141 => {
-                __reduce141(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 71,
+                }
             }
             142 => {
-                __reduce142(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 72,
+                }
             }
             143 => {
-                __reduce143(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 72,
+                }
             }
             144 => {
-                __reduce144(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 72,
+                }
             }
             145 => {
-                __reduce145(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 73,
+                }
             }
             146 => {
-                __reduce146(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 73,
+                }
             }
             147 => {
-                __reduce147(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 73,
+                }
             }
             148 => {
-                __reduce148(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 73,
+                }
             }
             149 => {
-                __reduce149(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 74,
+                }
             }
             150 => {
-                __reduce150(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 74,
+                }
             }
             151 => {
-                __reduce151(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 74,
+                }
             }
             152 => {
-                __reduce152(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 74,
+                }
             }
             153 => {
-                __reduce153(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 75,
+                }
             }
             154 => {
-                __reduce154(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 75,
+                }
             }
             155 => {
-                __reduce155(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 75,
+                }
             }
             156 => {
-                __reduce156(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 75,
+                }
             }
             157 => {
-                __reduce157(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 76,
+                }
             }
             158 => {
-                // __Program = Program => ActionFn(0);
-                let __sym0 = __pop_Variant50(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
-                return Some(Ok(__nt));
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 76,
+                }
             }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
-        let __state = *__states.last().unwrap();
-        let __next_state = __goto(__state, __nonterminal);
-        __states.push(__next_state);
-        None
-    }
-    #[inline(never)]
-    fn __symbol_type_mismatch() -> ! {
-        panic!("symbol type mismatch")
-    }
-    fn __pop_Variant12<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (ComparisonOperator, Term<'input>), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant14<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (EqualityOperator, Comparison<'input>), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant16<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (FactorOperator, Unary<'input>), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant18<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (TermOperator, Factor<'input>), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant20<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Arguments<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant21<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Assignment<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant27<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Block<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant22<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Assignment<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant23<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<ExprStatement<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant24<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expression<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant25<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Statement<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant26<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Unary<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant10<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Call<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant28<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, CallRhs<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant30<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ClassDeclaration<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
+            159 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 77,
+                }
+            }
+            160 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 77,
+                }
+            }
+            161 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 78,
+                }
+            }
+            162 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 79,
+                }
+            }
+            163 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 80,
+                }
+            }
+            164 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 81,
+                }
+            }
+            165 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 82,
+                }
+            }
+            166 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 82,
+                }
+            }
+            167 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 83,
+                }
+            }
+            168 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 83,
+                }
+            }
+            169 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 84,
+                }
+            }
+            170 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 84,
+                }
+            }
+            171 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 84,
+                }
+            }
+            172 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 84,
+                }
+            }
+            173 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 85,
+                }
+            }
+            174 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 85,
+                }
+            }
+            175 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 86,
+                }
+            }
+            176 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 86,
+                }
+            }
+            177 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 87,
+                }
+            }
+            178 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 87,
+                }
+            }
+            179 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 88,
+                }
+            }
+            180 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 88,
+                }
+            }
+            181 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 88,
+                }
+            }
+            182 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 88,
+                }
+            }
+            183 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 88,
+                }
+            }
+            184 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 88,
+                }
+            }
+            185 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 88,
+                }
+            }
+            186 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 88,
+                }
+            }
+            187 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 88,
+                }
+            }
+            188 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 88,
+                }
+            }
+            189 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 89,
+                }
+            }
+            190 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 90,
+                }
+            }
+            191 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
+            }
+            192 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 91,
+                }
+            }
+            193 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 91,
+                }
+            }
+            194 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 92,
+                }
+            }
+            195 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 93,
+                }
+            }
+            196 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 94,
+                }
+            }
+            197 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 95,
+                }
+            }
+            198 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 96,
+                }
+            }
+            199 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 97,
+                }
+            }
+            200 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 98,
+                }
+            }
+            201 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 99,
+                }
+            }
+            202 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 99,
+                }
+            }
+            203 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 100,
+                }
+            }
+            204 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 100,
+                }
+            }
+            205 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 101,
+                }
+            }
+            206 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 101,
+                }
+            }
+            207 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 102,
+                }
+            }
+            208 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 102,
+                }
+            }
+            209 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 102,
+                }
+            }
+            210 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 103,
+                }
+            }
+            211 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 103,
+                }
+            }
+            212 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 104,
+                }
+            }
+            213 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 104,
+                }
+            }
+            214 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 104,
+                }
+            }
+            215 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 105,
+                }
+            }
+            216 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 105,
+                }
+            }
+            217 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 106,
+                }
+            }
+            218 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 106,
+                }
+            }
+            219 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 106,
+                }
+            }
+            220 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 106,
+                }
+            }
+            221 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 107,
+                }
+            }
+            222 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 108,
+                }
+            }
+            223 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {}", __reduce_index)
         }
     }
-    fn __pop_Variant31<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Comparison<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+    pub struct ProgramParser {
+        builder: __lalrpop_util::lexer::MatcherBuilder,
+        _priv: (),
     }
-    fn __pop_Variant32<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ComparisonOperator, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
+
+    impl ProgramParser {
+        pub fn new() -> ProgramParser {
+            let __builder = super::__intern_token::new_builder();
+            ProgramParser {
+                builder: __builder,
+                _priv: (),
+            }
         }
-    }
-    fn __pop_Variant33<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Declaration<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'input,
+            'err,
+        >(
+            &self,
+            errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+            input: &'input str,
+        ) -> Result<Program<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = self.builder.matcher(input);
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    errors,
+                    input,
+                    __phantom: core::marker::PhantomData::<(&(), &())>,
+                },
+                __tokens,
+            )
         }
     }
-    fn __pop_Variant6<
-      'input,
+    fn __accepts<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Equality<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __error_state: i16,
+        __states: & [i16],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
         }
     }
-    fn __pop_Variant35<
-      'input,
+    pub(crate) fn __reduce<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, EqualityOperator, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> Option<Result<Program<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            1 => {
+                __reduce1(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            2 => {
+                __reduce2(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            3 => {
+                __reduce3(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            4 => {
+                __reduce4(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            5 => {
+                __reduce5(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            6 => {
+                __reduce6(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            7 => {
+                __reduce7(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            8 => {
+                __reduce8(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            9 => {
+                __reduce9(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            10 => {
+                __reduce10(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            11 => {
+                __reduce11(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            12 => {
+                __reduce12(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            13 => {
+                __reduce13(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            14 => {
+                __reduce14(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            15 => {
+                __reduce15(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            16 => {
+                __reduce16(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            17 => {
+                __reduce17(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            18 => {
+                __reduce18(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            19 => {
+                __reduce19(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            20 => {
+                __reduce20(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            21 => {
+                __reduce21(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            22 => {
+                __reduce22(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            23 => {
+                __reduce23(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            24 => {
+                __reduce24(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            25 => {
+                __reduce25(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            26 => {
+                __reduce26(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            27 => {
+                __reduce27(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            28 => {
+                __reduce28(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            29 => {
+                __reduce29(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            30 => {
+                __reduce30(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            31 => {
+                __reduce31(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            32 => {
+                __reduce32(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            33 => {
+                __reduce33(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            34 => {
+                __reduce34(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            35 => {
+                __reduce35(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            36 => {
+                __reduce36(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            37 => {
+                __reduce37(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            38 => {
+                __reduce38(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            39 => {
+                __reduce39(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            40 => {
+                __reduce40(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            41 => {
+                __reduce41(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            42 => {
+                __reduce42(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            43 => {
+                __reduce43(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            44 => {
+                __reduce44(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            45 => {
+                __reduce45(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            46 => {
+                __reduce46(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            47 => {
+                __reduce47(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            48 => {
+                __reduce48(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            49 => {
+                __reduce49(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            50 => {
+                __reduce50(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            51 => {
+                __reduce51(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            52 => {
+                __reduce52(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            53 => {
+                __reduce53(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            54 => {
+                __reduce54(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            55 => {
+                __reduce55(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            56 => {
+                __reduce56(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            57 => {
+                __reduce57(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            58 => {
+                __reduce58(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            59 => {
+                __reduce59(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            60 => {
+                __reduce60(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            61 => {
+                __reduce61(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            62 => {
+                __reduce62(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            63 => {
+                __reduce63(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            64 => {
+                __reduce64(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            65 => {
+                __reduce65(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            66 => {
+                __reduce66(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            67 => {
+                __reduce67(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            68 => {
+                __reduce68(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            69 => {
+                __reduce69(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            70 => {
+                __reduce70(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            71 => {
+                __reduce71(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            72 => {
+                __reduce72(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            73 => {
+                __reduce73(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            74 => {
+                __reduce74(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            75 => {
+                __reduce75(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            76 => {
+                __reduce76(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            77 => {
+                __reduce77(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            78 => {
+                __reduce78(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            79 => {
+                __reduce79(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            80 => {
+                __reduce80(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            81 => {
+                __reduce81(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            82 => {
+                __reduce82(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            83 => {
+                __reduce83(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            84 => {
+                __reduce84(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            85 => {
+                __reduce85(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            86 => {
+                __reduce86(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            87 => {
+                __reduce87(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            88 => {
+                __reduce88(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            89 => {
+                __reduce89(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            90 => {
+                __reduce90(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            91 => {
+                __reduce91(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            92 => {
+                __reduce92(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            93 => {
+                __reduce93(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            94 => {
+                __reduce94(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            95 => {
+                __reduce95(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            96 => {
+                __reduce96(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            97 => {
+                __reduce97(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            98 => {
+                __reduce98(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            99 => {
+                __reduce99(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            100 => {
+                __reduce100(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            101 => {
+                __reduce101(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            102 => {
+                __reduce102(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            103 => {
+                __reduce103(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            104 => {
+                __reduce104(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            105 => {
+                __reduce105(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            106 => {
+                __reduce106(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            107 => {
+                __reduce107(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            108 => {
+                __reduce108(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            109 => {
+                __reduce109(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            110 => {
+                __reduce110(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            111 => {
+                __reduce111(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            112 => {
+                __reduce112(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            113 => {
+                __reduce113(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            114 => {
+                __reduce114(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            115 => {
+                __reduce115(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            116 => {
+                __reduce116(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            117 => {
+                __reduce117(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            118 => {
+                __reduce118(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            119 => {
+                __reduce119(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            120 => {
+                __reduce120(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            121 => {
+                __reduce121(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            122 => {
+                __reduce122(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            123 => {
+                __reduce123(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            124 => {
+                __reduce124(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            125 => {
+                __reduce125(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            126 => {
+                __reduce126(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            127 => {
+                __reduce127(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            128 => {
+                __reduce128(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            129 => {
+                __reduce129(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            130 => {
+                __reduce130(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            131 => {
+                __reduce131(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            132 => {
+                __reduce132(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            133 => {
+                __reduce133(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            134 => {
+                __reduce134(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            135 => {
+                __reduce135(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            136 => {
+                __reduce136(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            137 => {
+                __reduce137(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            138 => {
+                __reduce138(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            139 => {
+                __reduce139(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            140 => {
+                __reduce140(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            141 => {
+                __reduce141(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            142 => {
+                __reduce142(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            143 => {
+                __reduce143(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            144 => {
+                __reduce144(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            145 => {
+                __reduce145(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            146 => {
+                __reduce146(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            147 => {
+                __reduce147(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            148 => {
+                __reduce148(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            149 => {
+                __reduce149(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            150 => {
+                __reduce150(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            151 => {
+                __reduce151(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            152 => {
+                __reduce152(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            153 => {
+                __reduce153(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            154 => {
+                __reduce154(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            155 => {
+                __reduce155(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            156 => {
+                __reduce156(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            157 => {
+                __reduce157(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            158 => {
+                __reduce158(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            159 => {
+                __reduce159(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            160 => {
+                __reduce160(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            161 => {
+                __reduce161(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            162 => {
+                __reduce162(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            163 => {
+                __reduce163(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            164 => {
+                __reduce164(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            165 => {
+                __reduce165(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            166 => {
+                __reduce166(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            167 => {
+                __reduce167(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            168 => {
+                __reduce168(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            169 => {
+                __reduce169(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            170 => {
+                __reduce170(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            171 => {
+                __reduce171(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            172 => {
+                __reduce172(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            173 => {
+                __reduce173(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            174 => {
+                __reduce174(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            175 => {
+                __reduce175(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            176 => {
+                __reduce176(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            177 => {
+                __reduce177(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            178 => {
+                __reduce178(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            179 => {
+                __reduce179(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            180 => {
+                __reduce180(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            181 => {
+                __reduce181(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            182 => {
+                __reduce182(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            183 => {
+                __reduce183(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            184 => {
+                __reduce184(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            185 => {
+                __reduce185(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            186 => {
+                __reduce186(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            187 => {
+                __reduce187(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            188 => {
+                __reduce188(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            189 => {
+                __reduce189(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            190 => {
+                __reduce190(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            191 => {
+                __reduce191(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            192 => {
+                __reduce192(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            193 => {
+                __reduce193(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            194 => {
+                __reduce194(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            195 => {
+                __reduce195(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            196 => {
+                __reduce196(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            197 => {
+                __reduce197(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            198 => {
+                __reduce198(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            199 => {
+                __reduce199(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            200 => {
+                __reduce200(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            201 => {
+                __reduce201(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            202 => {
+                __reduce202(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            203 => {
+                __reduce203(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            204 => {
+                __reduce204(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            205 => {
+                __reduce205(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            206 => {
+                __reduce206(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            207 => {
+                __reduce207(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            208 => {
+                __reduce208(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            209 => {
+                __reduce209(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            210 => {
+                __reduce210(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            211 => {
+                __reduce211(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            212 => {
+                __reduce212(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            213 => {
+                __reduce213(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            214 => {
+                __reduce214(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            215 => {
+                __reduce215(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            216 => {
+                __reduce216(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            217 => {
+                __reduce217(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            218 => {
+                __reduce218(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            219 => {
+                __reduce219(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            220 => {
+                __reduce220(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            221 => {
+                __reduce221(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            222 => {
+                __reduce222(errors, input, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            223 => {
+                // __Program = Program => ActionFn(0);
+                let __sym0 = __pop_Variant62(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(errors, input, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
+    }
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant15<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<ComparisonOperator>, Term<'input>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<EqualityOperator>, Comparison<'input>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<FactorOperator>, Unary<'input>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant21<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<TermOperator>, Factor<'input>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant27<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Arguments<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant29<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Assignment<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant36<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Block<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant30<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Assignment<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant32<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<ExprStatement<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant33<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expression<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant31<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Statement<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant34<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<TypeAnnotation<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant35<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Unary<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Call<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant37<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, CallRhs<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant37(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant39<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ClassDeclaration<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant39(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant43<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Comparison<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant43(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant44<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ComparisonOperator, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant44(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant45<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Declaration<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant45(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Equality<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant47<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, EqualityOperator, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant47(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant48<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ExprStatement<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant48(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expression<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant49<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Factor<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant49(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant50<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, FactorOperator, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant50(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant51<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ForInitializer<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant51(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant52<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ForStatement<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant52(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant53<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Function<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant53(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant55<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, FunctionDeclaration<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant55(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Identifier<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant56<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, IfStatement<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant56(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, LogicAnd<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant57<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, LogicOr<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant57(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Parameter<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant58<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Parameters<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant58(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant60<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Primary<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant60(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant61<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, PrintStatement<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant61(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant62<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Program<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant62(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant63<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ReturnStatement<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant63(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant64<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<ComparisonOperator>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant64(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant65<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<EqualityOperator>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant65(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant66<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<FactorOperator>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant66(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant68<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<TermOperator>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant68(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant69<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<UnaryOperator>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant69(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant67<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<&'input str>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant67(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant40<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Statement<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant40(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant70<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Term<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant70(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant71<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TermOperator, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant71(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant23<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeAnnotation<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant72<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Unary<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant72(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant73<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, UnaryOperator, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant73(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant74<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, VariableDeclaration<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant74(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant41<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Expression<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant41(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant42<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<TypeAnnotation<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant42(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant75<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, WhileStatement<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant75(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant18<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant20<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant22<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant38<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<CallRhs<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant38(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant46<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Declaration<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant46(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Equality<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Expression<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant54<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Function<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant54(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<LogicAnd<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Parameter<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant24<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<TypeAnnotation<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant28<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Arguments<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Call<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Expression<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Identifier<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant59<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Parameters<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant59(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant26<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<TypeAnnotation<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant25<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant36<
-      'input,
+    pub(crate) fn __reduce0<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Expression>) = ",", Expression => ActionFn(79);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action79::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 0)
+    }
+    pub(crate) fn __reduce1<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Expression>)* =  => ActionFn(77);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action77::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (0, 1)
+    }
+    pub(crate) fn __reduce2<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Expression>)* = ("," <Expression>)+ => ActionFn(78);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action78::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 1)
+    }
+    pub(crate) fn __reduce3<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Expression>)+ = ",", Expression => ActionFn(183);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action183::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 2)
+    }
+    pub(crate) fn __reduce4<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Expression>)+ = ("," <Expression>)+, ",", Expression => ActionFn(184);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action184::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 2)
+    }
+    pub(crate) fn __reduce5<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Parameter>) = ",", Parameter => ActionFn(84);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action84::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 3)
+    }
+    pub(crate) fn __reduce6<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Parameter>)* =  => ActionFn(82);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action82::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (0, 4)
+    }
+    pub(crate) fn __reduce7<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Parameter>)* = ("," <Parameter>)+ => ActionFn(83);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action83::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 4)
+    }
+    pub(crate) fn __reduce8<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Parameter>)+ = ",", Parameter => ActionFn(187);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action187::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 5)
+    }
+    pub(crate) fn __reduce9<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," <Parameter>)+ = ("," <Parameter>)+, ",", Parameter => ActionFn(188);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action188::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 5)
+    }
+    pub(crate) fn __reduce10<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("<" <Identifier>) = "<", Identifier => ActionFn(142);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action142::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 6)
+    }
+    pub(crate) fn __reduce11<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("<" <Identifier>)? = "<", Identifier => ActionFn(191);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action191::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 7)
+    }
+    pub(crate) fn __reduce12<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("<" <Identifier>)? =  => ActionFn(141);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action141::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (0, 7)
+    }
+    pub(crate) fn __reduce13<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("=" <Expression>) = "=", Expression => ActionFn(135);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action135::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 8)
+    }
+    pub(crate) fn __reduce14<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("=" <Expression>)? = "=", Expression => ActionFn(194);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action194::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 9)
+    }
+    pub(crate) fn __reduce15<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("=" <Expression>)? =  => ActionFn(134);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action134::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 9)
+    }
+    pub(crate) fn __reduce16<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("and" <Equality>) = "and", Equality => ActionFn(114);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action114::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 10)
+    }
+    pub(crate) fn __reduce17<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("and" <Equality>)* =  => ActionFn(112);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action112::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 11)
+    }
+    pub(crate) fn __reduce18<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ExprStatement<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("and" <Equality>)* = ("and" <Equality>)+ => ActionFn(113);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action113::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 11)
     }
-    fn __pop_Variant1<
-      'input,
+    pub(crate) fn __reduce19<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expression<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("and" <Equality>)+ = "and", Equality => ActionFn(197);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action197::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 12)
     }
-    fn __pop_Variant37<
-      'input,
+    pub(crate) fn __reduce20<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Factor<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant37(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("and" <Equality>)+ = ("and" <Equality>)+, "and", Equality => ActionFn(198);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action198::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 12)
     }
-    fn __pop_Variant38<
-      'input,
+    pub(crate) fn __reduce21<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, FactorOperator, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant38(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("or" <LogicAnd>) = "or", LogicAnd => ActionFn(117);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action117::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 13)
     }
-    fn __pop_Variant39<
-      'input,
+    pub(crate) fn __reduce22<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ForInitializer<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant39(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("or" <LogicAnd>)* =  => ActionFn(115);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action115::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (0, 14)
+    }
+    pub(crate) fn __reduce23<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("or" <LogicAnd>)* = ("or" <LogicAnd>)+ => ActionFn(116);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action116::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 14)
+    }
+    pub(crate) fn __reduce24<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("or" <LogicAnd>)+ = "or", LogicAnd => ActionFn(201);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action201::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 15)
+    }
+    pub(crate) fn __reduce25<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("or" <LogicAnd>)+ = ("or" <LogicAnd>)+, "or", LogicAnd => ActionFn(202);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant11(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action202::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 15)
+    }
+    pub(crate) fn __reduce26<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Call> ".") = Primary, "." => ActionFn(306);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action306::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 16)
+    }
+    pub(crate) fn __reduce27<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Call> ".") = Primary, CallRhs+, "." => ActionFn(307);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant38(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action307::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (3, 16)
+    }
+    pub(crate) fn __reduce28<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Call> ".")? = Primary, "." => ActionFn(312);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action312::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (2, 17)
+    }
+    pub(crate) fn __reduce29<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Call> ".")? = Primary, CallRhs+, "." => ActionFn(313);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant38(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action313::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (3, 17)
+    }
+    pub(crate) fn __reduce30<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Call> ".")? =  => ActionFn(121);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action121::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 17)
+    }
+    pub(crate) fn __reduce31<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expression> ",") = Expression, "," => ActionFn(169);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action169::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 18)
+    }
+    pub(crate) fn __reduce32<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expression> ",")* =  => ActionFn(167);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action167::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (0, 19)
     }
-    fn __pop_Variant40<
-      'input,
+    pub(crate) fn __reduce33<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ForStatement<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant40(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expression> ",")* = (<Expression> ",")+ => ActionFn(168);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action168::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 19)
     }
-    fn __pop_Variant41<
-      'input,
+    pub(crate) fn __reduce34<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Function<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant41(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expression> ",")+ = Expression, "," => ActionFn(317);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action317::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 20)
     }
-    fn __pop_Variant43<
-      'input,
+    pub(crate) fn __reduce35<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, FunctionDeclaration<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant43(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expression> ",")+ = (<Expression> ",")+, Expression, "," => ActionFn(318);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action318::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 20)
     }
-    fn __pop_Variant44<
-      'input,
+    pub(crate) fn __reduce36<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, IfStatement<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant44(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<ComparisonOperator>> <Term>) = Sp<ComparisonOperator>, Term => ActionFn(106);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant70(__symbols);
+        let __sym0 = __pop_Variant64(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action106::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 21)
     }
-    fn __pop_Variant8<
-      'input,
+    pub(crate) fn __reduce37<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, LogicAnd<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<ComparisonOperator>> <Term>)* =  => ActionFn(104);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action104::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (0, 22)
     }
-    fn __pop_Variant45<
-      'input,
+    pub(crate) fn __reduce38<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, LogicOr<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant45(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<ComparisonOperator>> <Term>)* = (<Sp<ComparisonOperator>> <Term>)+ => ActionFn(105);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action105::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 22)
     }
-    fn __pop_Variant46<
-      'input,
+    pub(crate) fn __reduce39<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Parameters<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant46(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<ComparisonOperator>> <Term>)+ = Sp<ComparisonOperator>, Term => ActionFn(321);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant70(__symbols);
+        let __sym0 = __pop_Variant64(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action321::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (2, 23)
     }
-    fn __pop_Variant48<
-      'input,
+    pub(crate) fn __reduce40<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Primary<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant48(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<ComparisonOperator>> <Term>)+ = (<Sp<ComparisonOperator>> <Term>)+, Sp<ComparisonOperator>, Term => ActionFn(322);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant70(__symbols);
+        let __sym1 = __pop_Variant64(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action322::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (3, 23)
     }
-    fn __pop_Variant49<
-      'input,
+    pub(crate) fn __reduce41<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, PrintStatement<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant49(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<EqualityOperator>> <Comparison>) = Sp<EqualityOperator>, Comparison => ActionFn(110);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant43(__symbols);
+        let __sym0 = __pop_Variant65(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action110::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (2, 24)
     }
-    fn __pop_Variant50<
-      'input,
+    pub(crate) fn __reduce42<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Program<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant50(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<EqualityOperator>> <Comparison>)* =  => ActionFn(108);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action108::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 25)
     }
-    fn __pop_Variant51<
-      'input,
+    pub(crate) fn __reduce43<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ReturnStatement<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant51(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<EqualityOperator>> <Comparison>)* = (<Sp<EqualityOperator>> <Comparison>)+ => ActionFn(109);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action109::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 25)
     }
-    fn __pop_Variant52<
-      'input,
+    pub(crate) fn __reduce44<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Statement<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant52(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<EqualityOperator>> <Comparison>)+ = Sp<EqualityOperator>, Comparison => ActionFn(325);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant43(__symbols);
+        let __sym0 = __pop_Variant65(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action325::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 26)
     }
-    fn __pop_Variant53<
-      'input,
+    pub(crate) fn __reduce45<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Term<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant53(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<EqualityOperator>> <Comparison>)+ = (<Sp<EqualityOperator>> <Comparison>)+, Sp<EqualityOperator>, Comparison => ActionFn(326);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant43(__symbols);
+        let __sym1 = __pop_Variant65(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action326::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (3, 26)
     }
-    fn __pop_Variant54<
-      'input,
+    pub(crate) fn __reduce46<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, TermOperator, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant54(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<FactorOperator>> <Unary>) = Sp<FactorOperator>, Unary => ActionFn(98);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant72(__symbols);
+        let __sym0 = __pop_Variant66(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action98::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (2, 27)
     }
-    fn __pop_Variant55<
-      'input,
+    pub(crate) fn __reduce47<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Unary<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant55(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<FactorOperator>> <Unary>)* =  => ActionFn(96);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action96::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 28)
     }
-    fn __pop_Variant56<
-      'input,
+    pub(crate) fn __reduce48<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, UnaryOperator, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant56(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<FactorOperator>> <Unary>)* = (<Sp<FactorOperator>> <Unary>)+ => ActionFn(97);
+        let __sym0 = __pop_Variant20(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action97::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 28)
     }
-    fn __pop_Variant57<
-      'input,
+    pub(crate) fn __reduce49<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, VariableDeclaration<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant57(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<FactorOperator>> <Unary>)+ = Sp<FactorOperator>, Unary => ActionFn(329);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant72(__symbols);
+        let __sym0 = __pop_Variant66(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action329::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (2, 29)
     }
-    fn __pop_Variant58<
-      'input,
+    pub(crate) fn __reduce50<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, WhileStatement<'input>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant58(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<FactorOperator>> <Unary>)+ = (<Sp<FactorOperator>> <Unary>)+, Sp<FactorOperator>, Unary => ActionFn(330);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant72(__symbols);
+        let __sym1 = __pop_Variant66(__symbols);
+        let __sym0 = __pop_Variant20(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action330::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (3, 29)
     }
-    fn __pop_Variant13<
-      'input,
+    pub(crate) fn __reduce51<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(ComparisonOperator, Term<'input>)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<TermOperator>> <Factor>) = Sp<TermOperator>, Factor => ActionFn(102);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant49(__symbols);
+        let __sym0 = __pop_Variant68(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action102::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (2, 30)
     }
-    fn __pop_Variant15<
-      'input,
+    pub(crate) fn __reduce52<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<TermOperator>> <Factor>)* =  => ActionFn(100);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action100::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (0, 31)
     }
-    fn __pop_Variant17<
-      'input,
+    pub(crate) fn __reduce53<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(FactorOperator, Unary<'input>)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<TermOperator>> <Factor>)* = (<Sp<TermOperator>> <Factor>)+ => ActionFn(101);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action101::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 31)
     }
-    fn __pop_Variant19<
-      'input,
+    pub(crate) fn __reduce54<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(TermOperator, Factor<'input>)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<TermOperator>> <Factor>)+ = Sp<TermOperator>, Factor => ActionFn(333);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant49(__symbols);
+        let __sym0 = __pop_Variant68(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action333::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (2, 32)
     }
-    fn __pop_Variant29<
-      'input,
+    pub(crate) fn __reduce55<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<CallRhs<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Sp<TermOperator>> <Factor>)+ = (<Sp<TermOperator>> <Factor>)+, Sp<TermOperator>, Factor => ActionFn(334);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant49(__symbols);
+        let __sym1 = __pop_Variant68(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action334::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (3, 32)
     }
-    fn __pop_Variant34<
-      'input,
+    pub(crate) fn __reduce56<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Declaration<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypeAnnotation> ",") = TypeAnnotation, "," => ActionFn(176);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action176::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 33)
     }
-    fn __pop_Variant7<
-      'input,
+    pub(crate) fn __reduce57<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Equality<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypeAnnotation> ",")* =  => ActionFn(174);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action174::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (0, 34)
     }
-    fn __pop_Variant2<
-      'input,
+    pub(crate) fn __reduce58<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Expression<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypeAnnotation> ",")* = (<TypeAnnotation> ",")+ => ActionFn(175);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action175::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 34)
     }
-    fn __pop_Variant42<
-      'input,
+    pub(crate) fn __reduce59<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Function<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant42(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypeAnnotation> ",")+ = TypeAnnotation, "," => ActionFn(337);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action337::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (2, 35)
     }
-    fn __pop_Variant9<
-      'input,
+    pub(crate) fn __reduce60<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<LogicAnd<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypeAnnotation> ",")+ = (<TypeAnnotation> ",")+, TypeAnnotation, "," => ActionFn(338);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action338::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (3, 35)
     }
-    fn __pop_Variant3<
-      'input,
+    pub(crate) fn __reduce61<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<&'input str>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // @L =  => ActionFn(144);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action144::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 36)
     }
-    fn __pop_Variant11<
-      'input,
+    pub(crate) fn __reduce62<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Call<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(143);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action143::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 37)
     }
-    fn __pop_Variant5<
-      'input,
+    pub(crate) fn __reduce63<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Expression<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Annotation = ":", TypeAnnotation => ActionFn(71);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action71::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 38)
     }
-    fn __pop_Variant47<
-      'input,
+    pub(crate) fn __reduce64<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Parameters<'input>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant47(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Annotation? = Annotation => ActionFn(136);
+        let __sym0 = __pop_Variant23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action136::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 39)
     }
-    fn __pop_Variant4<
-      'input,
+    pub(crate) fn __reduce65<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<&'input str>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Annotation? =  => ActionFn(137);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action137::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (0, 39)
     }
-    fn __pop_Variant0<
-      'input,
+    pub(crate) fn __reduce66<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Arguments = B<Expression> => ActionFn(185);
+        let __sym0 = __pop_Variant33(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action185::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce0<
+    pub(crate) fn __reduce67<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Expression>) = ",", Expression => ActionFn(66);
+        // Arguments = B<Expression>, ("," <Expression>)+ => ActionFn(186);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant33(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action66::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 0)
+        let __nt = super::__action186::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (2, 40)
     }
-    pub(crate) fn __reduce1<
+    pub(crate) fn __reduce68<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Arguments? = Arguments => ActionFn(90);
+        let __sym0 = __pop_Variant27(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action90::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 41)
+    }
+    pub(crate) fn __reduce69<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Expression>)* =  => ActionFn(64);
+        // Arguments? =  => ActionFn(91);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action64::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (0, 1)
+        let __nt = super::__action91::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (0, 41)
     }
-    pub(crate) fn __reduce2<
+    pub(crate) fn __reduce70<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Expression>)* = ("," <Expression>)+ => ActionFn(65);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Assignment = Primary, ".", Identifier, "=", B<Assignment> => ActionFn(314);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant30(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action65::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action314::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (5, 42)
     }
-    pub(crate) fn __reduce3<
+    pub(crate) fn __reduce71<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Expression>)+ = ",", Expression => ActionFn(134);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Assignment = Primary, CallRhs+, ".", Identifier, "=", B<Assignment> => ActionFn(315);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant30(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant6(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant38(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action134::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 2)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action315::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (6, 42)
     }
-    pub(crate) fn __reduce4<
+    pub(crate) fn __reduce72<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <Expression>)+ = ("," <Expression>)+, ",", Expression => ActionFn(135);
+        // Assignment = Identifier, "=", B<Assignment> => ActionFn(316);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant30(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action135::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 2)
+        let __nt = super::__action316::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (3, 42)
     }
-    pub(crate) fn __reduce5<
+    pub(crate) fn __reduce73<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <IDENTIFIER>) = ",", IDENTIFIER => ActionFn(69);
-        assert!(__symbols.len() >= 2);
+        // Assignment = Primary, "[", B<Expression>, "]", "=", B<Assignment> => ActionFn(308);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant30(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant33(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action69::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
-        (2, 3)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action308::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (6, 42)
     }
-    pub(crate) fn __reduce6<
+    pub(crate) fn __reduce74<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <IDENTIFIER>)* =  => ActionFn(67);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action67::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (0, 4)
+        // Assignment = Primary, CallRhs+, "[", B<Expression>, "]", "=", B<Assignment> => ActionFn(309);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant30(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant33(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant38(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action309::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (7, 42)
     }
-    pub(crate) fn __reduce7<
+    pub(crate) fn __reduce75<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <IDENTIFIER>)* = ("," <IDENTIFIER>)+ => ActionFn(68);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Assignment = LogicOr => ActionFn(33);
+        let __sym0 = __pop_Variant57(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action68::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 4)
+        let __nt = super::__action33::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 42)
     }
-    pub(crate) fn __reduce8<
+    pub(crate) fn __reduce76<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <IDENTIFIER>)+ = ",", IDENTIFIER => ActionFn(138);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // B<Assignment> = Assignment => ActionFn(119);
+        let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action138::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 5)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action119::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 43)
     }
-    pub(crate) fn __reduce9<
+    pub(crate) fn __reduce77<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," <IDENTIFIER>)+ = ("," <IDENTIFIER>)+, ",", IDENTIFIER => ActionFn(139);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // B<ClosedStatement> = ClosedStatement => ActionFn(152);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action139::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 5)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action152::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 44)
     }
-    pub(crate) fn __reduce10<
+    pub(crate) fn __reduce78<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("<" <IDENTIFIER>) = "<", IDENTIFIER => ActionFn(109);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // B<ExprStatement> = ExprStatement => ActionFn(125);
+        let __sym0 = __pop_Variant48(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action109::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
-        (2, 6)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action125::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce11<
+    pub(crate) fn __reduce79<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("<" <IDENTIFIER>)? = "<", IDENTIFIER => ActionFn(142);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // B<Expression> = Expression => ActionFn(118);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action142::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (2, 7)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action118::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (1, 46)
     }
-    pub(crate) fn __reduce12<
+    pub(crate) fn __reduce80<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("<" <IDENTIFIER>)? =  => ActionFn(108);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action108::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 7)
+        // B<OpenStatement> = OpenStatement => ActionFn(151);
+        let __sym0 = __pop_Variant40(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action151::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 47)
     }
-    pub(crate) fn __reduce13<
+    pub(crate) fn __reduce81<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("=" <Expression>) = "=", Expression => ActionFn(104);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // B<Statement> = Statement => ActionFn(132);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action104::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 8)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action132::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 48)
     }
-    pub(crate) fn __reduce14<
+    pub(crate) fn __reduce82<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("=" <Expression>)? = "=", Expression => ActionFn(145);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // B<TypeAnnotation> = TypeAnnotation => ActionFn(80);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action145::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 9)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action80::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 49)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce83<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("=" <Expression>)? =  => ActionFn(103);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action103::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (0, 9)
+        // B<Unary> = Unary => ActionFn(94);
+        let __sym0 = __pop_Variant72(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action94::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 50)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce84<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("and" <Equality>) = "and", Equality => ActionFn(90);
+        // Block = "{", "}" => ActionFn(351);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action90::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 10)
+        let __nt = super::__action351::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (2, 51)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce85<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("and" <Equality>)* =  => ActionFn(88);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action88::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 11)
+        // Block = "{", Declaration+, "}" => ActionFn(352);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant46(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action352::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (3, 51)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce86<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("and" <Equality>)* = ("and" <Equality>)+ => ActionFn(89);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Call = Primary => ActionFn(259);
+        let __sym0 = __pop_Variant60(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action89::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 11)
+        let __nt = super::__action259::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce19<
+    pub(crate) fn __reduce87<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("and" <Equality>)+ = "and", Equality => ActionFn(148);
+        // Call = Primary, CallRhs+ => ActionFn(260);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant38(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action148::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 12)
+        let __nt = super::__action260::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 52)
     }
-    pub(crate) fn __reduce20<
+    pub(crate) fn __reduce88<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("and" <Equality>)+ = ("and" <Equality>)+, "and", Equality => ActionFn(149);
+        // CallRhs = "(", Arguments, ")" => ActionFn(349);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant6(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant27(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action149::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (3, 12)
+        let __nt = super::__action349::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (3, 53)
     }
-    pub(crate) fn __reduce21<
+    pub(crate) fn __reduce89<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("or" <LogicAnd>) = "or", LogicAnd => ActionFn(93);
+        // CallRhs = "(", ")" => ActionFn(350);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action93::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 13)
-    }
-    pub(crate) fn __reduce22<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // ("or" <LogicAnd>)* =  => ActionFn(91);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action91::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 14)
+        let __nt = super::__action350::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (2, 53)
     }
-    pub(crate) fn __reduce23<
+    pub(crate) fn __reduce90<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("or" <LogicAnd>)* = ("or" <LogicAnd>)+ => ActionFn(92);
-        let __sym0 = __pop_Variant9(__symbols);
+        // CallRhs = ".", Identifier => ActionFn(56);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action92::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action56::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (2, 53)
     }
-    pub(crate) fn __reduce24<
+    pub(crate) fn __reduce91<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("or" <LogicAnd>)+ = "or", LogicAnd => ActionFn(152);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant8(__symbols);
+        // CallRhs = "[", B<Expression>, "]" => ActionFn(262);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant33(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action152::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (2, 15)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action262::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (3, 53)
     }
-    pub(crate) fn __reduce25<
+    pub(crate) fn __reduce92<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("or" <LogicAnd>)+ = ("or" <LogicAnd>)+, "or", LogicAnd => ActionFn(153);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant8(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant9(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action153::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (3, 15)
+        // CallRhs* =  => ActionFn(92);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action92::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (0, 54)
     }
-    pub(crate) fn __reduce26<
+    pub(crate) fn __reduce93<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Call> ".") = Primary, "." => ActionFn(158);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant48(__symbols);
+        // CallRhs* = CallRhs+ => ActionFn(93);
+        let __sym0 = __pop_Variant38(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action158::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action93::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce27<
+    pub(crate) fn __reduce94<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Call> ".") = Primary, CallRhs+, "." => ActionFn(159);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant29(__symbols);
-        let __sym0 = __pop_Variant48(__symbols);
+        // CallRhs+ = CallRhs => ActionFn(165);
+        let __sym0 = __pop_Variant37(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action159::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (3, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action165::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce28<
+    pub(crate) fn __reduce95<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Call> ".")? = Primary, "." => ActionFn(162);
+        // CallRhs+ = CallRhs+, CallRhs => ActionFn(166);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant48(__symbols);
+        let __sym1 = __pop_Variant37(__symbols);
+        let __sym0 = __pop_Variant38(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action162::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (2, 17)
+        let __nt = super::__action166::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (2, 55)
     }
-    pub(crate) fn __reduce29<
+    pub(crate) fn __reduce96<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Call> ".")? = Primary, CallRhs+, "." => ActionFn(163);
-        assert!(__symbols.len() >= 3);
+        // ClassDeclaration = "class", Identifier, "<", Identifier, "{", "}" => ActionFn(369);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant6(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant29(__symbols);
-        let __sym0 = __pop_Variant48(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action163::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (3, 17)
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action369::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
+        (6, 56)
     }
-    pub(crate) fn __reduce30<
+    pub(crate) fn __reduce97<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Call> ".")? =  => ActionFn(96);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action96::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (0, 17)
+        // ClassDeclaration = "class", Identifier, "<", Identifier, "{", Function+, "}" => ActionFn(370);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant54(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant6(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action370::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
+        (7, 56)
     }
-    pub(crate) fn __reduce31<
+    pub(crate) fn __reduce98<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<ComparisonOperator> <Term>) = ComparisonOperator, Term => ActionFn(84);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant53(__symbols);
-        let __sym0 = __pop_Variant32(__symbols);
+        // ClassDeclaration = "class", Identifier, "{", "}" => ActionFn(371);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action84::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (2, 18)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action371::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
+        (4, 56)
     }
-    pub(crate) fn __reduce32<
+    pub(crate) fn __reduce99<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<ComparisonOperator> <Term>)* =  => ActionFn(82);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action82::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 19)
+        // ClassDeclaration = "class", Identifier, "{", Function+, "}" => ActionFn(372);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant54(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action372::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
+        (5, 56)
     }
-    pub(crate) fn __reduce33<
+    pub(crate) fn __reduce100<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<ComparisonOperator> <Term>)* = (<ComparisonOperator> <Term>)+ => ActionFn(83);
-        let __sym0 = __pop_Variant13(__symbols);
+        // ClosedStatement = ExprStatement => ActionFn(16);
+        let __sym0 = __pop_Variant48(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action83::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 19)
+        let __nt = super::__action16::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce34<
+    pub(crate) fn __reduce101<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<ComparisonOperator> <Term>)+ = ComparisonOperator, Term => ActionFn(167);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant53(__symbols);
-        let __sym0 = __pop_Variant32(__symbols);
+        // ClosedStatement = ForStatement<ClosedStatement> => ActionFn(17);
+        let __sym0 = __pop_Variant52(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action167::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 20)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action17::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce35<
+    pub(crate) fn __reduce102<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<ComparisonOperator> <Term>)+ = (<ComparisonOperator> <Term>)+, ComparisonOperator, Term => ActionFn(168);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant53(__symbols);
-        let __sym1 = __pop_Variant32(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
+        // ClosedStatement = IfElseStatement<ClosedStatement> => ActionFn(18);
+        let __sym0 = __pop_Variant56(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action168::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 20)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action18::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce36<
+    pub(crate) fn __reduce103<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<EqualityOperator> <Comparison>) = EqualityOperator, Comparison => ActionFn(87);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant31(__symbols);
-        let __sym0 = __pop_Variant35(__symbols);
+        // ClosedStatement = PrintStatement => ActionFn(19);
+        let __sym0 = __pop_Variant61(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action87::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (2, 21)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action19::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce37<
+    pub(crate) fn __reduce104<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<EqualityOperator> <Comparison>)* =  => ActionFn(85);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action85::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (0, 22)
+        // ClosedStatement = ReturnStatement => ActionFn(20);
+        let __sym0 = __pop_Variant63(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action20::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce38<
+    pub(crate) fn __reduce105<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<EqualityOperator> <Comparison>)* = (<EqualityOperator> <Comparison>)+ => ActionFn(86);
-        let __sym0 = __pop_Variant15(__symbols);
+        // ClosedStatement = WhileStatement<ClosedStatement> => ActionFn(21);
+        let __sym0 = __pop_Variant75(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action86::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 22)
+        let __nt = super::__action21::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce39<
+    pub(crate) fn __reduce106<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<EqualityOperator> <Comparison>)+ = EqualityOperator, Comparison => ActionFn(171);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant31(__symbols);
-        let __sym0 = __pop_Variant35(__symbols);
+        // ClosedStatement = Block => ActionFn(22);
+        let __sym0 = __pop_Variant36(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action171::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (2, 23)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action22::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce40<
+    pub(crate) fn __reduce107<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<EqualityOperator> <Comparison>)+ = (<EqualityOperator> <Comparison>)+, EqualityOperator, Comparison => ActionFn(172);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant31(__symbols);
-        let __sym1 = __pop_Variant35(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // Comma<Expression> = Expression => ActionFn(355);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action172::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (3, 23)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action355::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
+        (1, 58)
     }
-    pub(crate) fn __reduce41<
+    pub(crate) fn __reduce108<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<FactorOperator> <Unary>) = FactorOperator, Unary => ActionFn(78);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant55(__symbols);
-        let __sym0 = __pop_Variant38(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action78::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (2, 24)
+        // Comma<Expression> =  => ActionFn(356);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action356::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
+        (0, 58)
     }
-    pub(crate) fn __reduce42<
+    pub(crate) fn __reduce109<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<FactorOperator> <Unary>)* =  => ActionFn(76);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action76::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (0, 25)
+        // Comma<Expression> = (<Expression> ",")+, Expression => ActionFn(357);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action357::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
+        (2, 58)
     }
-    pub(crate) fn __reduce43<
+    pub(crate) fn __reduce110<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<FactorOperator> <Unary>)* = (<FactorOperator> <Unary>)+ => ActionFn(77);
-        let __sym0 = __pop_Variant17(__symbols);
+        // Comma<Expression> = (<Expression> ",")+ => ActionFn(358);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action77::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 25)
+        let __nt = super::__action358::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
+        (1, 58)
     }
-    pub(crate) fn __reduce44<
+    pub(crate) fn __reduce111<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<FactorOperator> <Unary>)+ = FactorOperator, Unary => ActionFn(175);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant55(__symbols);
-        let __sym0 = __pop_Variant38(__symbols);
+        // Comma<TypeAnnotation> = TypeAnnotation => ActionFn(377);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action175::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (2, 26)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action377::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
+        (1, 59)
     }
-    pub(crate) fn __reduce45<
+    pub(crate) fn __reduce112<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<FactorOperator> <Unary>)+ = (<FactorOperator> <Unary>)+, FactorOperator, Unary => ActionFn(176);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant55(__symbols);
-        let __sym1 = __pop_Variant38(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action176::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (3, 26)
+        // Comma<TypeAnnotation> =  => ActionFn(378);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action378::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
+        (0, 59)
     }
-    pub(crate) fn __reduce46<
+    pub(crate) fn __reduce113<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<TermOperator> <Factor>) = TermOperator, Factor => ActionFn(81);
+        // Comma<TypeAnnotation> = (<TypeAnnotation> ",")+, TypeAnnotation => ActionFn(379);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant37(__symbols);
-        let __sym0 = __pop_Variant54(__symbols);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action81::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (2, 27)
+        let __nt = super::__action379::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
+        (2, 59)
     }
-    pub(crate) fn __reduce47<
+    pub(crate) fn __reduce114<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<TermOperator> <Factor>)* =  => ActionFn(79);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action79::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (0, 28)
+        // Comma<TypeAnnotation> = (<TypeAnnotation> ",")+ => ActionFn(380);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action380::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
+        (1, 59)
     }
-    pub(crate) fn __reduce48<
+    pub(crate) fn __reduce115<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<TermOperator> <Factor>)* = (<TermOperator> <Factor>)+ => ActionFn(80);
-        let __sym0 = __pop_Variant19(__symbols);
+        // Comparison = Term => ActionFn(323);
+        let __sym0 = __pop_Variant70(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action80::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 28)
+        let __nt = super::__action323::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant43(__nt), __end));
+        (1, 60)
     }
-    pub(crate) fn __reduce49<
+    pub(crate) fn __reduce116<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<TermOperator> <Factor>)+ = TermOperator, Factor => ActionFn(179);
+        // Comparison = Term, (<Sp<ComparisonOperator>> <Term>)+ => ActionFn(324);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant37(__symbols);
-        let __sym0 = __pop_Variant54(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant70(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action179::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 29)
-    }
-    pub(crate) fn __reduce50<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<TermOperator> <Factor>)+ = (<TermOperator> <Factor>)+, TermOperator, Factor => ActionFn(180);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant37(__symbols);
-        let __sym1 = __pop_Variant54(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action180::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (3, 29)
+        let __nt = super::__action324::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant43(__nt), __end));
+        (2, 60)
     }
-    pub(crate) fn __reduce51<
+    pub(crate) fn __reduce117<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Arguments = B<Expression> => ActionFn(136);
-        let __sym0 = __pop_Variant24(__symbols);
+        // ComparisonOperator = ">" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action136::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 30)
+        let __nt = super::__action40::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (1, 61)
     }
-    pub(crate) fn __reduce52<
+    pub(crate) fn __reduce118<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Arguments = B<Expression>, ("," <Expression>)+ => ActionFn(137);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant24(__symbols);
+        // ComparisonOperator = ">=" => ActionFn(41);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action137::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (2, 30)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (1, 61)
     }
-    pub(crate) fn __reduce53<
+    pub(crate) fn __reduce119<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Assignment = Primary, ".", IDENTIFIER, "=", B<Assignment> => ActionFn(164);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant22(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant48(__symbols);
+        // ComparisonOperator = "<" => ActionFn(42);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action164::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (5, 31)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (1, 61)
     }
-    pub(crate) fn __reduce54<
+    pub(crate) fn __reduce120<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Assignment = Primary, CallRhs+, ".", IDENTIFIER, "=", B<Assignment> => ActionFn(165);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant22(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant29(__symbols);
-        let __sym0 = __pop_Variant48(__symbols);
+        // ComparisonOperator = "<=" => ActionFn(43);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action165::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (6, 31)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (1, 61)
     }
-    pub(crate) fn __reduce55<
+    pub(crate) fn __reduce121<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Assignment = IDENTIFIER, "=", B<Assignment> => ActionFn(166);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant22(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Declaration = ClassDeclaration => ActionFn(2);
+        let __sym0 = __pop_Variant39(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action166::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (3, 31)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 62)
     }
-    pub(crate) fn __reduce56<
+    pub(crate) fn __reduce122<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Assignment = LogicOr => ActionFn(28);
-        let __sym0 = __pop_Variant45(__symbols);
+        // Declaration = FunctionDeclaration => ActionFn(3);
+        let __sym0 = __pop_Variant55(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 31)
+        let __nt = super::__action3::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 62)
     }
-    pub(crate) fn __reduce57<
+    pub(crate) fn __reduce123<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // B<Assignment> = Assignment => ActionFn(94);
-        let __sym0 = __pop_Variant21(__symbols);
+        // Declaration = VariableDeclaration => ActionFn(4);
+        let __sym0 = __pop_Variant74(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action94::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 32)
+        let __nt = super::__action4::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 62)
     }
-    pub(crate) fn __reduce58<
+    pub(crate) fn __reduce124<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // B<ExprStatement> = ExprStatement => ActionFn(98);
-        let __sym0 = __pop_Variant36(__symbols);
+        // Declaration = Statement => ActionFn(5);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action98::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 33)
+        let __nt = super::__action5::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 62)
     }
-    pub(crate) fn __reduce59<
+    pub(crate) fn __reduce125<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // B<Expression> = Expression => ActionFn(72);
+        // Declaration = error, ";" => ActionFn(266);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action72::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 34)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action266::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (2, 62)
     }
-    pub(crate) fn __reduce60<
+    pub(crate) fn __reduce126<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // B<Statement> = Statement => ActionFn(99);
-        let __sym0 = __pop_Variant52(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action99::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 35)
+        // Declaration* =  => ActionFn(145);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action145::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
+        (0, 63)
     }
-    pub(crate) fn __reduce61<
+    pub(crate) fn __reduce127<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // B<Unary> = Unary => ActionFn(75);
-        let __sym0 = __pop_Variant55(__symbols);
+        // Declaration* = Declaration+ => ActionFn(146);
+        let __sym0 = __pop_Variant46(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action75::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (1, 36)
+        let __nt = super::__action146::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
+        (1, 63)
     }
-    pub(crate) fn __reduce62<
+    pub(crate) fn __reduce128<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Block = "{", "}" => ActionFn(183);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Declaration+ = Declaration => ActionFn(147);
+        let __sym0 = __pop_Variant45(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action183::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (2, 37)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action147::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
+        (1, 64)
     }
-    pub(crate) fn __reduce63<
+    pub(crate) fn __reduce129<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Block = "{", Declaration+, "}" => ActionFn(184);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant34(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Declaration+ = Declaration+, Declaration => ActionFn(148);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant45(__symbols);
+        let __sym0 = __pop_Variant46(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action184::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (3, 37)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action148::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
+        (2, 64)
     }
-    pub(crate) fn __reduce64<
+    pub(crate) fn __reduce130<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Call = Primary => ActionFn(156);
-        let __sym0 = __pop_Variant48(__symbols);
+        // Equality = Comparison => ActionFn(327);
+        let __sym0 = __pop_Variant43(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action156::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 38)
+        let __nt = super::__action327::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 65)
     }
-    pub(crate) fn __reduce65<
+    pub(crate) fn __reduce131<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Call = Primary, CallRhs+ => ActionFn(157);
+        // Equality = Comparison, (<Sp<EqualityOperator>> <Comparison>)+ => ActionFn(328);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant29(__symbols);
-        let __sym0 = __pop_Variant48(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant43(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action157::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 38)
+        let __nt = super::__action328::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 65)
     }
-    pub(crate) fn __reduce66<
+    pub(crate) fn __reduce132<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // CallRhs = "(", Arguments, ")" => ActionFn(50);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant20(__symbols);
+        // EqualityOperator = "!=" => ActionFn(37);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action50::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (3, 39)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant47(__nt), __end));
+        (1, 66)
     }
-    pub(crate) fn __reduce67<
+    pub(crate) fn __reduce133<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // CallRhs = ".", IDENTIFIER => ActionFn(51);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
+        // EqualityOperator = "==" => ActionFn(38);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action51::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (2, 39)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant47(__nt), __end));
+        (1, 66)
     }
-    pub(crate) fn __reduce68<
+    pub(crate) fn __reduce134<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // CallRhs* =  => ActionFn(73);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action73::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (0, 40)
+        // ExprStatement = Expression, ";" => ActionFn(268);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action268::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
+        (2, 67)
     }
-    pub(crate) fn __reduce69<
+    pub(crate) fn __reduce135<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // CallRhs* = CallRhs+ => ActionFn(74);
+        // Expression = Assignment => ActionFn(30);
         let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action74::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (1, 40)
+        let __nt = super::__action30::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 68)
     }
-    pub(crate) fn __reduce70<
+    pub(crate) fn __reduce136<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // CallRhs+ = CallRhs => ActionFn(128);
-        let __sym0 = __pop_Variant28(__symbols);
+        // Expression? = Expression => ActionFn(123);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action128::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (1, 41)
+        let __nt = super::__action123::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 69)
     }
-    pub(crate) fn __reduce71<
+    pub(crate) fn __reduce137<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // CallRhs+ = CallRhs+, CallRhs => ActionFn(129);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant28(__symbols);
-        let __sym0 = __pop_Variant29(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action129::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (2, 41)
+        // Expression? =  => ActionFn(124);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action124::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 69)
     }
-    pub(crate) fn __reduce72<
+    pub(crate) fn __reduce138<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ClassDeclaration = "class", IDENTIFIER, "<", IDENTIFIER, "{", "}" => ActionFn(193);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Factor = Unary => ActionFn(331);
+        let __sym0 = __pop_Variant72(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action193::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
-        (6, 42)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action331::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant49(__nt), __end));
+        (1, 70)
     }
-    pub(crate) fn __reduce73<
+    pub(crate) fn __reduce139<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ClassDeclaration = "class", IDENTIFIER, "<", IDENTIFIER, "{", Function+, "}" => ActionFn(194);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant42(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Factor = Unary, (<Sp<FactorOperator>> <Unary>)+ => ActionFn(332);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant72(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action194::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
-        (7, 42)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action332::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant49(__nt), __end));
+        (2, 70)
     }
-    pub(crate) fn __reduce74<
+    pub(crate) fn __reduce140<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ClassDeclaration = "class", IDENTIFIER, "{", "}" => ActionFn(195);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // FactorOperator = "/" => ActionFn(48);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action195::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
-        (4, 42)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action48::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant50(__nt), __end));
+        (1, 71)
     }
-    pub(crate) fn __reduce75<
+    pub(crate) fn __reduce141<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ClassDeclaration = "class", IDENTIFIER, "{", Function+, "}" => ActionFn(196);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant42(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // FactorOperator = "*" => ActionFn(49);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action196::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
-        (5, 42)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action49::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant50(__nt), __end));
+        (1, 71)
     }
-    pub(crate) fn __reduce76<
+    pub(crate) fn __reduce142<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comparison = Term => ActionFn(169);
-        let __sym0 = __pop_Variant53(__symbols);
+        // ForInitializer = VariableDeclaration => ActionFn(24);
+        let __sym0 = __pop_Variant74(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action169::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (1, 43)
+        let __nt = super::__action24::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant51(__nt), __end));
+        (1, 72)
     }
-    pub(crate) fn __reduce77<
+    pub(crate) fn __reduce143<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comparison = Term, (<ComparisonOperator> <Term>)+ => ActionFn(170);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant53(__symbols);
+        // ForInitializer = B<ExprStatement> => ActionFn(25);
+        let __sym0 = __pop_Variant32(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action170::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (2, 43)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant51(__nt), __end));
+        (1, 72)
     }
-    pub(crate) fn __reduce78<
+    pub(crate) fn __reduce144<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ComparisonOperator = ">" => ActionFn(35);
+        // ForInitializer = ";" => ActionFn(26);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action35::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (1, 44)
+        let __nt = super::__action26::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant51(__nt), __end));
+        (1, 72)
     }
-    pub(crate) fn __reduce79<
+    pub(crate) fn __reduce145<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ComparisonOperator = ">=" => ActionFn(36);
+        // ForStatement<ClosedStatement> = "for", "(", ForInitializer, Expression, ";", Expression, ")", B<ClosedStatement> => ActionFn(359);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant31(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant2(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant51(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (1, 44)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action359::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (8, 73)
+    }
+    pub(crate) fn __reduce146<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ForStatement<ClosedStatement> = "for", "(", ForInitializer, Expression, ";", ")", B<ClosedStatement> => ActionFn(360);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant31(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant51(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action360::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (7, 73)
     }
-    pub(crate) fn __reduce80<
+    pub(crate) fn __reduce147<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ComparisonOperator = "<" => ActionFn(37);
+        // ForStatement<ClosedStatement> = "for", "(", ForInitializer, ";", Expression, ")", B<ClosedStatement> => ActionFn(361);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant31(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant51(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action37::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (1, 44)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action361::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (7, 73)
     }
-    pub(crate) fn __reduce81<
+    pub(crate) fn __reduce148<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ComparisonOperator = "<=" => ActionFn(38);
+        // ForStatement<ClosedStatement> = "for", "(", ForInitializer, ";", ")", B<ClosedStatement> => ActionFn(362);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant31(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant51(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (1, 44)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action362::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (6, 73)
     }
-    pub(crate) fn __reduce82<
+    pub(crate) fn __reduce149<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Declaration = ClassDeclaration => ActionFn(2);
-        let __sym0 = __pop_Variant30(__symbols);
+        // ForStatement<OpenStatement> = "for", "(", ForInitializer, Expression, ";", Expression, ")", B<OpenStatement> => ActionFn(363);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant31(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant2(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant51(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 45)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action363::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (8, 74)
     }
-    pub(crate) fn __reduce83<
+    pub(crate) fn __reduce150<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Declaration = FunctionDeclaration => ActionFn(3);
-        let __sym0 = __pop_Variant43(__symbols);
+        // ForStatement<OpenStatement> = "for", "(", ForInitializer, Expression, ";", ")", B<OpenStatement> => ActionFn(364);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant31(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant51(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action3::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 45)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action364::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (7, 74)
     }
-    pub(crate) fn __reduce84<
+    pub(crate) fn __reduce151<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Declaration = VariableDeclaration => ActionFn(4);
-        let __sym0 = __pop_Variant57(__symbols);
+        // ForStatement<OpenStatement> = "for", "(", ForInitializer, ";", Expression, ")", B<OpenStatement> => ActionFn(365);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant31(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant51(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action4::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 45)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action365::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (7, 74)
     }
-    pub(crate) fn __reduce85<
+    pub(crate) fn __reduce152<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Declaration = Statement => ActionFn(5);
-        let __sym0 = __pop_Variant52(__symbols);
+        // ForStatement<OpenStatement> = "for", "(", ForInitializer, ";", ")", B<OpenStatement> => ActionFn(366);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant31(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant51(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 45)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action366::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
+        (6, 74)
     }
-    pub(crate) fn __reduce86<
+    pub(crate) fn __reduce153<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Declaration* =  => ActionFn(110);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action110::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (0, 46)
+        // Function = Identifier, "(", Parameters, ")", Annotation, Block => ActionFn(373);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant36(__symbols);
+        let __sym4 = __pop_Variant23(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant58(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action373::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant53(__nt), __end));
+        (6, 75)
     }
-    pub(crate) fn __reduce87<
+    pub(crate) fn __reduce154<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Declaration* = Declaration+ => ActionFn(111);
-        let __sym0 = __pop_Variant34(__symbols);
+        // Function = Identifier, "(", ")", Annotation, Block => ActionFn(374);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant36(__symbols);
+        let __sym3 = __pop_Variant23(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action111::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (1, 46)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action374::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant53(__nt), __end));
+        (5, 75)
     }
-    pub(crate) fn __reduce88<
+    pub(crate) fn __reduce155<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Declaration+ = Declaration => ActionFn(112);
-        let __sym0 = __pop_Variant33(__symbols);
+        // Function = Identifier, "(", Parameters, ")", Block => ActionFn(375);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant36(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant58(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action112::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (1, 47)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action375::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant53(__nt), __end));
+        (5, 75)
     }
-    pub(crate) fn __reduce89<
+    pub(crate) fn __reduce156<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Declaration+ = Declaration+, Declaration => ActionFn(113);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant33(__symbols);
-        let __sym0 = __pop_Variant34(__symbols);
+        // Function = Identifier, "(", ")", Block => ActionFn(376);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant36(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action113::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (2, 47)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action376::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant53(__nt), __end));
+        (4, 75)
     }
-    pub(crate) fn __reduce90<
+    pub(crate) fn __reduce157<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Equality = Comparison => ActionFn(173);
-        let __sym0 = __pop_Variant31(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action173::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 48)
+        // Function* =  => ActionFn(138);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action138::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant54(__nt), __end));
+        (0, 76)
     }
-    pub(crate) fn __reduce91<
+    pub(crate) fn __reduce158<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Equality = Comparison, (<EqualityOperator> <Comparison>)+ => ActionFn(174);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant31(__symbols);
+        // Function* = Function+ => ActionFn(139);
+        let __sym0 = __pop_Variant54(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action174::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 48)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action139::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant54(__nt), __end));
+        (1, 76)
     }
-    pub(crate) fn __reduce92<
+    pub(crate) fn __reduce159<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // EqualityOperator = "!=" => ActionFn(32);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Function+ = Function => ActionFn(149);
+        let __sym0 = __pop_Variant53(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action32::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
-        (1, 49)
+        let __nt = super::__action149::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant54(__nt), __end));
+        (1, 77)
     }
-    pub(crate) fn __reduce93<
+    pub(crate) fn __reduce160<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // EqualityOperator = "==" => ActionFn(33);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Function+ = Function+, Function => ActionFn(150);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant53(__symbols);
+        let __sym0 = __pop_Variant54(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
-        (1, 49)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action150::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant54(__nt), __end));
+        (2, 77)
     }
-    pub(crate) fn __reduce94<
+    pub(crate) fn __reduce161<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ExprStatement = Expression, ";" => ActionFn(16);
+        // FunctionDeclaration = "fun", Function => ActionFn(273);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant53(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action16::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
-        (2, 50)
+        let __nt = super::__action273::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant55(__nt), __end));
+        (2, 78)
     }
-    pub(crate) fn __reduce95<
+    pub(crate) fn __reduce162<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Expression = Assignment => ActionFn(26);
-        let __sym0 = __pop_Variant21(__symbols);
+        // Identifier = IDENTIFIER => ActionFn(274);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 51)
+        let __nt = super::__action274::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 79)
     }
-    pub(crate) fn __reduce96<
+    pub(crate) fn __reduce163<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Expression? = Expression => ActionFn(100);
-        let __sym0 = __pop_Variant1(__symbols);
+        // IfElseStatement<ClosedStatement> = "if", "(", Expression, ")", B<ClosedStatement>, "else", B<ClosedStatement> => ActionFn(275);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant31(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant31(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action100::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 52)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action275::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant56(__nt), __end));
+        (7, 80)
     }
-    pub(crate) fn __reduce97<
+    pub(crate) fn __reduce164<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Expression? =  => ActionFn(101);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action101::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (0, 52)
+        // IfElseStatement<OpenStatement> = "if", "(", Expression, ")", B<ClosedStatement>, "else", B<OpenStatement> => ActionFn(276);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant31(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant31(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action276::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant56(__nt), __end));
+        (7, 81)
     }
-    pub(crate) fn __reduce98<
+    pub(crate) fn __reduce165<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Factor = Unary => ActionFn(177);
-        let __sym0 = __pop_Variant55(__symbols);
+        // LogicAnd = Equality => ActionFn(277);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action177::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
-        (1, 53)
+        let __nt = super::__action277::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 82)
     }
-    pub(crate) fn __reduce99<
+    pub(crate) fn __reduce166<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Factor = Unary, (<FactorOperator> <Unary>)+ => ActionFn(178);
+        // LogicAnd = Equality, ("and" <Equality>)+ => ActionFn(278);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant55(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action178::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
-        (2, 53)
+        let __nt = super::__action278::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 82)
     }
-    pub(crate) fn __reduce100<
+    pub(crate) fn __reduce167<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FactorOperator = "/" => ActionFn(43);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LogicOr = LogicAnd => ActionFn(279);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
-        (1, 54)
+        let __nt = super::__action279::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant57(__nt), __end));
+        (1, 83)
     }
-    pub(crate) fn __reduce101<
+    pub(crate) fn __reduce168<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FactorOperator = "*" => ActionFn(44);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LogicOr = LogicAnd, ("or" <LogicAnd>)+ => ActionFn(280);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
-        (1, 54)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action280::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant57(__nt), __end));
+        (2, 83)
     }
-    pub(crate) fn __reduce102<
+    pub(crate) fn __reduce169<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ForInitializer = VariableDeclaration => ActionFn(18);
-        let __sym0 = __pop_Variant57(__symbols);
+        // OpenStatement = "if", "(", Expression, ")", B<Statement> => ActionFn(281);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant31(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
-        (1, 55)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action281::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (5, 84)
     }
-    pub(crate) fn __reduce103<
+    pub(crate) fn __reduce170<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ForInitializer = B<ExprStatement> => ActionFn(19);
-        let __sym0 = __pop_Variant23(__symbols);
+        // OpenStatement = IfElseStatement<OpenStatement> => ActionFn(13);
+        let __sym0 = __pop_Variant56(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
-        (1, 55)
+        let __nt = super::__action13::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 84)
     }
-    pub(crate) fn __reduce104<
+    pub(crate) fn __reduce171<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ForInitializer = ";" => ActionFn(20);
-        let __sym0 = __pop_Variant0(__symbols);
+        // OpenStatement = ForStatement<OpenStatement> => ActionFn(14);
+        let __sym0 = __pop_Variant52(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
-        (1, 55)
+        let __nt = super::__action14::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 84)
     }
-    pub(crate) fn __reduce105<
+    pub(crate) fn __reduce172<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ForStatement = "for", "(", ForInitializer, Expression, ";", Expression, ")", B<Statement> => ActionFn(187);
-        assert!(__symbols.len() >= 8);
-        let __sym7 = __pop_Variant25(__symbols);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant1(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant39(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // OpenStatement = WhileStatement<OpenStatement> => ActionFn(15);
+        let __sym0 = __pop_Variant75(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym7.2.clone();
-        let __nt = super::__action187::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action15::<>(errors, input, __sym0);
         __symbols.push((__start, __Symbol::Variant40(__nt), __end));
-        (8, 56)
+        (1, 84)
     }
-    pub(crate) fn __reduce106<
+    pub(crate) fn __reduce173<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // ForStatement = "for", "(", ForInitializer, Expression, ";", ")", B<Statement> => ActionFn(188);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant25(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant39(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Parameter = Identifier, Annotation => ActionFn(343);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action188::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
-        (7, 56)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action343::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 85)
     }
-    pub(crate) fn __reduce107<
+    pub(crate) fn __reduce174<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ForStatement = "for", "(", ForInitializer, ";", Expression, ")", B<Statement> => ActionFn(189);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant25(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant39(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Parameter = Identifier => ActionFn(344);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action189::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
-        (7, 56)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action344::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 85)
     }
-    pub(crate) fn __reduce108<
+    pub(crate) fn __reduce175<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ForStatement = "for", "(", ForInitializer, ";", ")", B<Statement> => ActionFn(190);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant25(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant39(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Parameters = Parameter => ActionFn(189);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action190::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
-        (6, 56)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action189::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant58(__nt), __end));
+        (1, 86)
     }
-    pub(crate) fn __reduce109<
+    pub(crate) fn __reduce176<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Function = IDENTIFIER, "(", Parameters, ")", Block => ActionFn(197);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant27(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant46(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Parameters = Parameter, ("," <Parameter>)+ => ActionFn(190);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action197::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
-        (5, 57)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action190::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant58(__nt), __end));
+        (2, 86)
     }
-    pub(crate) fn __reduce110<
+    pub(crate) fn __reduce177<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Function = IDENTIFIER, "(", ")", Block => ActionFn(198);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant27(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Parameters? = Parameters => ActionFn(85);
+        let __sym0 = __pop_Variant58(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action198::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
-        (4, 57)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action85::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant59(__nt), __end));
+        (1, 87)
     }
-    pub(crate) fn __reduce111<
+    pub(crate) fn __reduce178<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Function* =  => ActionFn(105);
+        // Parameters? =  => ActionFn(86);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action105::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
-        (0, 58)
+        let __nt = super::__action86::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant59(__nt), __end));
+        (0, 87)
     }
-    pub(crate) fn __reduce112<
+    pub(crate) fn __reduce179<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Function* = Function+ => ActionFn(106);
-        let __sym0 = __pop_Variant42(__symbols);
+        // Primary = "true" => ActionFn(282);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action106::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
-        (1, 58)
+        let __nt = super::__action282::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (1, 88)
     }
-    pub(crate) fn __reduce113<
+    pub(crate) fn __reduce180<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Function+ = Function => ActionFn(114);
-        let __sym0 = __pop_Variant41(__symbols);
+        // Primary = "false" => ActionFn(283);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action114::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
-        (1, 59)
+        let __nt = super::__action283::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (1, 88)
     }
-    pub(crate) fn __reduce114<
+    pub(crate) fn __reduce181<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Function+ = Function+, Function => ActionFn(115);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant41(__symbols);
-        let __sym0 = __pop_Variant42(__symbols);
+        // Primary = "nil" => ActionFn(284);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action115::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
-        (2, 59)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action284::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (1, 88)
     }
-    pub(crate) fn __reduce115<
+    pub(crate) fn __reduce182<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FunctionDeclaration = "fun", Function => ActionFn(7);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant41(__symbols);
+        // Primary = "this" => ActionFn(285);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action7::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant43(__nt), __end));
-        (2, 60)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action285::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (1, 88)
     }
-    pub(crate) fn __reduce116<
+    pub(crate) fn __reduce183<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IfStatement = "if", "(", Expression, ")", B<Statement> => ActionFn(21);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant25(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Primary = Sp<NUMBER> => ActionFn(62);
+        let __sym0 = __pop_Variant67(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action21::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
-        (5, 61)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action62::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (1, 88)
     }
-    pub(crate) fn __reduce117<
+    pub(crate) fn __reduce184<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LogicAnd = Equality => ActionFn(150);
-        let __sym0 = __pop_Variant6(__symbols);
+        // Primary = Sp<STRING> => ActionFn(63);
+        let __sym0 = __pop_Variant67(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action150::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 62)
+        let __nt = super::__action63::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (1, 88)
     }
-    pub(crate) fn __reduce118<
+    pub(crate) fn __reduce185<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LogicAnd = Equality, ("and" <Equality>)+ => ActionFn(151);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
+        // Primary = Identifier => ActionFn(64);
         let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action151::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 62)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (1, 88)
     }
-    pub(crate) fn __reduce119<
+    pub(crate) fn __reduce186<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LogicOr = LogicAnd => ActionFn(154);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Primary = "(", B<Expression>, ")" => ActionFn(286);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant33(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action154::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
-        (1, 63)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action286::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (3, 88)
     }
-    pub(crate) fn __reduce120<
+    pub(crate) fn __reduce187<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LogicOr = LogicAnd, ("or" <LogicAnd>)+ => ActionFn(155);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant9(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Primary = "super", ".", Identifier => ActionFn(287);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action155::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
-        (2, 63)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action287::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (3, 88)
     }
-    pub(crate) fn __reduce121<
+    pub(crate) fn __reduce188<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Parameters = IDENTIFIER => ActionFn(140);
+        // Primary = "[", Comma<Expression>, "]" => ActionFn(288);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant41(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action140::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
-        (1, 64)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action288::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant60(__nt), __end));
+        (3, 88)
     }
-    pub(crate) fn __reduce122<
+    pub(crate) fn __reduce189<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Parameters = IDENTIFIER, ("," <IDENTIFIER>)+ => ActionFn(141);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
+        // PrintStatement = "print", Expression, ";" => ActionFn(289);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action141::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
-        (2, 64)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action289::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant61(__nt), __end));
+        (3, 89)
     }
-    pub(crate) fn __reduce123<
+    pub(crate) fn __reduce190<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Parameters? = Parameters => ActionFn(70);
-        let __sym0 = __pop_Variant46(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action70::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant47(__nt), __end));
-        (1, 65)
+        // Program =  => ActionFn(353);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action353::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant62(__nt), __end));
+        (0, 90)
     }
-    pub(crate) fn __reduce124<
+    pub(crate) fn __reduce191<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Parameters? =  => ActionFn(71);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action71::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant47(__nt), __end));
-        (0, 65)
+        // Program = Declaration+ => ActionFn(354);
+        let __sym0 = __pop_Variant46(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action354::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant62(__nt), __end));
+        (1, 90)
     }
-    pub(crate) fn __reduce125<
+    pub(crate) fn __reduce192<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Primary = "true" => ActionFn(52);
+        // ReturnStatement = "return", Expression, ";" => ActionFn(367);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
-        (1, 66)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action367::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant63(__nt), __end));
+        (3, 91)
     }
-    pub(crate) fn __reduce126<
+    pub(crate) fn __reduce193<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Primary = "false" => ActionFn(53);
+        // ReturnStatement = "return", ";" => ActionFn(368);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action53::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
-        (1, 66)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action368::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant63(__nt), __end));
+        (2, 91)
     }
-    pub(crate) fn __reduce127<
+    pub(crate) fn __reduce194<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Primary = "nil" => ActionFn(54);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Sp<ComparisonOperator> = ComparisonOperator => ActionFn(291);
+        let __sym0 = __pop_Variant44(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
-        (1, 66)
+        let __nt = super::__action291::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant64(__nt), __end));
+        (1, 92)
     }
-    pub(crate) fn __reduce128<
+    pub(crate) fn __reduce195<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Primary = "this" => ActionFn(55);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Sp<EqualityOperator> = EqualityOperator => ActionFn(292);
+        let __sym0 = __pop_Variant47(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action55::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
-        (1, 66)
+        let __nt = super::__action292::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant65(__nt), __end));
+        (1, 93)
     }
-    pub(crate) fn __reduce129<
+    pub(crate) fn __reduce196<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Primary = NUMBER => ActionFn(56);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Sp<FactorOperator> = FactorOperator => ActionFn(293);
+        let __sym0 = __pop_Variant50(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action56::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
-        (1, 66)
+        let __nt = super::__action293::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant66(__nt), __end));
+        (1, 94)
     }
-    pub(crate) fn __reduce130<
+    pub(crate) fn __reduce197<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Primary = STRING => ActionFn(57);
+        // Sp<NUMBER> = NUMBER => ActionFn(294);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
-        (1, 66)
+        let __nt = super::__action294::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant67(__nt), __end));
+        (1, 95)
     }
-    pub(crate) fn __reduce131<
+    pub(crate) fn __reduce198<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Primary = IDENTIFIER => ActionFn(58);
+        // Sp<STRING> = STRING => ActionFn(295);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action58::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
-        (1, 66)
+        let __nt = super::__action295::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant67(__nt), __end));
+        (1, 96)
     }
-    pub(crate) fn __reduce132<
+    pub(crate) fn __reduce199<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Primary = "(", B<Expression>, ")" => ActionFn(59);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant24(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Sp<TermOperator> = TermOperator => ActionFn(296);
+        let __sym0 = __pop_Variant71(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action59::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
-        (3, 66)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action296::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant68(__nt), __end));
+        (1, 97)
     }
-    pub(crate) fn __reduce133<
+    pub(crate) fn __reduce200<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Primary = "super", ".", IDENTIFIER => ActionFn(60);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Sp<UnaryOperator> = UnaryOperator => ActionFn(297);
+        let __sym0 = __pop_Variant73(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action60::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
-        (3, 66)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action297::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant69(__nt), __end));
+        (1, 98)
     }
-    pub(crate) fn __reduce134<
+    pub(crate) fn __reduce201<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // PrintStatement = "print", Expression, ";" => ActionFn(22);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Statement = OpenStatement => ActionFn(10);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action22::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant49(__nt), __end));
-        (3, 67)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action10::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 99)
     }
-    pub(crate) fn __reduce135<
+    pub(crate) fn __reduce202<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Program =  => ActionFn(185);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action185::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant50(__nt), __end));
-        (0, 68)
+        // Statement = ClosedStatement => ActionFn(11);
+        let __sym0 = __pop_Variant40(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action11::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (1, 99)
     }
-    pub(crate) fn __reduce136<
+    pub(crate) fn __reduce203<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Program = Declaration+ => ActionFn(186);
-        let __sym0 = __pop_Variant34(__symbols);
+        // Term = Factor => ActionFn(335);
+        let __sym0 = __pop_Variant49(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action186::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant50(__nt), __end));
-        (1, 68)
+        let __nt = super::__action335::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant70(__nt), __end));
+        (1, 100)
     }
-    pub(crate) fn __reduce137<
+    pub(crate) fn __reduce204<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ReturnStatement = "return", Expression, ";" => ActionFn(191);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Term = Factor, (<Sp<TermOperator>> <Factor>)+ => ActionFn(336);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant49(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action191::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant51(__nt), __end));
-        (3, 69)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action336::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant70(__nt), __end));
+        (2, 100)
     }
-    pub(crate) fn __reduce138<
+    pub(crate) fn __reduce205<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ReturnStatement = "return", ";" => ActionFn(192);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
+        // TermOperator = "-" => ActionFn(45);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action192::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant51(__nt), __end));
-        (2, 69)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action45::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant71(__nt), __end));
+        (1, 101)
     }
-    pub(crate) fn __reduce139<
+    pub(crate) fn __reduce206<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Statement = ExprStatement => ActionFn(9);
-        let __sym0 = __pop_Variant36(__symbols);
+        // TermOperator = "+" => ActionFn(46);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action9::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
-        (1, 70)
+        let __nt = super::__action46::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant71(__nt), __end));
+        (1, 101)
     }
-    pub(crate) fn __reduce140<
+    pub(crate) fn __reduce207<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Statement = ForStatement => ActionFn(10);
-        let __sym0 = __pop_Variant40(__symbols);
+        // TypeAnnotation = Identifier => ActionFn(72);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
-        (1, 70)
+        let __nt = super::__action72::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 102)
     }
-    pub(crate) fn __reduce141<
+    pub(crate) fn __reduce208<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Statement = IfStatement => ActionFn(11);
-        let __sym0 = __pop_Variant44(__symbols);
+        // TypeAnnotation = "nil" => ActionFn(299);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
-        (1, 70)
+        let __nt = super::__action299::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 102)
     }
-    pub(crate) fn __reduce142<
+    pub(crate) fn __reduce209<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Statement = PrintStatement => ActionFn(12);
-        let __sym0 = __pop_Variant49(__symbols);
+        // TypeAnnotation = "fun", "(", Comma<TypeAnnotation>, ")", ":", B<TypeAnnotation> => ActionFn(300);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant34(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant42(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
-        (1, 70)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action300::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (6, 102)
     }
-    pub(crate) fn __reduce143<
+    pub(crate) fn __reduce210<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Statement = ReturnStatement => ActionFn(13);
-        let __sym0 = __pop_Variant51(__symbols);
+        // TypeAnnotation? = TypeAnnotation => ActionFn(172);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
-        (1, 70)
+        let __nt = super::__action172::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 103)
     }
-    pub(crate) fn __reduce144<
+    pub(crate) fn __reduce211<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Statement = WhileStatement => ActionFn(14);
-        let __sym0 = __pop_Variant58(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
-        (1, 70)
+        // TypeAnnotation? =  => ActionFn(173);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action173::<>(errors, input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (0, 103)
     }
-    pub(crate) fn __reduce145<
+    pub(crate) fn __reduce212<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Statement = Block => ActionFn(15);
-        let __sym0 = __pop_Variant27(__symbols);
+        // Unary = Sp<UnaryOperator>, B<Unary> => ActionFn(301);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant35(__symbols);
+        let __sym0 = __pop_Variant69(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant52(__nt), __end));
-        (1, 70)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action301::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant72(__nt), __end));
+        (2, 104)
     }
-    pub(crate) fn __reduce146<
+    pub(crate) fn __reduce213<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Term = Factor => ActionFn(181);
-        let __sym0 = __pop_Variant37(__symbols);
+        // Unary = Primary => ActionFn(310);
+        let __sym0 = __pop_Variant60(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action181::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant53(__nt), __end));
-        (1, 71)
+        let __nt = super::__action310::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant72(__nt), __end));
+        (1, 104)
     }
-    pub(crate) fn __reduce147<
+    pub(crate) fn __reduce214<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Term = Factor, (<TermOperator> <Factor>)+ => ActionFn(182);
+        // Unary = Primary, CallRhs+ => ActionFn(311);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant19(__symbols);
-        let __sym0 = __pop_Variant37(__symbols);
+        let __sym1 = __pop_Variant38(__symbols);
+        let __sym0 = __pop_Variant60(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action182::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant53(__nt), __end));
-        (2, 71)
+        let __nt = super::__action311::<>(errors, input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant72(__nt), __end));
+        (2, 104)
     }
-    pub(crate) fn __reduce148<
+    pub(crate) fn __reduce215<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // TermOperator = "-" => ActionFn(40);
+        // UnaryOperator = "!" => ActionFn(52);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action40::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant54(__nt), __end));
-        (1, 72)
+        let __nt = super::__action52::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant73(__nt), __end));
+        (1, 105)
     }
-    pub(crate) fn __reduce149<
+    pub(crate) fn __reduce216<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // TermOperator = "+" => ActionFn(41);
+        // UnaryOperator = "-" => ActionFn(53);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action41::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant54(__nt), __end));
-        (1, 72)
-    }
-    pub(crate) fn __reduce150<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Unary = UnaryOperator, B<Unary> => ActionFn(45);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant26(__symbols);
-        let __sym0 = __pop_Variant56(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action45::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant55(__nt), __end));
-        (2, 73)
+        let __nt = super::__action53::<>(errors, input, __sym0);
+        __symbols.push((__start, __Symbol::Variant73(__nt), __end));
+        (1, 105)
     }
-    pub(crate) fn __reduce151<
+    pub(crate) fn __reduce217<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Unary = Primary => ActionFn(160);
-        let __sym0 = __pop_Variant48(__symbols);
+        // VariableDeclaration = "var", Identifier, Annotation, "=", Expression, ";" => ActionFn(345);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant23(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action160::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant55(__nt), __end));
-        (1, 73)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action345::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant74(__nt), __end));
+        (6, 106)
     }
-    pub(crate) fn __reduce152<
+    pub(crate) fn __reduce218<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Unary = Primary, CallRhs+ => ActionFn(161);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant29(__symbols);
-        let __sym0 = __pop_Variant48(__symbols);
+        // VariableDeclaration = "var", Identifier, "=", Expression, ";" => ActionFn(346);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action161::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant55(__nt), __end));
-        (2, 73)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action346::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant74(__nt), __end));
+        (5, 106)
     }
-    pub(crate) fn __reduce153<
+    pub(crate) fn __reduce219<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // UnaryOperator = "!" => ActionFn(47);
+        // VariableDeclaration = "var", Identifier, Annotation, ";" => ActionFn(347);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant23(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action47::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant56(__nt), __end));
-        (1, 74)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action347::<>(errors, input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant74(__nt), __end));
+        (4, 106)
     }
-    pub(crate) fn __reduce154<
+    pub(crate) fn __reduce220<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // UnaryOperator = "-" => ActionFn(48);
+        // VariableDeclaration = "var", Identifier, ";" => ActionFn(348);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant56(__nt), __end));
-        (1, 74)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action348::<>(errors, input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant74(__nt), __end));
+        (3, 106)
     }
-    pub(crate) fn __reduce155<
+    pub(crate) fn __reduce221<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // VariableDeclaration = "var", IDENTIFIER, "=", Expression, ";" => ActionFn(146);
+        // WhileStatement<ClosedStatement> = "while", "(", Expression, ")", B<ClosedStatement> => ActionFn(304);
         assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant31(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym4.2.clone();
-        let __nt = super::__action146::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant57(__nt), __end));
-        (5, 75)
+        let __nt = super::__action304::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant75(__nt), __end));
+        (5, 107)
     }
-    pub(crate) fn __reduce156<
+    pub(crate) fn __reduce222<
         'input,
+        'err,
     >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
         input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // VariableDeclaration = "var", IDENTIFIER, ";" => ActionFn(147);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
+        // WhileStatement<OpenStatement> = "while", "(", Expression, ")", B<OpenStatement> => ActionFn(305);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant31(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action147::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant57(__nt), __end));
-        (3, 75)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action305::<>(errors, input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant75(__nt), __end));
+        (5, 108)
+    }
+}
+pub use self::__parse__Program::ProgramParser;
+#[cfg_attr(rustfmt, rustfmt_skip)]
+mod __intern_token {
+    #![allow(unused_imports)]
+    use crate::ast::*;
+    use lalrpop_util::ErrorRecovery;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
+        let __strs: &[(&str, bool)] = &[
+            ("^(\"[\0-!\\#-\u{10ffff}]*\")", false),
+            ("^([0-9]+(\\.[0-9]+)?)", false),
+            ("^([A-Z_a-z][0-9A-Z_a-z]*)", false),
+            ("^(!)", false),
+            ("^(!=)", false),
+            ("^(\\()", false),
+            ("^(\\))", false),
+            ("^(\\*)", false),
+            ("^(\\+)", false),
+            ("^(,)", false),
+            ("^(\\-)", false),
+            ("^(\\.)", false),
+            ("^(/)", false),
+            ("^(:)", false),
+            ("^(;)", false),
+            ("^(<)", false),
+            ("^(<=)", false),
+            ("^(=)", false),
+            ("^(==)", false),
+            ("^(>)", false),
+            ("^(>=)", false),
+            ("^(\\[)", false),
+            ("^(\\])", false),
+            ("^(and)", false),
+            ("^(class)", false),
+            ("^(else)", false),
+            ("^(false)", false),
+            ("^(for)", false),
+            ("^(fun)", false),
+            ("^(if)", false),
+            ("^(nil)", false),
+            ("^(or)", false),
+            ("^(print)", false),
+            ("^(return)", false),
+            ("^(super)", false),
+            ("^(this)", false),
+            ("^(true)", false),
+            ("^(var)", false),
+            ("^(while)", false),
+            ("^(\\{)", false),
+            ("^(\\})", false),
+            (r"^(\s*)", true),
+        ];
+        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+    }
+}
+pub(crate) use self::__lalrpop_util::lexer::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Program<'input>, usize),
+) -> Program<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, decls, _): (usize, alloc::vec::Vec<Declaration<'input>>, usize),
+) -> Program<'input>
+{
+    Program(decls)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, cd, _): (usize, ClassDeclaration<'input>, usize),
+) -> Declaration<'input>
+{
+    Declaration::Class(cd)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, fd, _): (usize, FunctionDeclaration<'input>, usize),
+) -> Declaration<'input>
+{
+    Declaration::Function(fd)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, vd, _): (usize, VariableDeclaration<'input>, usize),
+) -> Declaration<'input>
+{
+    Declaration::Variable(vd)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, s, _): (usize, Statement<'input>, usize),
+) -> Declaration<'input>
+{
+    Declaration::Statement(s)
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, error, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Declaration<'input>
+{
+    {
+        errors.push(error);
+        Declaration::Error(Span::new(l, r))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, class_name, _): (usize, Identifier<'input>, usize),
+    (_, base, _): (usize, core::option::Option<Identifier<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, members, _): (usize, alloc::vec::Vec<Function<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ClassDeclaration<'input>
+{
+    ClassDeclaration { class_name, base, members, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, f, _): (usize, Function<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> FunctionDeclaration<'input>
+{
+    FunctionDeclaration(f, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, name, _): (usize, Identifier<'input>, usize),
+    (_, annotation, _): (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    (_, initializer, _): (usize, core::option::Option<Expression<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> VariableDeclaration<'input>
+{
+    VariableDeclaration { name, annotation, initializer, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Statement<'input>, usize),
+) -> Statement<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Statement<'input>, usize),
+) -> Statement<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, condition, _): (usize, Expression<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Statement<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Statement<'input>
+{
+    Statement::If(IfStatement { condition, body, else_body: None, span: Span::new(l, r) })
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, i, _): (usize, IfStatement<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::If(i)
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, f, _): (usize, ForStatement<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::For(f)
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, w, _): (usize, WhileStatement<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::While(w)
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, e, _): (usize, ExprStatement<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::Expression(e)
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, f, _): (usize, ForStatement<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::For(f)
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, i, _): (usize, IfStatement<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::If(i)
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, p, _): (usize, PrintStatement<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::Print(p)
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, r, _): (usize, ReturnStatement<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::Return(r)
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, w, _): (usize, WhileStatement<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::While(w)
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, b, _): (usize, Block<'input>, usize),
+) -> Statement<'input>
+{
+    Statement::Block(b)
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e, _): (usize, Expression<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ExprStatement<'input>
+{
+    ExprStatement(e, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, declaration, _): (usize, VariableDeclaration<'input>, usize),
+) -> ForInitializer<'input>
+{
+    ForInitializer::Declaration(declaration)
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, expression, _): (usize, Box<ExprStatement<'input>>, usize),
+) -> ForInitializer<'input>
+{
+    ForInitializer::Expression(expression)
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ForInitializer<'input>
+{
+    ForInitializer::Semicolon
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, value, _): (usize, Expression<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> PrintStatement<'input>
+{
+    PrintStatement(value, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, value, _): (usize, core::option::Option<Expression<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ReturnStatement<'input>
+{
+    ReturnStatement(value, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, alloc::vec::Vec<Declaration<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Block<'input>
+{
+    Block(body, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, a, _): (usize, Assignment<'input>, usize),
+) -> Expression<'input>
+{
+    Expression::Assignment(a)
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, object, _): (usize, core::option::Option<Call<'input>>, usize),
+    (_, target, _): (usize, Identifier<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, value, _): (usize, Box<Assignment<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Assignment<'input>
+{
+    Assignment::Assignment { object, target, value, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, object, _): (usize, Call<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, index, _): (usize, Box<Expression<'input>>, usize),
+    (_, lb, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, rb, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, value, _): (usize, Box<Assignment<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Assignment<'input>
+{
+    Assignment::Index {
+                object,
+                index,
+                value,
+                span: Span::new(l, r),
+                bracket: Span::new(lb, rb),
+            }
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, lo, _): (usize, LogicOr<'input>, usize),
+) -> Assignment<'input>
+{
+    Assignment::LogicOr(lo)
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, LogicAnd<'input>, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> LogicOr<'input>
+{
+    LogicOr { left, rest, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, Equality<'input>, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<Equality<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> LogicAnd<'input>
+{
+    LogicAnd { left, rest, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, Comparison<'input>, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Equality<'input>
+{
+    Equality { left, rest, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> EqualityOperator
+{
+    EqualityOperator::Neq
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> EqualityOperator
+{
+    EqualityOperator::Eq
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, Term<'input>, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Comparison<'input>
+{
+    Comparison { left, rest, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ComparisonOperator
+{
+    ComparisonOperator::Gt
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ComparisonOperator
+{
+    ComparisonOperator::Ge
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ComparisonOperator
+{
+    ComparisonOperator::Lt
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ComparisonOperator
+{
+    ComparisonOperator::Le
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, Factor<'input>, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Term<'input>
+{
+    Term { left, rest, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> TermOperator
+{
+    TermOperator::Minus
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> TermOperator
+{
+    TermOperator::Plus
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, Unary<'input>, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Factor<'input>
+{
+    Factor { left, rest, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> FactorOperator
+{
+    FactorOperator::Slash
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> FactorOperator
+{
+    FactorOperator::Star
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, operator, _): (usize, Spanned<UnaryOperator>, usize),
+    (_, right, _): (usize, Box<Unary<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Unary<'input>
+{
+    Unary::Unary { operator, right, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, c, _): (usize, Call<'input>, usize),
+) -> Unary<'input>
+{
+    Unary::Call(c)
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> UnaryOperator
+{
+    UnaryOperator::LogicNot
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> UnaryOperator
+{
+    UnaryOperator::Neg
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, target, _): (usize, Primary<'input>, usize),
+    (_, rhs, _): (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Call<'input>
+{
+    Call { target, rhs, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, arguments, _): (usize, core::option::Option<Arguments<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> CallRhs<'input>
+{
+    CallRhs::Call(arguments, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, member, _): (usize, Identifier<'input>, usize),
+) -> CallRhs<'input>
+{
+    CallRhs::Member(member)
+}
+
+#[allow(unused_variables)]
+fn __action57<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, index, _): (usize, Box<Expression<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> CallRhs<'input>
+{
+    CallRhs::Index(index, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action58<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Primary<'input>
+{
+    Primary::True(Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action59<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Primary<'input>
+{
+    Primary::False(Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action60<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Primary<'input>
+{
+    Primary::Nil(Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action61<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Primary<'input>
+{
+    Primary::This(Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action62<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, n, _): (usize, Spanned<&'input str>, usize),
+) -> Primary<'input>
+{
+    Primary::Number(n)
+}
+
+#[allow(unused_variables)]
+fn __action63<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, s, _): (usize, Spanned<&'input str>, usize),
+) -> Primary<'input>
+{
+    Primary::String(s)
+}
+
+#[allow(unused_variables)]
+fn __action64<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, i, _): (usize, Identifier<'input>, usize),
+) -> Primary<'input>
+{
+    Primary::Identifier(i)
+}
+
+#[allow(unused_variables)]
+fn __action65<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Expression<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Primary<'input>
+{
+    Primary::Parenthesized(e, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action66<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, i, _): (usize, Identifier<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Primary<'input>
+{
+    Primary::SuperMember(i, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action67<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, elements, _): (usize, Vec<Expression<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Primary<'input>
+{
+    Primary::List(elements, Span::new(l, r))
+}
+
+#[allow(unused_variables)]
+fn __action68<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, name, _): (usize, Identifier<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, parameters, _): (usize, core::option::Option<Parameters<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, return_type, _): (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    (_, body, _): (usize, Block<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Function<'input>
+{
+    Function { name, parameters, return_type, body, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action69<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, first, _): (usize, Parameter<'input>, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<Parameter<'input>>, usize),
+) -> Parameters<'input>
+{
+    Parameters { first:first, rest:rest }
+}
+
+#[allow(unused_variables)]
+fn __action70<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, name, _): (usize, Identifier<'input>, usize),
+    (_, annotation, _): (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+) -> Parameter<'input>
+{
+    Parameter { name:name, annotation:annotation }
+}
+
+#[allow(unused_variables)]
+fn __action71<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, TypeAnnotation<'input>, usize),
+) -> TypeAnnotation<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action72<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, name, _): (usize, Identifier<'input>, usize),
+) -> TypeAnnotation<'input>
+{
+    TypeAnnotation::Named(name)
+}
+
+#[allow(unused_variables)]
+fn __action73<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, name, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> TypeAnnotation<'input>
+{
+    TypeAnnotation::Named(Identifier { name, span: Span::new(l, r) })
+}
+
+#[allow(unused_variables)]
+fn __action74<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, parameters, _): (usize, Vec<TypeAnnotation<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, returns, _): (usize, Box<TypeAnnotation<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> TypeAnnotation<'input>
+{
+    TypeAnnotation::Function { parameters, returns, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action75<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, name, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Identifier<'input>
+{
+    Identifier { name, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action76<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, first, _): (usize, Box<Expression<'input>>, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<Expression<'input>>, usize),
+) -> Arguments<'input>
+{
+    Arguments { first:first, rest:rest }
+}
+
+#[allow(unused_variables)]
+fn __action77<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Expression<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action78<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Expression<'input>>, usize),
+) -> alloc::vec::Vec<Expression<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action79<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Expression<'input>, usize),
+) -> Expression<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action80<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, t, _): (usize, TypeAnnotation<'input>, usize),
+) -> Box<TypeAnnotation<'input>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action81<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, mut v, _): (usize, alloc::vec::Vec<TypeAnnotation<'input>>, usize),
+    (_, e, _): (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+) -> Vec<TypeAnnotation<'input>>
+{
+    match e {
+        None => v,
+        Some(e) => {
+            v.push(e);
+            v
+        }
     }
-    pub(crate) fn __reduce157<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // WhileStatement = "while", "(", Expression, ")", B<Statement> => ActionFn(24);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant25(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action24::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant58(__nt), __end));
-        (5, 76)
+}
+
+#[allow(unused_variables)]
+fn __action82<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Parameter<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action83<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Parameter<'input>>, usize),
+) -> alloc::vec::Vec<Parameter<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action84<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Parameter<'input>, usize),
+) -> Parameter<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action85<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Parameters<'input>, usize),
+) -> core::option::Option<Parameters<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action86<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Parameters<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action87<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, mut v, _): (usize, alloc::vec::Vec<Expression<'input>>, usize),
+    (_, e, _): (usize, core::option::Option<Expression<'input>>, usize),
+) -> Vec<Expression<'input>>
+{
+    match e {
+        None => v,
+        Some(e) => {
+            v.push(e);
+            v
+        }
     }
 }
-pub use self::__parse__Program::ProgramParser;
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use crate::ast::*;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
-        let __strs: &[(&str, bool)] = &[
-            ("^(\"[\u{0}-!\\#-\u{10ffff}]*\")", false),
-            ("^([1-9][0-9]*|0)", false),
-            ("^([A-Z_a-z][0-9A-Z_a-z]*)", false),
-            ("^(!)", false),
-            ("^(!=)", false),
-            ("^(\\()", false),
-            ("^(\\))", false),
-            ("^(\\*)", false),
-            ("^(\\+)", false),
-            ("^(,)", false),
-            ("^(\\-)", false),
-            ("^(\\.)", false),
-            ("^(/)", false),
-            ("^(;)", false),
-            ("^(<)", false),
-            ("^(<=)", false),
-            ("^(=)", false),
-            ("^(==)", false),
-            ("^(>)", false),
-            ("^(>=)", false),
-            ("^(and)", false),
-            ("^(class)", false),
-            ("^(false)", false),
-            ("^(for)", false),
-            ("^(fun)", false),
-            ("^(if)", false),
-            ("^(nil)", false),
-            ("^(or)", false),
-            ("^(print)", false),
-            ("^(return)", false),
-            ("^(super)", false),
-            ("^(this)", false),
-            ("^(true)", false),
-            ("^(var)", false),
-            ("^(while)", false),
-            ("^(\\{)", false),
-            ("^(\\})", false),
-            (r"^(\s*)", true),
-        ];
-        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
-    }
+
+#[allow(unused_variables)]
+fn __action88<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, node, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<&'input str>
+{
+    Spanned { node, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action89<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, node, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<&'input str>
+{
+    Spanned { node, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action90<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Arguments<'input>, usize),
+) -> core::option::Option<Arguments<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action91<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Arguments<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action92<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<CallRhs<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action93<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+) -> alloc::vec::Vec<CallRhs<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action94<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, t, _): (usize, Unary<'input>, usize),
+) -> Box<Unary<'input>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action95<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, node, _): (usize, UnaryOperator, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<UnaryOperator>
+{
+    Spanned { node, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action96<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action97<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>, usize),
+) -> alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action98<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Spanned<FactorOperator>, usize),
+    (_, __1, _): (usize, Unary<'input>, usize),
+) -> (Spanned<FactorOperator>, Unary<'input>)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action99<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, node, _): (usize, FactorOperator, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<FactorOperator>
+{
+    Spanned { node, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action100<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action101<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>, usize),
+) -> alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action102<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Spanned<TermOperator>, usize),
+    (_, __1, _): (usize, Factor<'input>, usize),
+) -> (Spanned<TermOperator>, Factor<'input>)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action103<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, node, _): (usize, TermOperator, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<TermOperator>
+{
+    Spanned { node, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action104<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action105<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>, usize),
+) -> alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action106<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Spanned<ComparisonOperator>, usize),
+    (_, __1, _): (usize, Term<'input>, usize),
+) -> (Spanned<ComparisonOperator>, Term<'input>)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action107<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, node, _): (usize, ComparisonOperator, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<ComparisonOperator>
+{
+    Spanned { node, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action108<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action109<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>, usize),
+) -> alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action110<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Spanned<EqualityOperator>, usize),
+    (_, __1, _): (usize, Comparison<'input>, usize),
+) -> (Spanned<EqualityOperator>, Comparison<'input>)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action111<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, node, _): (usize, EqualityOperator, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<EqualityOperator>
+{
+    Spanned { node, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action112<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Equality<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action113<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Equality<'input>>, usize),
+) -> alloc::vec::Vec<Equality<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action114<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Equality<'input>, usize),
+) -> Equality<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action115<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<LogicAnd<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action116<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
+) -> alloc::vec::Vec<LogicAnd<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action117<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, LogicAnd<'input>, usize),
+) -> LogicAnd<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action118<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, t, _): (usize, Expression<'input>, usize),
+) -> Box<Expression<'input>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action119<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, t, _): (usize, Assignment<'input>, usize),
+) -> Box<Assignment<'input>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action120<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Call<'input>, usize),
+) -> core::option::Option<Call<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action121<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Call<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action122<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Call<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Call<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action123<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Expression<'input>, usize),
+) -> core::option::Option<Expression<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action124<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Expression<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action125<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, t, _): (usize, ExprStatement<'input>, usize),
+) -> Box<ExprStatement<'input>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action126<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, condition, _): (usize, Expression<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Statement<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> WhileStatement<'input>
+{
+    WhileStatement { condition, body, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action127<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, condition, _): (usize, Expression<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Statement<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, else_body, _): (usize, Box<Statement<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> IfStatement<'input>
+{
+    IfStatement { condition, body, else_body: Some(else_body), span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action128<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, initializer, _): (usize, ForInitializer<'input>, usize),
+    (_, condition, _): (usize, core::option::Option<Expression<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, increment, _): (usize, core::option::Option<Expression<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Statement<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ForStatement<'input>
+{
+    ForStatement { initializer, condition, increment, body, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action129<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, condition, _): (usize, Expression<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Statement<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> WhileStatement<'input>
+{
+    WhileStatement { condition, body, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action130<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, initializer, _): (usize, ForInitializer<'input>, usize),
+    (_, condition, _): (usize, core::option::Option<Expression<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, increment, _): (usize, core::option::Option<Expression<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Statement<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ForStatement<'input>
+{
+    ForStatement { initializer, condition, increment, body, span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action131<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, condition, _): (usize, Expression<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Statement<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, else_body, _): (usize, Box<Statement<'input>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> IfStatement<'input>
+{
+    IfStatement { condition, body, else_body: Some(else_body), span: Span::new(l, r) }
+}
+
+#[allow(unused_variables)]
+fn __action132<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, t, _): (usize, Statement<'input>, usize),
+) -> Box<Statement<'input>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action133<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Expression<'input>, usize),
+) -> core::option::Option<Expression<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action134<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Expression<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action135<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Expression<'input>, usize),
+) -> Expression<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action136<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, TypeAnnotation<'input>, usize),
+) -> core::option::Option<TypeAnnotation<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action137<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<TypeAnnotation<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action138<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Function<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action139<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Function<'input>>, usize),
+) -> alloc::vec::Vec<Function<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action140<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Identifier<'input>, usize),
+) -> core::option::Option<Identifier<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action141<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Identifier<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action142<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Identifier<'input>, usize),
+) -> Identifier<'input>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action143<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action144<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action145<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Declaration<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action146<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Declaration<'input>>, usize),
+) -> alloc::vec::Vec<Declaration<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action147<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Declaration<'input>, usize),
+) -> alloc::vec::Vec<Declaration<'input>>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action148<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Declaration<'input>>, usize),
+    (_, e, _): (usize, Declaration<'input>, usize),
+) -> alloc::vec::Vec<Declaration<'input>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action149<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Function<'input>, usize),
+) -> alloc::vec::Vec<Function<'input>>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action150<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Function<'input>>, usize),
+    (_, e, _): (usize, Function<'input>, usize),
+) -> alloc::vec::Vec<Function<'input>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action151<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, t, _): (usize, Statement<'input>, usize),
+) -> Box<Statement<'input>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action152<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, t, _): (usize, Statement<'input>, usize),
+) -> Box<Statement<'input>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action153<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, LogicAnd<'input>, usize),
+) -> alloc::vec::Vec<LogicAnd<'input>>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action154<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
+    (_, e, _): (usize, LogicAnd<'input>, usize),
+) -> alloc::vec::Vec<LogicAnd<'input>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action155<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, Equality<'input>, usize),
+) -> alloc::vec::Vec<Equality<'input>>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action156<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Equality<'input>>, usize),
+    (_, e, _): (usize, Equality<'input>, usize),
+) -> alloc::vec::Vec<Equality<'input>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action157<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, (Spanned<EqualityOperator>, Comparison<'input>), usize),
+) -> alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action158<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>, usize),
+    (_, e, _): (usize, (Spanned<EqualityOperator>, Comparison<'input>), usize),
+) -> alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action159<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, (Spanned<ComparisonOperator>, Term<'input>), usize),
+) -> alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action160<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>, usize),
+    (_, e, _): (usize, (Spanned<ComparisonOperator>, Term<'input>), usize),
+) -> alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action161<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, (Spanned<TermOperator>, Factor<'input>), usize),
+) -> alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action162<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>, usize),
+    (_, e, _): (usize, (Spanned<TermOperator>, Factor<'input>), usize),
+) -> alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action163<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, (Spanned<FactorOperator>, Unary<'input>), usize),
+) -> alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action164<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>, usize),
+    (_, e, _): (usize, (Spanned<FactorOperator>, Unary<'input>), usize),
+) -> alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action165<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, __0, _): (usize, CallRhs<'input>, usize),
+) -> alloc::vec::Vec<CallRhs<'input>>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action166<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+    (_, e, _): (usize, CallRhs<'input>, usize),
+) -> alloc::vec::Vec<CallRhs<'input>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action167<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Expression<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action168<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<Expression<'input>>, usize),
+) -> alloc::vec::Vec<Expression<'input>>
+{
+    v
 }
-pub(crate) use self::__lalrpop_util::lexer::Token;
 
 #[allow(unused_variables)]
-fn __action0<
+fn __action169<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Program<'input>, usize),
-) -> Program<'input>
+    (_, __0, _): (usize, Expression<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Expression<'input>
 {
     __0
 }
 
 #[allow(unused_variables)]
-fn __action1<
+fn __action170<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, decls, _): (usize, alloc::vec::Vec<Declaration<'input>>, usize),
-) -> Program<'input>
+    (_, __0, _): (usize, Parameter<'input>, usize),
+) -> alloc::vec::Vec<Parameter<'input>>
 {
-    Program(decls)
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action2<
+fn __action171<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, cd, _): (usize, ClassDeclaration<'input>, usize),
-) -> Declaration<'input>
+    (_, v, _): (usize, alloc::vec::Vec<Parameter<'input>>, usize),
+    (_, e, _): (usize, Parameter<'input>, usize),
+) -> alloc::vec::Vec<Parameter<'input>>
 {
-    Declaration::Class(cd)
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action3<
+fn __action172<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, fd, _): (usize, FunctionDeclaration<'input>, usize),
-) -> Declaration<'input>
+    (_, __0, _): (usize, TypeAnnotation<'input>, usize),
+) -> core::option::Option<TypeAnnotation<'input>>
 {
-    Declaration::Function(fd)
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action4<
+fn __action173<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, vd, _): (usize, VariableDeclaration<'input>, usize),
-) -> Declaration<'input>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<TypeAnnotation<'input>>
 {
-    Declaration::Variable(vd)
+    None
 }
 
 #[allow(unused_variables)]
-fn __action5<
+fn __action174<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, s, _): (usize, Statement<'input>, usize),
-) -> Declaration<'input>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<TypeAnnotation<'input>>
 {
-    Declaration::Statement(s)
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action6<
+fn __action175<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, class_name, _): (usize, &'input str, usize),
-    (_, base, _): (usize, core::option::Option<&'input str>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, members, _): (usize, alloc::vec::Vec<Function<'input>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> ClassDeclaration<'input>
+    (_, v, _): (usize, alloc::vec::Vec<TypeAnnotation<'input>>, usize),
+) -> alloc::vec::Vec<TypeAnnotation<'input>>
 {
-    ClassDeclaration { class_name:class_name, base:base, members:members }
+    v
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action176<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
+    (_, __0, _): (usize, TypeAnnotation<'input>, usize),
     (_, _, _): (usize, &'input str, usize),
-    (_, f, _): (usize, Function<'input>, usize),
-) -> FunctionDeclaration<'input>
+) -> TypeAnnotation<'input>
 {
-    FunctionDeclaration(f)
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action177<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, &'input str, usize),
-    (_, initializer, _): (usize, core::option::Option<Expression<'input>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> VariableDeclaration<'input>
+    (_, __0, _): (usize, Expression<'input>, usize),
+) -> alloc::vec::Vec<Expression<'input>>
 {
-    VariableDeclaration { name:name, initializer:initializer }
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action178<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, e, _): (usize, ExprStatement<'input>, usize),
-) -> Statement<'input>
+    (_, v, _): (usize, alloc::vec::Vec<Expression<'input>>, usize),
+    (_, e, _): (usize, Expression<'input>, usize),
+) -> alloc::vec::Vec<Expression<'input>>
 {
-    Statement::Expression(e)
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action179<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, f, _): (usize, ForStatement<'input>, usize),
-) -> Statement<'input>
+    (_, __0, _): (usize, TypeAnnotation<'input>, usize),
+) -> alloc::vec::Vec<TypeAnnotation<'input>>
 {
-    Statement::For(f)
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action180<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, i, _): (usize, IfStatement<'input>, usize),
-) -> Statement<'input>
+    (_, v, _): (usize, alloc::vec::Vec<TypeAnnotation<'input>>, usize),
+    (_, e, _): (usize, TypeAnnotation<'input>, usize),
+) -> alloc::vec::Vec<TypeAnnotation<'input>>
 {
-    Statement::If(i)
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action181<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, p, _): (usize, PrintStatement<'input>, usize),
-) -> Statement<'input>
+    (_, __0, _): (usize, Expression<'input>, usize),
+) -> alloc::vec::Vec<Expression<'input>>
 {
-    Statement::Print(p)
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action182<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, r, _): (usize, ReturnStatement<'input>, usize),
-) -> Statement<'input>
+    (_, v, _): (usize, alloc::vec::Vec<Expression<'input>>, usize),
+    (_, e, _): (usize, Expression<'input>, usize),
+) -> alloc::vec::Vec<Expression<'input>>
 {
-    Statement::Return(r)
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action183<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, w, _): (usize, WhileStatement<'input>, usize),
-) -> Statement<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Expression<'input>, usize),
+) -> alloc::vec::Vec<Expression<'input>>
 {
-    Statement::While(w)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action79(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action177(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action184<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, b, _): (usize, Block<'input>, usize),
-) -> Statement<'input>
+    __0: (usize, alloc::vec::Vec<Expression<'input>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Expression<'input>, usize),
+) -> alloc::vec::Vec<Expression<'input>>
 {
-    Statement::Block(b)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action79(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action178(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action185<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, e, _): (usize, Expression<'input>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> ExprStatement<'input>
+    __0: (usize, Box<Expression<'input>>, usize),
+) -> Arguments<'input>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action77(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action76(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action186<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, Box<Expression<'input>>, usize),
+    __1: (usize, alloc::vec::Vec<Expression<'input>>, usize),
+) -> Arguments<'input>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action78(
+        errors,
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action76(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action187<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Parameter<'input>, usize),
+) -> alloc::vec::Vec<Parameter<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action84(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action170(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action188<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<Parameter<'input>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Parameter<'input>, usize),
+) -> alloc::vec::Vec<Parameter<'input>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action84(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action171(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action189<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, Parameter<'input>, usize),
+) -> Parameters<'input>
 {
-    ExprStatement(e)
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action82(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action17<
+fn __action190<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, initializer, _): (usize, ForInitializer<'input>, usize),
-    (_, condition, _): (usize, core::option::Option<Expression<'input>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, increment, _): (usize, core::option::Option<Expression<'input>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Statement<'input>>, usize),
-) -> ForStatement<'input>
+    __0: (usize, Parameter<'input>, usize),
+    __1: (usize, alloc::vec::Vec<Parameter<'input>>, usize),
+) -> Parameters<'input>
 {
-    ForStatement { initializer:initializer, condition:condition, increment:increment, body:body }
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action83(
+        errors,
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action18<
+fn __action191<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, declaration, _): (usize, VariableDeclaration<'input>, usize),
-) -> ForInitializer<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
+) -> core::option::Option<Identifier<'input>>
 {
-    ForInitializer::Declaration(declaration)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action142(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action140(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action19<
+fn __action192<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, expression, _): (usize, Box<ExprStatement<'input>>, usize),
-) -> ForInitializer<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Identifier<'input>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, alloc::vec::Vec<Function<'input>>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, usize, usize),
+) -> ClassDeclaration<'input>
 {
-    ForInitializer::Expression(expression)
+    let __start0 = __3.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action191(
+        errors,
+        input,
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action20<
+fn __action193<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ForInitializer<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, alloc::vec::Vec<Function<'input>>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, usize, usize),
+) -> ClassDeclaration<'input>
 {
-    ForInitializer::Semicolon
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action141(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action21<
+fn __action194<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, condition, _): (usize, Expression<'input>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Statement<'input>>, usize),
-) -> IfStatement<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Expression<'input>, usize),
+) -> core::option::Option<Expression<'input>>
 {
-    IfStatement { condition:condition, body:body }
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action135(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action133(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action22<
+fn __action195<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, value, _): (usize, Expression<'input>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> PrintStatement<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Expression<'input>, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, usize, usize),
+) -> VariableDeclaration<'input>
 {
-    PrintStatement(value)
+    let __start0 = __4.0.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action194(
+        errors,
+        input,
+        __4,
+        __5,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action23<
+fn __action196<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, value, _): (usize, core::option::Option<Expression<'input>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> ReturnStatement<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> VariableDeclaration<'input>
 {
-    ReturnStatement(value)
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action134(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action24<
+fn __action197<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, condition, _): (usize, Expression<'input>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Statement<'input>>, usize),
-) -> WhileStatement<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Equality<'input>, usize),
+) -> alloc::vec::Vec<Equality<'input>>
 {
-    WhileStatement { condition:condition, body:body }
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action114(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action155(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action25<
+fn __action198<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, alloc::vec::Vec<Declaration<'input>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Block<'input>
+    __0: (usize, alloc::vec::Vec<Equality<'input>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Equality<'input>, usize),
+) -> alloc::vec::Vec<Equality<'input>>
 {
-    Block(body)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action114(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action26<
+fn __action199<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, a, _): (usize, Assignment<'input>, usize),
-) -> Expression<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, Equality<'input>, usize),
+    __2: (usize, usize, usize),
+) -> LogicAnd<'input>
 {
-    Expression::Assignment(a)
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action112(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action35(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action27<
+fn __action200<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, object, _): (usize, core::option::Option<Call<'input>>, usize),
-    (_, target, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, value, _): (usize, Box<Assignment<'input>>, usize),
-) -> Assignment<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, Equality<'input>, usize),
+    __2: (usize, alloc::vec::Vec<Equality<'input>>, usize),
+    __3: (usize, usize, usize),
+) -> LogicAnd<'input>
 {
-    Assignment::Assignment { object:object, target:target, value:value }
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action113(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action35(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action28<
+fn __action201<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, lo, _): (usize, LogicOr<'input>, usize),
-) -> Assignment<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, LogicAnd<'input>, usize),
+) -> alloc::vec::Vec<LogicAnd<'input>>
 {
-    Assignment::LogicOr(lo)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action117(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action153(
+        errors,
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action29<
+fn __action202<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, left, _): (usize, LogicAnd<'input>, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
-) -> LogicOr<'input>
+    __0: (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, LogicAnd<'input>, usize),
+) -> alloc::vec::Vec<LogicAnd<'input>>
 {
-    LogicOr { left:left, rest:rest }
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action117(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action154(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action30<
+fn __action203<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, left, _): (usize, Equality<'input>, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<Equality<'input>>, usize),
-) -> LogicAnd<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, LogicAnd<'input>, usize),
+    __2: (usize, usize, usize),
+) -> LogicOr<'input>
 {
-    LogicAnd { left:left, rest:rest }
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action115(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action34(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action31<
+fn __action204<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, left, _): (usize, Comparison<'input>, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>, usize),
-) -> Equality<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, LogicAnd<'input>, usize),
+    __2: (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
+    __3: (usize, usize, usize),
+) -> LogicOr<'input>
 {
-    Equality { left:left, rest:rest }
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action116(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action34(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action32<
+fn __action205<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> EqualityOperator
+    __0: (usize, usize, usize),
+    __1: (usize, core::option::Option<Call<'input>>, usize),
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Assignment<'input>>, usize),
+) -> Assignment<'input>
 {
-    EqualityOperator::Neq
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action31(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action33<
+fn __action206<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> EqualityOperator
+    __0: (usize, usize, usize),
+    __1: (usize, Call<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Expression<'input>>, usize),
+    __4: (usize, usize, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Assignment<'input>>, usize),
+) -> Assignment<'input>
 {
-    EqualityOperator::Eq
+    let __start0 = __5.2.clone();
+    let __end0 = __6.0.clone();
+    let __start1 = __7.2.clone();
+    let __end1 = __7.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action143(
+        errors,
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action32(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+        __7,
+        __temp1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action34<
+fn __action207<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, left, _): (usize, Term<'input>, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<(ComparisonOperator, Term<'input>)>, usize),
-) -> Comparison<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, alloc::vec::Vec<Declaration<'input>>, usize),
+    __3: (usize, &'input str, usize),
+) -> Block<'input>
 {
-    Comparison { left:left, rest:rest }
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action29(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action35<
+fn __action208<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ComparisonOperator
+    __0: (usize, usize, usize),
+    __1: (usize, Primary<'input>, usize),
+    __2: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+) -> Call<'input>
 {
-    ComparisonOperator::Gt
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action54(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action36<
+fn __action209<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ComparisonOperator
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, core::option::Option<Arguments<'input>>, usize),
+    __3: (usize, &'input str, usize),
+) -> CallRhs<'input>
 {
-    ComparisonOperator::Ge
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action55(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action37<
+fn __action210<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ComparisonOperator
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Expression<'input>>, usize),
+    __3: (usize, &'input str, usize),
+) -> CallRhs<'input>
 {
-    ComparisonOperator::Lt
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action57(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action38<
+fn __action211<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ComparisonOperator
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Identifier<'input>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, alloc::vec::Vec<Function<'input>>, usize),
+    __7: (usize, &'input str, usize),
+) -> ClassDeclaration<'input>
 {
-    ComparisonOperator::Le
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action192(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action39<
+fn __action212<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, left, _): (usize, Factor<'input>, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<(TermOperator, Factor<'input>)>, usize),
-) -> Term<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, alloc::vec::Vec<Function<'input>>, usize),
+    __5: (usize, &'input str, usize),
+) -> ClassDeclaration<'input>
 {
-    Term { left:left, rest:rest }
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action193(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action40<
+fn __action213<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> TermOperator
+    __0: (usize, usize, usize),
+    __1: (usize, Term<'input>, usize),
+    __2: (usize, alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>, usize),
+) -> Comparison<'input>
 {
-    TermOperator::Minus
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action39(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action41<
+fn __action214<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> TermOperator
+    __0: (usize, usize, usize),
+    __1: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __2: (usize, &'input str, usize),
+) -> Declaration<'input>
 {
-    TermOperator::Plus
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action42<
+fn __action215<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, left, _): (usize, Unary<'input>, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<(FactorOperator, Unary<'input>)>, usize),
-) -> Factor<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, Comparison<'input>, usize),
+    __2: (usize, alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>, usize),
+) -> Equality<'input>
 {
-    Factor { left:left, rest:rest }
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action36(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action43<
+fn __action216<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> FactorOperator
+    __0: (usize, usize, usize),
+    __1: (usize, Expression<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> ExprStatement<'input>
 {
-    FactorOperator::Slash
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action44<
+fn __action217<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> FactorOperator
+    __0: (usize, usize, usize),
+    __1: (usize, Unary<'input>, usize),
+    __2: (usize, alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>, usize),
+) -> Factor<'input>
 {
-    FactorOperator::Star
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action47(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action45<
+fn __action218<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, operator, _): (usize, UnaryOperator, usize),
-    (_, right, _): (usize, Box<Unary<'input>>, usize),
-) -> Unary<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, ForInitializer<'input>, usize),
+    __4: (usize, core::option::Option<Expression<'input>>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, core::option::Option<Expression<'input>>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Box<Statement<'input>>, usize),
+) -> ForStatement<'input>
 {
-    Unary::Unary { operator:operator, right:right }
+    let __start0 = __8.2.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action128(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action46<
+fn __action219<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, c, _): (usize, Call<'input>, usize),
-) -> Unary<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, ForInitializer<'input>, usize),
+    __4: (usize, core::option::Option<Expression<'input>>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, core::option::Option<Expression<'input>>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Box<Statement<'input>>, usize),
+) -> ForStatement<'input>
 {
-    Unary::Call(c)
+    let __start0 = __8.2.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action130(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action47<
+fn __action220<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> UnaryOperator
+    __0: (usize, usize, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, core::option::Option<Parameters<'input>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    __6: (usize, Block<'input>, usize),
+) -> Function<'input>
 {
-    UnaryOperator::LogicNot
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action48<
+fn __action221<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> UnaryOperator
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Function<'input>, usize),
+) -> FunctionDeclaration<'input>
 {
-    UnaryOperator::Neg
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action49<
+fn __action222<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, target, _): (usize, Primary<'input>, usize),
-    (_, rhs, _): (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
-) -> Call<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+) -> Identifier<'input>
 {
-    Call { target:target, rhs:rhs }
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action75(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action50<
+fn __action223<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, arguments, _): (usize, Arguments<'input>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> CallRhs<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Expression<'input>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Statement<'input>>, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Statement<'input>>, usize),
+) -> IfStatement<'input>
 {
-    CallRhs::Call(arguments)
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action127(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action51<
+fn __action224<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, member, _): (usize, &'input str, usize),
-) -> CallRhs<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Expression<'input>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Statement<'input>>, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Statement<'input>>, usize),
+) -> IfStatement<'input>
 {
-    CallRhs::Member(member)
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action131(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action52<
+fn __action225<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Primary<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, Equality<'input>, usize),
+) -> LogicAnd<'input>
 {
-    Primary::True
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action199(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action53<
+fn __action226<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Primary<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, Equality<'input>, usize),
+    __2: (usize, alloc::vec::Vec<Equality<'input>>, usize),
+) -> LogicAnd<'input>
 {
-    Primary::False
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action200(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action54<
+fn __action227<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Primary<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, LogicAnd<'input>, usize),
+) -> LogicOr<'input>
 {
-    Primary::Nil
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action203(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action55<
+fn __action228<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Primary<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, LogicAnd<'input>, usize),
+    __2: (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
+) -> LogicOr<'input>
 {
-    Primary::This
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action204(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action56<
+fn __action229<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, n, _): (usize, &'input str, usize),
-) -> Primary<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Expression<'input>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Statement<'input>>, usize),
+) -> Statement<'input>
 {
-    Primary::Number(n)
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action57<
+fn __action230<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
 ) -> Primary<'input>
 {
-    Primary::String(s)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action58(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action58<
+fn __action231<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, i, _): (usize, &'input str, usize),
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
 ) -> Primary<'input>
 {
-    Primary::Identifier(i)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action59(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action59<
+fn __action232<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Expression<'input>>, usize),
-    (_, _, _): (usize, &'input str, usize),
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
 ) -> Primary<'input>
 {
-    Primary::Parenthesized(e)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action60(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action60<
+fn __action233<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, i, _): (usize, &'input str, usize),
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
 ) -> Primary<'input>
 {
-    Primary::SuperMember(i)
-}
-
-#[allow(unused_variables)]
-fn __action61<
-    'input,
->(
-    input: &'input str,
-    (_, name, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, parameters, _): (usize, core::option::Option<Parameters<'input>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Block<'input>, usize),
-) -> Function<'input>
-{
-    Function { name:name, parameters:parameters, body:body }
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action61(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action62<
+fn __action234<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, first, _): (usize, &'input str, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<&'input str>, usize),
-) -> Parameters<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Expression<'input>>, usize),
+    __3: (usize, &'input str, usize),
+) -> Primary<'input>
 {
-    Parameters { first:first, rest:rest }
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action65(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action63<
+fn __action235<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, first, _): (usize, Box<Expression<'input>>, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<Expression<'input>>, usize),
-) -> Arguments<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Identifier<'input>, usize),
+) -> Primary<'input>
 {
-    Arguments { first:first, rest:rest }
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action66(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action64<
+fn __action236<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Expression<'input>>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Vec<Expression<'input>>, usize),
+    __3: (usize, &'input str, usize),
+) -> Primary<'input>
 {
-    alloc::vec![]
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action67(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action65<
+fn __action237<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Expression<'input>>, usize),
-) -> alloc::vec::Vec<Expression<'input>>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Expression<'input>, usize),
+    __3: (usize, &'input str, usize),
+) -> PrintStatement<'input>
 {
-    v
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action66<
+fn __action238<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Expression<'input>, usize),
-) -> Expression<'input>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, core::option::Option<Expression<'input>>, usize),
+    __3: (usize, &'input str, usize),
+) -> ReturnStatement<'input>
 {
-    __0
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action28(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action67<
+fn __action239<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<&'input str>
+    __0: (usize, usize, usize),
+    __1: (usize, ComparisonOperator, usize),
+) -> Spanned<ComparisonOperator>
 {
-    alloc::vec![]
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action107(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action68<
+fn __action240<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<&'input str>, usize),
-) -> alloc::vec::Vec<&'input str>
+    __0: (usize, usize, usize),
+    __1: (usize, EqualityOperator, usize),
+) -> Spanned<EqualityOperator>
 {
-    v
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action111(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action69<
+fn __action241<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, &'input str, usize),
-) -> &'input str
+    __0: (usize, usize, usize),
+    __1: (usize, FactorOperator, usize),
+) -> Spanned<FactorOperator>
 {
-    __0
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action99(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action70<
+fn __action242<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Parameters<'input>, usize),
-) -> core::option::Option<Parameters<'input>>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+) -> Spanned<&'input str>
 {
-    Some(__0)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action89(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action71<
+fn __action243<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Parameters<'input>>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+) -> Spanned<&'input str>
 {
-    None
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action88(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action72<
+fn __action244<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, t, _): (usize, Expression<'input>, usize),
-) -> Box<Expression<'input>>
+    __0: (usize, usize, usize),
+    __1: (usize, TermOperator, usize),
+) -> Spanned<TermOperator>
 {
-    Box::new(t)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action103(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action73<
+fn __action245<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<CallRhs<'input>>
+    __0: (usize, usize, usize),
+    __1: (usize, UnaryOperator, usize),
+) -> Spanned<UnaryOperator>
 {
-    alloc::vec![]
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action95(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action74<
+fn __action246<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
-) -> alloc::vec::Vec<CallRhs<'input>>
+    __0: (usize, usize, usize),
+    __1: (usize, Factor<'input>, usize),
+    __2: (usize, alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>, usize),
+) -> Term<'input>
 {
-    v
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action75<
+fn __action247<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, t, _): (usize, Unary<'input>, usize),
-) -> Box<Unary<'input>>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+) -> TypeAnnotation<'input>
 {
-    Box::new(t)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action73(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action76<
+fn __action248<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(FactorOperator, Unary<'input>)>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Vec<TypeAnnotation<'input>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<TypeAnnotation<'input>>, usize),
+) -> TypeAnnotation<'input>
 {
-    alloc::vec![]
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action74(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action77<
+fn __action249<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(FactorOperator, Unary<'input>)>, usize),
-) -> alloc::vec::Vec<(FactorOperator, Unary<'input>)>
+    __0: (usize, usize, usize),
+    __1: (usize, Spanned<UnaryOperator>, usize),
+    __2: (usize, Box<Unary<'input>>, usize),
+) -> Unary<'input>
 {
-    v
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action50(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action78<
+fn __action250<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, FactorOperator, usize),
-    (_, __1, _): (usize, Unary<'input>, usize),
-) -> (FactorOperator, Unary<'input>)
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Expression<'input>, usize),
+    __6: (usize, &'input str, usize),
+) -> VariableDeclaration<'input>
 {
-    (__0, __1)
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action195(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action79<
+fn __action251<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(TermOperator, Factor<'input>)>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    __4: (usize, &'input str, usize),
+) -> VariableDeclaration<'input>
 {
-    alloc::vec![]
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action196(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action80<
+fn __action252<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(TermOperator, Factor<'input>)>, usize),
-) -> alloc::vec::Vec<(TermOperator, Factor<'input>)>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Expression<'input>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Statement<'input>>, usize),
+) -> WhileStatement<'input>
 {
-    v
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action126(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action81<
+fn __action253<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, TermOperator, usize),
-    (_, __1, _): (usize, Factor<'input>, usize),
-) -> (TermOperator, Factor<'input>)
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Expression<'input>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Statement<'input>>, usize),
+) -> WhileStatement<'input>
 {
-    (__0, __1)
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action143(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action129(
+        errors,
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action82<
+fn __action254<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(ComparisonOperator, Term<'input>)>
+    __0: (usize, usize, usize),
+    __1: (usize, Primary<'input>, usize),
+) -> Call<'input>
 {
-    alloc::vec![]
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action92(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action208(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action83<
+fn __action255<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(ComparisonOperator, Term<'input>)>, usize),
-) -> alloc::vec::Vec<(ComparisonOperator, Term<'input>)>
+    __0: (usize, usize, usize),
+    __1: (usize, Primary<'input>, usize),
+    __2: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+) -> Call<'input>
 {
-    v
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action93(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action208(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action84<
+fn __action256<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, ComparisonOperator, usize),
-    (_, __1, _): (usize, Term<'input>, usize),
-) -> (ComparisonOperator, Term<'input>)
+    __0: (usize, core::option::Option<Call<'input>>, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Assignment<'input>>, usize),
+) -> Assignment<'input>
 {
-    (__0, __1)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action205(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action85<
+fn __action257<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>
+    __0: (usize, Call<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Expression<'input>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Assignment<'input>>, usize),
+) -> Assignment<'input>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __3.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action144(
+        errors,
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action206(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __temp1,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action86<
+fn __action258<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>, usize),
-) -> alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>
+    __0: (usize, &'input str, usize),
+    __1: (usize, alloc::vec::Vec<Declaration<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> Block<'input>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action207(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action87<
+fn __action259<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, EqualityOperator, usize),
-    (_, __1, _): (usize, Comparison<'input>, usize),
-) -> (EqualityOperator, Comparison<'input>)
+    __0: (usize, Primary<'input>, usize),
+) -> Call<'input>
 {
-    (__0, __1)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action254(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action88<
+fn __action260<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Equality<'input>>
+    __0: (usize, Primary<'input>, usize),
+    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+) -> Call<'input>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action255(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action89<
+fn __action261<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Equality<'input>>, usize),
-) -> alloc::vec::Vec<Equality<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, core::option::Option<Arguments<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> CallRhs<'input>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action209(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action90<
+fn __action262<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Equality<'input>, usize),
-) -> Equality<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Expression<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> CallRhs<'input>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action210(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action91<
+fn __action263<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<LogicAnd<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Identifier<'input>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, alloc::vec::Vec<Function<'input>>, usize),
+    __6: (usize, &'input str, usize),
+) -> ClassDeclaration<'input>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action211(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action92<
+fn __action264<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
-) -> alloc::vec::Vec<LogicAnd<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, alloc::vec::Vec<Function<'input>>, usize),
+    __4: (usize, &'input str, usize),
+) -> ClassDeclaration<'input>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action212(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action93<
+fn __action265<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, LogicAnd<'input>, usize),
-) -> LogicAnd<'input>
+    __0: (usize, Term<'input>, usize),
+    __1: (usize, alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>, usize),
+) -> Comparison<'input>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action213(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action94<
+fn __action266<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, t, _): (usize, Assignment<'input>, usize),
-) -> Box<Assignment<'input>>
+    __0: (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    __1: (usize, &'input str, usize),
+) -> Declaration<'input>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action214(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action95<
+fn __action267<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Call<'input>, usize),
-) -> core::option::Option<Call<'input>>
+    __0: (usize, Comparison<'input>, usize),
+    __1: (usize, alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>, usize),
+) -> Equality<'input>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action215(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action96<
+fn __action268<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Call<'input>>
+    __0: (usize, Expression<'input>, usize),
+    __1: (usize, &'input str, usize),
+) -> ExprStatement<'input>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action216(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action97<
+fn __action269<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Call<'input>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Call<'input>
+    __0: (usize, Unary<'input>, usize),
+    __1: (usize, alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>, usize),
+) -> Factor<'input>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action217(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action98<
+fn __action270<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, t, _): (usize, ExprStatement<'input>, usize),
-) -> Box<ExprStatement<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ForInitializer<'input>, usize),
+    __3: (usize, core::option::Option<Expression<'input>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, core::option::Option<Expression<'input>>, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Statement<'input>>, usize),
+) -> ForStatement<'input>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action218(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action99<
+fn __action271<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, t, _): (usize, Statement<'input>, usize),
-) -> Box<Statement<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ForInitializer<'input>, usize),
+    __3: (usize, core::option::Option<Expression<'input>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, core::option::Option<Expression<'input>>, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Statement<'input>>, usize),
+) -> ForStatement<'input>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action219(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action100<
+fn __action272<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Expression<'input>, usize),
-) -> core::option::Option<Expression<'input>>
+    __0: (usize, Identifier<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, core::option::Option<Parameters<'input>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    __5: (usize, Block<'input>, usize),
+) -> Function<'input>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action220(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action101<
+fn __action273<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Expression<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Function<'input>, usize),
+) -> FunctionDeclaration<'input>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action221(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action102<
+fn __action274<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Expression<'input>, usize),
-) -> core::option::Option<Expression<'input>>
+    __0: (usize, &'input str, usize),
+) -> Identifier<'input>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action222(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action103<
+fn __action275<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Expression<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Expression<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Statement<'input>>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Statement<'input>>, usize),
+) -> IfStatement<'input>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action223(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action104<
+fn __action276<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Expression<'input>, usize),
-) -> Expression<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Expression<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Statement<'input>>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Statement<'input>>, usize),
+) -> IfStatement<'input>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action224(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action105<
+fn __action277<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Function<'input>>
+    __0: (usize, Equality<'input>, usize),
+) -> LogicAnd<'input>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action225(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action106<
+fn __action278<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Function<'input>>, usize),
-) -> alloc::vec::Vec<Function<'input>>
+    __0: (usize, Equality<'input>, usize),
+    __1: (usize, alloc::vec::Vec<Equality<'input>>, usize),
+) -> LogicAnd<'input>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action226(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action107<
+fn __action279<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> core::option::Option<&'input str>
+    __0: (usize, LogicAnd<'input>, usize),
+) -> LogicOr<'input>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action227(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action108<
+fn __action280<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<&'input str>
+    __0: (usize, LogicAnd<'input>, usize),
+    __1: (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
+) -> LogicOr<'input>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action228(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action109<
+fn __action281<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, &'input str, usize),
-) -> &'input str
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Expression<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Statement<'input>>, usize),
+) -> Statement<'input>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action229(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action110<
+fn __action282<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Declaration<'input>>
+    __0: (usize, &'input str, usize),
+) -> Primary<'input>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action230(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action111<
+fn __action283<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Declaration<'input>>, usize),
-) -> alloc::vec::Vec<Declaration<'input>>
+    __0: (usize, &'input str, usize),
+) -> Primary<'input>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action231(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action112<
+fn __action284<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Declaration<'input>, usize),
-) -> alloc::vec::Vec<Declaration<'input>>
+    __0: (usize, &'input str, usize),
+) -> Primary<'input>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action232(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action113<
+fn __action285<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Declaration<'input>>, usize),
-    (_, e, _): (usize, Declaration<'input>, usize),
-) -> alloc::vec::Vec<Declaration<'input>>
+    __0: (usize, &'input str, usize),
+) -> Primary<'input>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action233(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action114<
+fn __action286<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Function<'input>, usize),
-) -> alloc::vec::Vec<Function<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Expression<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> Primary<'input>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action234(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action115<
+fn __action287<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Function<'input>>, usize),
-    (_, e, _): (usize, Function<'input>, usize),
-) -> alloc::vec::Vec<Function<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Identifier<'input>, usize),
+) -> Primary<'input>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action235(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action116<
+fn __action288<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, LogicAnd<'input>, usize),
-) -> alloc::vec::Vec<LogicAnd<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<Expression<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> Primary<'input>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action236(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action117<
+fn __action289<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
-    (_, e, _): (usize, LogicAnd<'input>, usize),
-) -> alloc::vec::Vec<LogicAnd<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Expression<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> PrintStatement<'input>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action237(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action118<
+fn __action290<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Equality<'input>, usize),
-) -> alloc::vec::Vec<Equality<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, core::option::Option<Expression<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> ReturnStatement<'input>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action238(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action119<
+fn __action291<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Equality<'input>>, usize),
-    (_, e, _): (usize, Equality<'input>, usize),
-) -> alloc::vec::Vec<Equality<'input>>
+    __0: (usize, ComparisonOperator, usize),
+) -> Spanned<ComparisonOperator>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action239(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action120<
+fn __action292<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, (EqualityOperator, Comparison<'input>), usize),
-) -> alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>
+    __0: (usize, EqualityOperator, usize),
+) -> Spanned<EqualityOperator>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action240(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action121<
+fn __action293<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>, usize),
-    (_, e, _): (usize, (EqualityOperator, Comparison<'input>), usize),
-) -> alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>
+    __0: (usize, FactorOperator, usize),
+) -> Spanned<FactorOperator>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action241(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action122<
+fn __action294<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, (ComparisonOperator, Term<'input>), usize),
-) -> alloc::vec::Vec<(ComparisonOperator, Term<'input>)>
+    __0: (usize, &'input str, usize),
+) -> Spanned<&'input str>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action242(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action123<
+fn __action295<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(ComparisonOperator, Term<'input>)>, usize),
-    (_, e, _): (usize, (ComparisonOperator, Term<'input>), usize),
-) -> alloc::vec::Vec<(ComparisonOperator, Term<'input>)>
+    __0: (usize, &'input str, usize),
+) -> Spanned<&'input str>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action243(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action124<
+fn __action296<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, (TermOperator, Factor<'input>), usize),
-) -> alloc::vec::Vec<(TermOperator, Factor<'input>)>
+    __0: (usize, TermOperator, usize),
+) -> Spanned<TermOperator>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action244(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action125<
+fn __action297<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(TermOperator, Factor<'input>)>, usize),
-    (_, e, _): (usize, (TermOperator, Factor<'input>), usize),
-) -> alloc::vec::Vec<(TermOperator, Factor<'input>)>
+    __0: (usize, UnaryOperator, usize),
+) -> Spanned<UnaryOperator>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action245(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action126<
+fn __action298<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, (FactorOperator, Unary<'input>), usize),
-) -> alloc::vec::Vec<(FactorOperator, Unary<'input>)>
+    __0: (usize, Factor<'input>, usize),
+    __1: (usize, alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>, usize),
+) -> Term<'input>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action246(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action127<
+fn __action299<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(FactorOperator, Unary<'input>)>, usize),
-    (_, e, _): (usize, (FactorOperator, Unary<'input>), usize),
-) -> alloc::vec::Vec<(FactorOperator, Unary<'input>)>
+    __0: (usize, &'input str, usize),
+) -> TypeAnnotation<'input>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action247(
+        errors,
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action128<
+fn __action300<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, CallRhs<'input>, usize),
-) -> alloc::vec::Vec<CallRhs<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Vec<TypeAnnotation<'input>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<TypeAnnotation<'input>>, usize),
+) -> TypeAnnotation<'input>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action248(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action129<
+fn __action301<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
-    (_, e, _): (usize, CallRhs<'input>, usize),
-) -> alloc::vec::Vec<CallRhs<'input>>
+    __0: (usize, Spanned<UnaryOperator>, usize),
+    __1: (usize, Box<Unary<'input>>, usize),
+) -> Unary<'input>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action249(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action130<
+fn __action302<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> alloc::vec::Vec<&'input str>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Expression<'input>, usize),
+    __5: (usize, &'input str, usize),
+) -> VariableDeclaration<'input>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action250(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action131<
+fn __action303<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<&'input str>, usize),
-    (_, e, _): (usize, &'input str, usize),
-) -> alloc::vec::Vec<&'input str>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+    __3: (usize, &'input str, usize),
+) -> VariableDeclaration<'input>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action251(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action132<
+fn __action304<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, __0, _): (usize, Expression<'input>, usize),
-) -> alloc::vec::Vec<Expression<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Expression<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Statement<'input>>, usize),
+) -> WhileStatement<'input>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action252(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action133<
+fn __action305<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Expression<'input>>, usize),
-    (_, e, _): (usize, Expression<'input>, usize),
-) -> alloc::vec::Vec<Expression<'input>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Expression<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Statement<'input>>, usize),
+) -> WhileStatement<'input>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action253(
+        errors,
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action134<
+fn __action306<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Expression<'input>, usize),
-) -> alloc::vec::Vec<Expression<'input>>
+    __0: (usize, Primary<'input>, usize),
+    __1: (usize, &'input str, usize),
+) -> Call<'input>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action66(
+    let __end0 = __0.2.clone();
+    let __temp0 = __action259(
+        errors,
         input,
         __0,
-        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action132(
+    __action122(
+        errors,
         input,
         __temp0,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action135<
+fn __action307<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<Expression<'input>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Expression<'input>, usize),
-) -> alloc::vec::Vec<Expression<'input>>
+    __0: (usize, Primary<'input>, usize),
+    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> Call<'input>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action66(
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action260(
+        errors,
         input,
+        __0,
         __1,
-        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action133(
+    __action122(
+        errors,
         input,
-        __0,
         __temp0,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action136<
+fn __action308<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Box<Expression<'input>>, usize),
-) -> Arguments<'input>
+    __0: (usize, Primary<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Expression<'input>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Assignment<'input>>, usize),
+) -> Assignment<'input>
 {
-    let __start0 = __0.2.clone();
+    let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action64(
+    let __temp0 = __action259(
+        errors,
         input,
-        &__start0,
-        &__end0,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action63(
+    __action257(
+        errors,
         input,
-        __0,
         __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action137<
+fn __action309<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Box<Expression<'input>>, usize),
-    __1: (usize, alloc::vec::Vec<Expression<'input>>, usize),
-) -> Arguments<'input>
+    __0: (usize, Primary<'input>, usize),
+    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Expression<'input>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Assignment<'input>>, usize),
+) -> Assignment<'input>
 {
-    let __start0 = __1.0.clone();
+    let __start0 = __0.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action65(
+    let __temp0 = __action260(
+        errors,
         input,
+        __0,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action63(
+    __action257(
+        errors,
         input,
-        __0,
         __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action138<
+fn __action310<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<&'input str>
+    __0: (usize, Primary<'input>, usize),
+) -> Unary<'input>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action69(
+    let __end0 = __0.2.clone();
+    let __temp0 = __action259(
+        errors,
         input,
         __0,
-        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action130(
+    __action51(
+        errors,
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action139<
+fn __action311<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<&'input str>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<&'input str>
+    __0: (usize, Primary<'input>, usize),
+    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+) -> Unary<'input>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action69(
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action260(
+        errors,
         input,
+        __0,
         __1,
-        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action131(
+    __action51(
+        errors,
         input,
-        __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action140<
+fn __action312<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-) -> Parameters<'input>
+    __0: (usize, Primary<'input>, usize),
+    __1: (usize, &'input str, usize),
+) -> core::option::Option<Call<'input>>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action67(
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action306(
+        errors,
         input,
-        &__start0,
-        &__end0,
+        __0,
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action62(
+    __action120(
+        errors,
         input,
-        __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action141<
+fn __action313<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, alloc::vec::Vec<&'input str>, usize),
-) -> Parameters<'input>
+    __0: (usize, Primary<'input>, usize),
+    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> core::option::Option<Call<'input>>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action68(
+    let __start0 = __0.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action307(
+        errors,
         input,
+        __0,
         __1,
+        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action62(
+    __action120(
+        errors,
         input,
-        __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action142<
+fn __action314<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Primary<'input>, usize),
     __1: (usize, &'input str, usize),
-) -> core::option::Option<&'input str>
+    __2: (usize, Identifier<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Assignment<'input>>, usize),
+) -> Assignment<'input>
 {
     let __start0 = __0.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action109(
+    let __temp0 = __action312(
+        errors,
         input,
         __0,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action107(
+    __action256(
+        errors,
         input,
         __temp0,
+        __2,
+        __3,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action143<
+fn __action315<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
+    __0: (usize, Primary<'input>, usize),
+    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
+    __3: (usize, Identifier<'input>, usize),
     __4: (usize, &'input str, usize),
-    __5: (usize, alloc::vec::Vec<Function<'input>>, usize),
-    __6: (usize, &'input str, usize),
-) -> ClassDeclaration<'input>
+    __5: (usize, Box<Assignment<'input>>, usize),
+) -> Assignment<'input>
 {
-    let __start0 = __2.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action142(
+    let __start0 = __0.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action313(
+        errors,
         input,
+        __0,
+        __1,
         __2,
-        __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    __action256(
+        errors,
         input,
-        __0,
-        __1,
         __temp0,
+        __3,
         __4,
         __5,
-        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action144<
+fn __action316<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Identifier<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, alloc::vec::Vec<Function<'input>>, usize),
-    __4: (usize, &'input str, usize),
-) -> ClassDeclaration<'input>
+    __2: (usize, Box<Assignment<'input>>, usize),
+) -> Assignment<'input>
 {
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action108(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action121(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    __action256(
+        errors,
         input,
+        __temp0,
         __0,
         __1,
-        __temp0,
         __2,
-        __3,
-        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action145<
+fn __action317<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Expression<'input>, usize),
-) -> core::option::Option<Expression<'input>>
+    __0: (usize, Expression<'input>, usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<Expression<'input>>
 {
     let __start0 = __0.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action104(
+    let __temp0 = __action169(
+        errors,
         input,
         __0,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(
+    __action181(
+        errors,
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action146<
+fn __action318<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
+    __0: (usize, alloc::vec::Vec<Expression<'input>>, usize),
+    __1: (usize, Expression<'input>, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, Expression<'input>, usize),
-    __4: (usize, &'input str, usize),
-) -> VariableDeclaration<'input>
+) -> alloc::vec::Vec<Expression<'input>>
 {
-    let __start0 = __2.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action145(
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action169(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action182(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action319<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, core::option::Option<Expression<'input>>, usize),
+) -> Vec<Expression<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action167(
+        errors,
         input,
-        __2,
-        __3,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action8(
+    __action87(
+        errors,
         input,
-        __0,
-        __1,
         __temp0,
-        __4,
+        __0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action147<
+fn __action320<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-) -> VariableDeclaration<'input>
+    __0: (usize, alloc::vec::Vec<Expression<'input>>, usize),
+    __1: (usize, core::option::Option<Expression<'input>>, usize),
+) -> Vec<Expression<'input>>
 {
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action103(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action168(
+        errors,
         input,
-        &__start0,
-        &__end0,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action8(
+    __action87(
+        errors,
         input,
-        __0,
-        __1,
         __temp0,
-        __2,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action148<
+fn __action321<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Equality<'input>, usize),
-) -> alloc::vec::Vec<Equality<'input>>
+    __0: (usize, Spanned<ComparisonOperator>, usize),
+    __1: (usize, Term<'input>, usize),
+) -> alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>
 {
     let __start0 = __0.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action90(
+    let __temp0 = __action106(
+        errors,
         input,
         __0,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action118(
+    __action159(
+        errors,
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action149<
+fn __action322<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<Equality<'input>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Equality<'input>, usize),
-) -> alloc::vec::Vec<Equality<'input>>
+    __0: (usize, alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>, usize),
+    __1: (usize, Spanned<ComparisonOperator>, usize),
+    __2: (usize, Term<'input>, usize),
+) -> alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>
 {
     let __start0 = __1.0.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action90(
+    let __temp0 = __action106(
+        errors,
         input,
         __1,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action119(
+    __action160(
+        errors,
         input,
         __0,
         __temp0,
@@ -7326,22 +16272,26 @@ fn __action149<
 }
 
 #[allow(unused_variables)]
-fn __action150<
+fn __action323<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Equality<'input>, usize),
-) -> LogicAnd<'input>
+    __0: (usize, Term<'input>, usize),
+) -> Comparison<'input>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action88(
+    let __temp0 = __action104(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action30(
+    __action265(
+        errors,
         input,
         __0,
         __temp0,
@@ -7349,22 +16299,26 @@ fn __action150<
 }
 
 #[allow(unused_variables)]
-fn __action151<
+fn __action324<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Equality<'input>, usize),
-    __1: (usize, alloc::vec::Vec<Equality<'input>>, usize),
-) -> LogicAnd<'input>
+    __0: (usize, Term<'input>, usize),
+    __1: (usize, alloc::vec::Vec<(Spanned<ComparisonOperator>, Term<'input>)>, usize),
+) -> Comparison<'input>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action89(
+    let __temp0 = __action105(
+        errors,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action30(
+    __action265(
+        errors,
         input,
         __0,
         __temp0,
@@ -7372,47 +16326,55 @@ fn __action151<
 }
 
 #[allow(unused_variables)]
-fn __action152<
+fn __action325<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, LogicAnd<'input>, usize),
-) -> alloc::vec::Vec<LogicAnd<'input>>
+    __0: (usize, Spanned<EqualityOperator>, usize),
+    __1: (usize, Comparison<'input>, usize),
+) -> alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>
 {
     let __start0 = __0.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action93(
+    let __temp0 = __action110(
+        errors,
         input,
         __0,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action116(
+    __action157(
+        errors,
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action153<
+fn __action326<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, LogicAnd<'input>, usize),
-) -> alloc::vec::Vec<LogicAnd<'input>>
+    __0: (usize, alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>, usize),
+    __1: (usize, Spanned<EqualityOperator>, usize),
+    __2: (usize, Comparison<'input>, usize),
+) -> alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>
 {
     let __start0 = __1.0.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action93(
+    let __temp0 = __action110(
+        errors,
         input,
         __1,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action117(
+    __action158(
+        errors,
         input,
         __0,
         __temp0,
@@ -7420,22 +16382,26 @@ fn __action153<
 }
 
 #[allow(unused_variables)]
-fn __action154<
+fn __action327<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, LogicAnd<'input>, usize),
-) -> LogicOr<'input>
+    __0: (usize, Comparison<'input>, usize),
+) -> Equality<'input>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action91(
+    let __temp0 = __action108(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action29(
+    __action267(
+        errors,
         input,
         __0,
         __temp0,
@@ -7443,22 +16409,26 @@ fn __action154<
 }
 
 #[allow(unused_variables)]
-fn __action155<
+fn __action328<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, LogicAnd<'input>, usize),
-    __1: (usize, alloc::vec::Vec<LogicAnd<'input>>, usize),
-) -> LogicOr<'input>
+    __0: (usize, Comparison<'input>, usize),
+    __1: (usize, alloc::vec::Vec<(Spanned<EqualityOperator>, Comparison<'input>)>, usize),
+) -> Equality<'input>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action92(
+    let __temp0 = __action109(
+        errors,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action29(
+    __action267(
+        errors,
         input,
         __0,
         __temp0,
@@ -7466,22 +16436,82 @@ fn __action155<
 }
 
 #[allow(unused_variables)]
-fn __action156<
+fn __action329<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
-) -> Call<'input>
+    __0: (usize, Spanned<FactorOperator>, usize),
+    __1: (usize, Unary<'input>, usize),
+) -> alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action98(
+        errors,
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action163(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action330<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>, usize),
+    __1: (usize, Spanned<FactorOperator>, usize),
+    __2: (usize, Unary<'input>, usize),
+) -> alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action98(
+        errors,
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action164(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action331<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, Unary<'input>, usize),
+) -> Factor<'input>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action73(
+    let __temp0 = __action96(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action49(
+    __action269(
+        errors,
         input,
         __0,
         __temp0,
@@ -7489,22 +16519,26 @@ fn __action156<
 }
 
 #[allow(unused_variables)]
-fn __action157<
+fn __action332<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
-    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
-) -> Call<'input>
+    __0: (usize, Unary<'input>, usize),
+    __1: (usize, alloc::vec::Vec<(Spanned<FactorOperator>, Unary<'input>)>, usize),
+) -> Factor<'input>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action74(
+    let __temp0 = __action97(
+        errors,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action49(
+    __action269(
+        errors,
         input,
         __0,
         __temp0,
@@ -7512,274 +16546,316 @@ fn __action157<
 }
 
 #[allow(unused_variables)]
-fn __action158<
+fn __action333<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
-    __1: (usize, &'input str, usize),
-) -> Call<'input>
+    __0: (usize, Spanned<TermOperator>, usize),
+    __1: (usize, Factor<'input>, usize),
+) -> alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action156(
+    let __end0 = __1.2.clone();
+    let __temp0 = __action102(
+        errors,
         input,
         __0,
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action97(
+    __action161(
+        errors,
         input,
         __temp0,
-        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action159<
+fn __action334<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
-    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
-    __2: (usize, &'input str, usize),
-) -> Call<'input>
+    __0: (usize, alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>, usize),
+    __1: (usize, Spanned<TermOperator>, usize),
+    __2: (usize, Factor<'input>, usize),
+) -> alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action157(
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action102(
+        errors,
         input,
-        __0,
         __1,
+        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action97(
+    __action162(
+        errors,
         input,
+        __0,
         __temp0,
-        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action160<
+fn __action335<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
-) -> Unary<'input>
+    __0: (usize, Factor<'input>, usize),
+) -> Term<'input>
 {
-    let __start0 = __0.0.clone();
+    let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action156(
+    let __temp0 = __action100(
+        errors,
         input,
-        __0,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(
+    __action298(
+        errors,
         input,
+        __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action161<
+fn __action336<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
-    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
-) -> Unary<'input>
+    __0: (usize, Factor<'input>, usize),
+    __1: (usize, alloc::vec::Vec<(Spanned<TermOperator>, Factor<'input>)>, usize),
+) -> Term<'input>
 {
-    let __start0 = __0.0.clone();
+    let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action157(
+    let __temp0 = __action101(
+        errors,
         input,
-        __0,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(
+    __action298(
+        errors,
         input,
+        __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action162<
+fn __action337<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
+    __0: (usize, TypeAnnotation<'input>, usize),
     __1: (usize, &'input str, usize),
-) -> core::option::Option<Call<'input>>
+) -> alloc::vec::Vec<TypeAnnotation<'input>>
 {
     let __start0 = __0.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action158(
+    let __temp0 = __action176(
+        errors,
         input,
         __0,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action95(
+    __action179(
+        errors,
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action163<
+fn __action338<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
-    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
+    __0: (usize, alloc::vec::Vec<TypeAnnotation<'input>>, usize),
+    __1: (usize, TypeAnnotation<'input>, usize),
     __2: (usize, &'input str, usize),
-) -> core::option::Option<Call<'input>>
+) -> alloc::vec::Vec<TypeAnnotation<'input>>
 {
-    let __start0 = __0.0.clone();
+    let __start0 = __1.0.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action159(
+    let __temp0 = __action176(
+        errors,
         input,
-        __0,
         __1,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action95(
+    __action180(
+        errors,
         input,
+        __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action164<
+fn __action339<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Box<Assignment<'input>>, usize),
-) -> Assignment<'input>
+    __0: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+) -> Vec<TypeAnnotation<'input>>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action162(
+    let __end0 = __0.0.clone();
+    let __temp0 = __action174(
+        errors,
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action27(
+    __action81(
+        errors,
         input,
         __temp0,
-        __2,
-        __3,
-        __4,
+        __0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action165<
+fn __action340<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Primary<'input>, usize),
-    __1: (usize, alloc::vec::Vec<CallRhs<'input>>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Box<Assignment<'input>>, usize),
-) -> Assignment<'input>
+    __0: (usize, alloc::vec::Vec<TypeAnnotation<'input>>, usize),
+    __1: (usize, core::option::Option<TypeAnnotation<'input>>, usize),
+) -> Vec<TypeAnnotation<'input>>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action163(
+    let __end0 = __0.2.clone();
+    let __temp0 = __action175(
+        errors,
         input,
         __0,
-        __1,
-        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action27(
+    __action81(
+        errors,
         input,
         __temp0,
-        __3,
-        __4,
-        __5,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action166<
+fn __action341<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Identifier<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, Box<Assignment<'input>>, usize),
-) -> Assignment<'input>
+    __2: (usize, core::option::Option<Parameters<'input>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, TypeAnnotation<'input>, usize),
+    __5: (usize, Block<'input>, usize),
+) -> Function<'input>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action96(
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action136(
+        errors,
         input,
-        &__start0,
-        &__end0,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action27(
+    __action272(
+        errors,
         input,
-        __temp0,
         __0,
         __1,
         __2,
+        __3,
+        __temp0,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action167<
+fn __action342<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, ComparisonOperator, usize),
-    __1: (usize, Term<'input>, usize),
-) -> alloc::vec::Vec<(ComparisonOperator, Term<'input>)>
+    __0: (usize, Identifier<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, core::option::Option<Parameters<'input>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Block<'input>, usize),
+) -> Function<'input>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action137(
+        errors,
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action122(
+    __action272(
+        errors,
         input,
+        __0,
+        __1,
+        __2,
+        __3,
         __temp0,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action168<
+fn __action343<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(ComparisonOperator, Term<'input>)>, usize),
-    __1: (usize, ComparisonOperator, usize),
-    __2: (usize, Term<'input>, usize),
-) -> alloc::vec::Vec<(ComparisonOperator, Term<'input>)>
+    __0: (usize, Identifier<'input>, usize),
+    __1: (usize, TypeAnnotation<'input>, usize),
+) -> Parameter<'input>
 {
     let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action84(
+    let __end0 = __1.2.clone();
+    let __temp0 = __action136(
+        errors,
         input,
         __1,
-        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action123(
+    __action70(
+        errors,
         input,
         __0,
         __temp0,
@@ -7787,22 +16863,26 @@ fn __action168<
 }
 
 #[allow(unused_variables)]
-fn __action169<
+fn __action344<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Term<'input>, usize),
-) -> Comparison<'input>
+    __0: (usize, Identifier<'input>, usize),
+) -> Parameter<'input>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action82(
+    let __temp0 = __action137(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action34(
+    __action70(
+        errors,
         input,
         __0,
         __temp0,
@@ -7810,304 +16890,378 @@ fn __action169<
 }
 
 #[allow(unused_variables)]
-fn __action170<
+fn __action345<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Term<'input>, usize),
-    __1: (usize, alloc::vec::Vec<(ComparisonOperator, Term<'input>)>, usize),
-) -> Comparison<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, TypeAnnotation<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Expression<'input>, usize),
+    __5: (usize, &'input str, usize),
+) -> VariableDeclaration<'input>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action83(
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action136(
+        errors,
         input,
-        __1,
+        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action34(
+    __action302(
+        errors,
         input,
         __0,
+        __1,
         __temp0,
+        __3,
+        __4,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action171<
+fn __action346<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, EqualityOperator, usize),
-    __1: (usize, Comparison<'input>, usize),
-) -> alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Expression<'input>, usize),
+    __4: (usize, &'input str, usize),
+) -> VariableDeclaration<'input>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action87(
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action137(
+        errors,
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action120(
+    __action302(
+        errors,
         input,
+        __0,
+        __1,
         __temp0,
+        __2,
+        __3,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action172<
+fn __action347<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>, usize),
-    __1: (usize, EqualityOperator, usize),
-    __2: (usize, Comparison<'input>, usize),
-) -> alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, TypeAnnotation<'input>, usize),
+    __3: (usize, &'input str, usize),
+) -> VariableDeclaration<'input>
 {
-    let __start0 = __1.0.clone();
+    let __start0 = __2.0.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action87(
+    let __temp0 = __action136(
+        errors,
         input,
-        __1,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action121(
+    __action303(
+        errors,
         input,
         __0,
+        __1,
         __temp0,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action173<
+fn __action348<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Comparison<'input>, usize),
-) -> Equality<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> VariableDeclaration<'input>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action85(
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action137(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action31(
+    __action303(
+        errors,
         input,
         __0,
+        __1,
         __temp0,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action174<
+fn __action349<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Comparison<'input>, usize),
-    __1: (usize, alloc::vec::Vec<(EqualityOperator, Comparison<'input>)>, usize),
-) -> Equality<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Arguments<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> CallRhs<'input>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action86(
+    let __temp0 = __action90(
+        errors,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action31(
+    __action261(
+        errors,
         input,
         __0,
         __temp0,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action175<
+fn __action350<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, FactorOperator, usize),
-    __1: (usize, Unary<'input>, usize),
-) -> alloc::vec::Vec<(FactorOperator, Unary<'input>)>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> CallRhs<'input>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action78(
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action91(
+        errors,
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action126(
+    __action261(
+        errors,
         input,
+        __0,
         __temp0,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action176<
+fn __action351<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(FactorOperator, Unary<'input>)>, usize),
-    __1: (usize, FactorOperator, usize),
-    __2: (usize, Unary<'input>, usize),
-) -> alloc::vec::Vec<(FactorOperator, Unary<'input>)>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Block<'input>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action78(
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action145(
+        errors,
         input,
-        __1,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action127(
+    __action258(
+        errors,
         input,
         __0,
         __temp0,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action177<
+fn __action352<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Unary<'input>, usize),
-) -> Factor<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, alloc::vec::Vec<Declaration<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> Block<'input>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action76(
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action146(
+        errors,
         input,
-        &__start0,
-        &__end0,
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action42(
+    __action258(
+        errors,
         input,
         __0,
         __temp0,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action178<
+fn __action353<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Unary<'input>, usize),
-    __1: (usize, alloc::vec::Vec<(FactorOperator, Unary<'input>)>, usize),
-) -> Factor<'input>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Program<'input>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action145(
+        errors,
         input,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action42(
+    __action1(
+        errors,
         input,
-        __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action179<
+fn __action354<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, TermOperator, usize),
-    __1: (usize, Factor<'input>, usize),
-) -> alloc::vec::Vec<(TermOperator, Factor<'input>)>
+    __0: (usize, alloc::vec::Vec<Declaration<'input>>, usize),
+) -> Program<'input>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action81(
+    let __end0 = __0.2.clone();
+    let __temp0 = __action146(
+        errors,
         input,
         __0,
-        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action124(
+    __action1(
+        errors,
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action180<
+fn __action355<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(TermOperator, Factor<'input>)>, usize),
-    __1: (usize, TermOperator, usize),
-    __2: (usize, Factor<'input>, usize),
-) -> alloc::vec::Vec<(TermOperator, Factor<'input>)>
+    __0: (usize, Expression<'input>, usize),
+) -> Vec<Expression<'input>>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action81(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action123(
+        errors,
         input,
-        __1,
-        __2,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action125(
+    __action319(
+        errors,
         input,
-        __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action181<
+fn __action356<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Factor<'input>, usize),
-) -> Term<'input>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<Expression<'input>>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action79(
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action124(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action39(
+    __action319(
+        errors,
         input,
-        __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action182<
+fn __action357<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, Factor<'input>, usize),
-    __1: (usize, alloc::vec::Vec<(TermOperator, Factor<'input>)>, usize),
-) -> Term<'input>
+    __0: (usize, alloc::vec::Vec<Expression<'input>>, usize),
+    __1: (usize, Expression<'input>, usize),
+) -> Vec<Expression<'input>>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action80(
+    let __temp0 = __action123(
+        errors,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action39(
+    __action320(
+        errors,
         input,
         __0,
         __temp0,
@@ -8115,103 +17269,226 @@ fn __action182<
 }
 
 #[allow(unused_variables)]
-fn __action183<
+fn __action358<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-) -> Block<'input>
+    __0: (usize, alloc::vec::Vec<Expression<'input>>, usize),
+) -> Vec<Expression<'input>>
 {
     let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action110(
+    let __end0 = __0.2.clone();
+    let __temp0 = __action124(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action25(
+    __action320(
+        errors,
         input,
         __0,
         __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action359<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ForInitializer<'input>, usize),
+    __3: (usize, Expression<'input>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Expression<'input>, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Statement<'input>>, usize),
+) -> ForStatement<'input>
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __start1 = __5.0.clone();
+    let __end1 = __5.2.clone();
+    let __temp0 = __action123(
+        errors,
+        input,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action123(
+        errors,
+        input,
+        __5,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action270(
+        errors,
+        input,
+        __0,
         __1,
+        __2,
+        __temp0,
+        __4,
+        __temp1,
+        __6,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action184<
+fn __action360<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, alloc::vec::Vec<Declaration<'input>>, usize),
-    __2: (usize, &'input str, usize),
-) -> Block<'input>
+    __1: (usize, &'input str, usize),
+    __2: (usize, ForInitializer<'input>, usize),
+    __3: (usize, Expression<'input>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Statement<'input>>, usize),
+) -> ForStatement<'input>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action111(
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __start1 = __4.2.clone();
+    let __end1 = __5.0.clone();
+    let __temp0 = __action123(
+        errors,
         input,
-        __1,
+        __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action25(
+    let __temp1 = __action124(
+        errors,
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action270(
+        errors,
         input,
         __0,
-        __temp0,
+        __1,
         __2,
+        __temp0,
+        __4,
+        __temp1,
+        __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action185<
+fn __action361<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Program<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ForInitializer<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Expression<'input>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Statement<'input>>, usize),
+) -> ForStatement<'input>
 {
-    let __start0 = __lookbehind.clone();
-    let __end0 = __lookahead.clone();
-    let __temp0 = __action110(
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __start1 = __4.0.clone();
+    let __end1 = __4.2.clone();
+    let __temp0 = __action124(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
+    let __temp1 = __action123(
+        errors,
+        input,
+        __4,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action270(
+        errors,
         input,
+        __0,
+        __1,
+        __2,
         __temp0,
+        __3,
+        __temp1,
+        __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action186<
+fn __action362<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<Declaration<'input>>, usize),
-) -> Program<'input>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ForInitializer<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Statement<'input>>, usize),
+) -> ForStatement<'input>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action111(
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __4.0.clone();
+    let __temp0 = __action124(
+        errors,
         input,
-        __0,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
+    let __temp1 = __action124(
+        errors,
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action270(
+        errors,
         input,
+        __0,
+        __1,
+        __2,
         __temp0,
+        __3,
+        __temp1,
+        __4,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action187<
+fn __action363<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -8227,17 +17504,20 @@ fn __action187<
     let __end0 = __3.2.clone();
     let __start1 = __5.0.clone();
     let __end1 = __5.2.clone();
-    let __temp0 = __action100(
+    let __temp0 = __action123(
+        errors,
         input,
         __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action100(
+    let __temp1 = __action123(
+        errors,
         input,
         __5,
     );
     let __temp1 = (__start1, __temp1, __end1);
-    __action17(
+    __action271(
+        errors,
         input,
         __0,
         __1,
@@ -8251,9 +17531,11 @@ fn __action187<
 }
 
 #[allow(unused_variables)]
-fn __action188<
+fn __action364<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -8268,18 +17550,21 @@ fn __action188<
     let __end0 = __3.2.clone();
     let __start1 = __4.2.clone();
     let __end1 = __5.0.clone();
-    let __temp0 = __action100(
+    let __temp0 = __action123(
+        errors,
         input,
         __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action101(
+    let __temp1 = __action124(
+        errors,
         input,
         &__start1,
         &__end1,
     );
     let __temp1 = (__start1, __temp1, __end1);
-    __action17(
+    __action271(
+        errors,
         input,
         __0,
         __1,
@@ -8293,9 +17578,11 @@ fn __action188<
 }
 
 #[allow(unused_variables)]
-fn __action189<
+fn __action365<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -8310,18 +17597,21 @@ fn __action189<
     let __end0 = __3.0.clone();
     let __start1 = __4.0.clone();
     let __end1 = __4.2.clone();
-    let __temp0 = __action101(
+    let __temp0 = __action124(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action100(
+    let __temp1 = __action123(
+        errors,
         input,
         __4,
     );
     let __temp1 = (__start1, __temp1, __end1);
-    __action17(
+    __action271(
+        errors,
         input,
         __0,
         __1,
@@ -8335,9 +17625,11 @@ fn __action189<
 }
 
 #[allow(unused_variables)]
-fn __action190<
+fn __action366<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -8351,19 +17643,22 @@ fn __action190<
     let __end0 = __3.0.clone();
     let __start1 = __3.2.clone();
     let __end1 = __4.0.clone();
-    let __temp0 = __action101(
+    let __temp0 = __action124(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action101(
+    let __temp1 = __action124(
+        errors,
         input,
         &__start1,
         &__end1,
     );
     let __temp1 = (__start1, __temp1, __end1);
-    __action17(
+    __action271(
+        errors,
         input,
         __0,
         __1,
@@ -8377,9 +17672,11 @@ fn __action190<
 }
 
 #[allow(unused_variables)]
-fn __action191<
+fn __action367<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, Expression<'input>, usize),
@@ -8388,12 +17685,14 @@ fn __action191<
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action100(
+    let __temp0 = __action123(
+        errors,
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action23(
+    __action290(
+        errors,
         input,
         __0,
         __temp0,
@@ -8402,9 +17701,11 @@ fn __action191<
 }
 
 #[allow(unused_variables)]
-fn __action192<
+fn __action368<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
@@ -8412,13 +17713,15 @@ fn __action192<
 {
     let __start0 = __0.2.clone();
     let __end0 = __1.0.clone();
-    let __temp0 = __action101(
+    let __temp0 = __action124(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action23(
+    __action290(
+        errors,
         input,
         __0,
         __temp0,
@@ -8427,27 +17730,31 @@ fn __action192<
 }
 
 #[allow(unused_variables)]
-fn __action193<
+fn __action369<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
+    __3: (usize, Identifier<'input>, usize),
     __4: (usize, &'input str, usize),
     __5: (usize, &'input str, usize),
 ) -> ClassDeclaration<'input>
 {
     let __start0 = __4.2.clone();
     let __end0 = __5.0.clone();
-    let __temp0 = __action105(
+    let __temp0 = __action138(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action143(
+    __action263(
+        errors,
         input,
         __0,
         __1,
@@ -8460,14 +17767,16 @@ fn __action193<
 }
 
 #[allow(unused_variables)]
-fn __action194<
+fn __action370<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize),
+    __3: (usize, Identifier<'input>, usize),
     __4: (usize, &'input str, usize),
     __5: (usize, alloc::vec::Vec<Function<'input>>, usize),
     __6: (usize, &'input str, usize),
@@ -8475,12 +17784,14 @@ fn __action194<
 {
     let __start0 = __5.0.clone();
     let __end0 = __5.2.clone();
-    let __temp0 = __action106(
+    let __temp0 = __action139(
+        errors,
         input,
         __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action143(
+    __action263(
+        errors,
         input,
         __0,
         __1,
@@ -8493,25 +17804,29 @@ fn __action194<
 }
 
 #[allow(unused_variables)]
-fn __action195<
+fn __action371<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
     __2: (usize, &'input str, usize),
     __3: (usize, &'input str, usize),
 ) -> ClassDeclaration<'input>
 {
     let __start0 = __2.2.clone();
     let __end0 = __3.0.clone();
-    let __temp0 = __action105(
+    let __temp0 = __action138(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action144(
+    __action264(
+        errors,
         input,
         __0,
         __1,
@@ -8522,12 +17837,14 @@ fn __action195<
 }
 
 #[allow(unused_variables)]
-fn __action196<
+fn __action372<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, Identifier<'input>, usize),
     __2: (usize, &'input str, usize),
     __3: (usize, alloc::vec::Vec<Function<'input>>, usize),
     __4: (usize, &'input str, usize),
@@ -8535,12 +17852,14 @@ fn __action196<
 {
     let __start0 = __3.0.clone();
     let __end0 = __3.2.clone();
-    let __temp0 = __action106(
+    let __temp0 = __action139(
+        errors,
         input,
         __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action144(
+    __action264(
+        errors,
         input,
         __0,
         __1,
@@ -8551,11 +17870,83 @@ fn __action196<
 }
 
 #[allow(unused_variables)]
-fn __action197<
+fn __action373<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Identifier<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Parameters<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, TypeAnnotation<'input>, usize),
+    __5: (usize, Block<'input>, usize),
+) -> Function<'input>
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action85(
+        errors,
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action341(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action374<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, Identifier<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, TypeAnnotation<'input>, usize),
+    __4: (usize, Block<'input>, usize),
+) -> Function<'input>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action86(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action341(
+        errors,
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action375<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, Identifier<'input>, usize),
     __1: (usize, &'input str, usize),
     __2: (usize, Parameters<'input>, usize),
     __3: (usize, &'input str, usize),
@@ -8564,12 +17955,14 @@ fn __action197<
 {
     let __start0 = __2.0.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action70(
+    let __temp0 = __action85(
+        errors,
         input,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action61(
+    __action342(
+        errors,
         input,
         __0,
         __1,
@@ -8580,11 +17973,13 @@ fn __action197<
 }
 
 #[allow(unused_variables)]
-fn __action198<
+fn __action376<
     'input,
+    'err,
 >(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
     input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Identifier<'input>, usize),
     __1: (usize, &'input str, usize),
     __2: (usize, &'input str, usize),
     __3: (usize, Block<'input>, usize),
@@ -8592,13 +17987,15 @@ fn __action198<
 {
     let __start0 = __1.2.clone();
     let __end0 = __2.0.clone();
-    let __temp0 = __action71(
+    let __temp0 = __action86(
+        errors,
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action61(
+    __action342(
+        errors,
         input,
         __0,
         __1,
@@ -8608,16 +18005,125 @@ fn __action198<
     )
 }
 
-pub trait __ToTriple<'input, > {
+#[allow(unused_variables)]
+fn __action377<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, TypeAnnotation<'input>, usize),
+) -> Vec<TypeAnnotation<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action172(
+        errors,
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action339(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action378<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<TypeAnnotation<'input>>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action173(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action339(
+        errors,
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action379<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<TypeAnnotation<'input>>, usize),
+    __1: (usize, TypeAnnotation<'input>, usize),
+) -> Vec<TypeAnnotation<'input>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action172(
+        errors,
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action340(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action380<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, &'static str>>,
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<TypeAnnotation<'input>>, usize),
+) -> Vec<TypeAnnotation<'input>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action173(
+        errors,
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action340(
+        errors,
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, 'err, >
+{
     fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+impl<'input, 'err, > __ToTriple<'input, 'err, > for (usize, Token<'input>, usize)
+{
     fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), &'static str> {
+impl<'input, 'err, > __ToTriple<'input, 'err, > for Result<(usize, Token<'input>, usize), &'static str>
+{
     fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
         match value {
             Ok(v) => Ok(v),
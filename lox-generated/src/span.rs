@@ -0,0 +1,97 @@
+/// A range of byte offsets into the source, from `start` up to but not
+/// including `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// The smallest span covering both `self` and `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+/// A node that carries no location of its own, such as an operator, paired
+/// with where it appeared.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spanned<T> {
+    pub node: T,
+    pub span: Span,
+}
+
+/// Converts byte offsets into line and column numbers.
+#[derive(Debug, Clone)]
+pub struct LineIndex<'source> {
+    source: &'source str,
+    /// Byte offsets at which each line starts.
+    line_starts: Vec<usize>,
+}
+
+impl<'source> LineIndex<'source> {
+    pub fn new(source: &'source str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    /// The 1-based line containing `offset`.
+    pub fn line(&self, offset: usize) -> usize {
+        match self.line_starts.binary_search(&offset) {
+            Ok(line) => line + 1,
+            Err(line) => line,
+        }
+    }
+
+    /// The 1-based line and column of `offset`. Columns count characters, not
+    /// bytes, and an offset inside a character counts as that character.
+    pub fn line_col(&self, offset: usize) -> (usize, usize) {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line = self.line(offset);
+        let start = self.line_starts[line - 1];
+        let column = self.source[start..offset].chars().count() + 1;
+        (line, column)
+    }
+
+    /// The text of the 1-based `line`, without its line terminator.
+    pub fn line_text(&self, line: usize) -> &'source str {
+        let start = self.line_starts[line - 1];
+        let end = self
+            .line_starts
+            .get(line)
+            .map_or(self.source.len(), |&next| next - 1);
+        self.source[start..end].trim_end_matches('\r')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_col() {
+        let index = LineIndex::new("var a;\n\nprint \"é\" + a;");
+        assert_eq!(index.line_col(0), (1, 1));
+        assert_eq!(index.line_col(4), (1, 5));
+        assert_eq!(index.line_col(7), (2, 1));
+        assert_eq!(index.line_col(8), (3, 1));
+        // The 'é' is two bytes but one column.
+        assert_eq!(index.line_col(18), (3, 10));
+        // Offsets inside it snap back to its start.
+        assert_eq!(index.line_col(16), (3, 8));
+        assert_eq!(index.line_text(3), "print \"é\" + a;");
+        assert_eq!(index.line_text(2), "");
+    }
+}
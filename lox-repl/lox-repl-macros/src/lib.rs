@@ -6,6 +6,9 @@ use syn::braced;
 use syn::parse::Parse;
 use syn::parse_macro_input;
 use syn::token::Colon;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
 use syn::Ident;
 use syn::Type;
 
@@ -75,7 +78,7 @@ pub fn generate_ast(input: TokenStream) -> TokenStream {
                 .map(|f| {
                     let name = &f.name;
                     let ty = &f.kind;
-                    quote! {pub(crate) #name: #ty}
+                    quote! {pub #name: #ty}
                 })
                 .collect::<Vec<_>>();
             let fields_wo_visibility = ty
@@ -96,11 +99,11 @@ pub fn generate_ast(input: TokenStream) -> TokenStream {
                 base.to_string().to_snake_case()
             );
             quote! {
-                pub(crate) struct #name {
+                pub struct #name {
                     #(#fields),*
                 }
                 impl #name {
-                    pub(crate) fn new(#(#fields_wo_visibility),*) -> Self {
+                    pub fn new(#(#fields_wo_visibility),*) -> Self {
                         Self {
                             #(#field_names),*
                         }
@@ -135,10 +138,10 @@ pub fn generate_ast(input: TokenStream) -> TokenStream {
         .collect::<Vec<_>>();
 
     let output = quote! {
-        pub(crate) enum #base {
+        pub enum #base {
             #(#base_variants),*
         }
-        pub(crate) trait #trait_name {
+        pub trait #trait_name {
             #accept_fn;
         }
         impl #trait_name for #base {
@@ -182,9 +185,103 @@ fn define_visitor(base: &Ident, types: &[AstType], name: &Ident) -> proc_macro2:
         })
         .collect::<Vec<_>>();
     let visitor = quote! {
-        pub(crate) trait #name<R> {
+        pub trait #name<R> {
             #(#visit_fns)*
         }
     };
     visitor
 }
+
+/// Converts a struct into an instance of a new class with the same name,
+/// with a field for each of the struct's.
+#[proc_macro_derive(IntoLox)]
+pub fn derive_into_lox(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match named_fields(&input) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let name = &input.ident;
+    let class = name.to_string();
+    let field_names = fields.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let output = quote! {
+        impl #impl_generics ::lox::convert::IntoLox for #name #ty_generics #where_clause {
+            fn into_lox(self) -> ::std::option::Option<::lox::object::Object> {
+                ::lox::convert::instance(
+                    #class,
+                    ::std::vec![
+                        #((#field_names, ::lox::convert::IntoLox::into_lox(self.#fields))),*
+                    ],
+                )
+            }
+        }
+    };
+    output.into()
+}
+
+/// Converts any instance with the right fields into a struct. Missing fields
+/// are read as `nil`.
+#[proc_macro_derive(FromLox)]
+pub fn derive_from_lox(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match named_fields(&input) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let name = &input.ident;
+    let field_names = fields.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let output = quote! {
+        impl #impl_generics ::lox::convert::FromLox for #name #ty_generics #where_clause {
+            fn from_lox(
+                value: ::std::option::Option<::lox::object::Object>,
+            ) -> ::std::result::Result<Self, ::lox::convert::FromLoxError> {
+                let instance = match value {
+                    ::std::option::Option::Some(::lox::object::Object::Instance(instance)) => {
+                        instance
+                    }
+                    _ => {
+                        return ::std::result::Result::Err(::lox::convert::FromLoxError::new(
+                            "an instance",
+                        ))
+                    }
+                };
+                let instance = instance.read();
+                ::std::result::Result::Ok(Self {
+                    #(#fields: ::lox::convert::FromLox::from_lox(instance.field(#field_names))
+                        .map_err(|e| {
+                            ::lox::convert::FromLoxError::new(::std::format!(
+                                "an instance whose '{}' field is {}",
+                                #field_names,
+                                e.expected
+                            ))
+                        })?),*
+                })
+            }
+        }
+    };
+    output.into()
+}
+
+fn named_fields(input: &DeriveInput) -> syn::Result<Vec<Ident>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields
+                .named
+                .iter()
+                .filter_map(|f| f.ident.clone())
+                .collect()),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                "only structs with named fields can be converted to and from Lox",
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "only structs can be converted to and from Lox",
+        )),
+    }
+}
@@ -1,77 +1,68 @@
-use std::fs::File;
 use std::io;
-use std::io::stdin;
-use std::io::BufReader;
 use std::path::PathBuf;
 use std::process;
 
-use ast::ExprPrinter;
 use dialoguer::console::style;
 use dialoguer::theme::ColorfulTheme;
 use dialoguer::Input;
-use interpreter::Interpreter;
-use io::Read;
 use log::error;
-use once_cell::sync::Lazy;
-use parking_lot::RwLock;
-use parser::Parser;
-use runtime_error::RuntimeError;
-use scanner::Scanner;
+use lox::diagnostic::ErrorFormat;
+use lox::Capabilities;
+use lox::Error;
+use lox::Interpreter;
 use structopt::StructOpt;
-use token::Token;
-use token_type::TokenType;
-
-mod ast;
-mod interpreter;
-mod object;
-mod parser;
-mod runtime_error;
-mod scanner;
-mod token;
-mod token_type;
-
-static mut HAD_ERROR: bool = false;
-static mut HAD_RUNTIME_ERROR: bool = false;
-static INTERPRETER: Lazy<RwLock<Interpreter>> = Lazy::new(|| RwLock::new(Interpreter::new()));
 
 #[derive(StructOpt)]
 struct Opt {
+    /// How to print errors: `human` or `json`, one object per line.
+    #[structopt(long, default_value = "human", possible_values = &["human", "json"])]
+    error_format: ErrorFormat,
+    /// Leave out the standard library's string, maths and other functions.
+    #[structopt(long)]
+    no_stdlib: bool,
+    /// Leave out the functions that read and write files and stdin.
+    #[structopt(long)]
+    no_io: bool,
     script: Option<PathBuf>,
 }
 
 fn main() {
     pretty_env_logger::init();
     let opt = Opt::from_args();
+    let interpreter = Interpreter::with_capabilities(Capabilities {
+        core: !opt.no_stdlib,
+        io: !opt.no_io,
+    });
 
     match opt.script {
-        Some(script) => run_file(script).unwrap_or_else(|e| {
+        Some(script) => run_file(interpreter, script, opt.error_format).unwrap_or_else(|e| {
             error!("{}", e);
             process::exit(1)
         }),
-        None => run_prompt().unwrap_or_else(|e| {
+        None => run_prompt(interpreter, opt.error_format).unwrap_or_else(|e| {
             error!("{}", e);
             process::exit(1)
         }),
     }
 }
 
-fn run_file(script: PathBuf) -> io::Result<()> {
-    let mut bytes = vec![];
-    File::open(script)?.read_to_end(&mut bytes)?;
-    run(String::from_utf8(bytes).unwrap_or_else(|e| {
-        error!("{}", e);
-        process::exit(1)
-    }));
-    if unsafe { HAD_ERROR } {
-        process::exit(65);
-    }
-    if unsafe { HAD_RUNTIME_ERROR } {
-        process::exit(70);
+fn run_file(mut interpreter: Interpreter, script: PathBuf, format: ErrorFormat) -> io::Result<()> {
+    match interpreter.run_file(&script) {
+        Ok(()) => Ok(()),
+        Err(Error::Io(e)) => Err(e),
+        Err(e) => {
+            emit_diagnostics(
+                &e,
+                format,
+                &script.display().to_string(),
+                interpreter.source(),
+            );
+            process::exit(e.exit_code())
+        }
     }
-    Ok(())
 }
 
-fn run_prompt() -> io::Result<()> {
+fn run_prompt(mut interpreter: Interpreter, format: ErrorFormat) -> io::Result<()> {
     let theme = ColorfulTheme {
         prompt_prefix: style("> ".into()),
         ..Default::default()
@@ -83,45 +74,14 @@ fn run_prompt() -> io::Result<()> {
             process::exit(1)
         });
 
-        run(line);
-
-        unsafe { HAD_ERROR = false };
+        if let Err(e) = interpreter.eval(&line) {
+            emit_diagnostics(&e, format, "<repl>", interpreter.source());
+        }
     }
 }
 
-fn run(source: String) {
-    let scanner = Scanner::new(&source);
-
-    let mut parser = Parser::new(scanner.scan_tokens());
-    let expression = parser.parse();
-
-    if unsafe { HAD_ERROR } {
-        return;
-    }
-
-    if let Some(expression) = expression {
-        INTERPRETER.write().interpret(&expression);
+fn emit_diagnostics(error: &Error, format: ErrorFormat, name: &str, source: &str) {
+    for diagnostic in error.diagnostics() {
+        diagnostic.emit(format, name, Some(source));
     }
 }
-
-fn err(line: usize, message: &str) {
-    report(line, "", message);
-}
-
-fn err_at(token: &Token, message: &str) {
-    if token.kind == TokenType::Eof {
-        report(token.line, " at end", message);
-    } else {
-        report(token.line, &format!(" at '{}'", token.lexeme), message);
-    }
-}
-
-fn report(line: usize, loc: &str, message: &str) {
-    error!("[line {}] Error{}: {}", line, loc, message);
-    unsafe { HAD_ERROR = true };
-}
-
-fn runtime_error(err: RuntimeError) {
-    error!("{}\n[line {}]", err.message(), err.token().line);
-    unsafe { HAD_RUNTIME_ERROR = true };
-}
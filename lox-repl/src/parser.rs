@@ -1,187 +0,0 @@
-use crate::ast::BinaryExpr;
-use crate::ast::Expr;
-use crate::ast::GroupingExpr;
-use crate::ast::LiteralExpr;
-use crate::ast::UnaryExpr;
-use crate::object::Object;
-use crate::token::Token;
-use crate::token_type::TokenType;
-
-pub(crate) struct Parser {
-    tokens: Vec<Token>,
-    current: usize,
-}
-
-struct ParseError;
-
-impl Parser {
-    pub(crate) fn new(tokens: Vec<Token>) -> Self {
-        Self { tokens, current: 0 }
-    }
-
-    pub(crate) fn parse(&mut self) -> Option<Expr> {
-        self.expression().ok()
-    }
-
-    fn expression(&mut self) -> Result<Expr, ParseError> {
-        self.equality()
-    }
-
-    fn equality(&mut self) -> Result<Expr, ParseError> {
-        let mut expr = self.comparison()?;
-
-        while self.matches(&[TokenType::BangEqual, TokenType::EqualEqual]) {
-            let operator = self.previous().clone();
-            let right = self.comparison()?;
-            expr = Expr::Binary(BinaryExpr::new(Box::new(expr), operator, Box::new(right)));
-        }
-
-        Ok(expr)
-    }
-
-    fn comparison(&mut self) -> Result<Expr, ParseError> {
-        let mut expr = self.term()?;
-
-        while self.matches(&[
-            TokenType::Greater,
-            TokenType::GreaterEqual,
-            TokenType::Less,
-            TokenType::LessEqual,
-        ]) {
-            let operator = self.previous().clone();
-            let right = self.term()?;
-            expr = Expr::Binary(BinaryExpr::new(Box::new(expr), operator, Box::new(right)));
-        }
-
-        Ok(expr)
-    }
-
-    fn term(&mut self) -> Result<Expr, ParseError> {
-        let mut expr = self.factor()?;
-
-        while self.matches(&[TokenType::Minus, TokenType::Plus]) {
-            let operator = self.previous().clone();
-            let right = self.factor()?;
-            expr = Expr::Binary(BinaryExpr::new(Box::new(expr), operator, Box::new(right)));
-        }
-
-        Ok(expr)
-    }
-
-    fn factor(&mut self) -> Result<Expr, ParseError> {
-        let mut expr = self.unary()?;
-
-        while self.matches(&[TokenType::Slash, TokenType::Star]) {
-            let operator = self.previous().clone();
-            let right = self.unary()?;
-            expr = Expr::Binary(BinaryExpr::new(Box::new(expr), operator, Box::new(right)));
-        }
-
-        Ok(expr)
-    }
-
-    fn unary(&mut self) -> Result<Expr, ParseError> {
-        if self.matches(&[TokenType::Bang, TokenType::Minus]) {
-            let operator = self.previous().clone();
-            let right = self.unary()?;
-            return Ok(Expr::Unary(UnaryExpr::new(operator, Box::new(right))));
-        }
-
-        self.primary()
-    }
-
-    fn primary(&mut self) -> Result<Expr, ParseError> {
-        if self.matches(&[TokenType::False]) {
-            Ok(Expr::Literal(LiteralExpr::new(Some(Object::Boolean(
-                false,
-            )))))
-        } else if self.matches(&[TokenType::True]) {
-            Ok(Expr::Literal(LiteralExpr::new(Some(Object::Boolean(true)))))
-        } else if self.matches(&[TokenType::Nil]) {
-            Ok(Expr::Literal(LiteralExpr::new(None)))
-        } else if self.matches(&[TokenType::Number, TokenType::String]) {
-            Ok(Expr::Literal(LiteralExpr::new(
-                self.previous().literal.clone(),
-            )))
-        } else if self.matches(&[TokenType::LeftParen]) {
-            let expr = self.expression()?;
-            self.consume(TokenType::RightParen, "Expect ')' after expression.")?;
-            Ok(Expr::Grouping(GroupingExpr::new(Box::new(expr))))
-        } else {
-            Err(self.error(self.peek(), "Expect expression."))
-        }
-    }
-
-    fn consume(&mut self, kind: TokenType, message: &str) -> Result<&Token, ParseError> {
-        if self.check(kind) {
-            return Ok(self.advance());
-        }
-
-        Err(self.error(self.peek(), message))
-    }
-
-    fn error(&self, token: &Token, message: &str) -> ParseError {
-        crate::err_at(token, message);
-        ParseError
-    }
-
-    fn matches(&mut self, types: &[TokenType]) -> bool {
-        for ty in types {
-            if self.check(*ty) {
-                self.advance();
-                return true;
-            }
-        }
-        false
-    }
-
-    fn check(&self, ty: TokenType) -> bool {
-        if self.at_end() {
-            false
-        } else {
-            self.peek().kind == ty
-        }
-    }
-
-    fn advance(&mut self) -> &Token {
-        if !self.at_end() {
-            self.current += 1;
-        }
-        self.previous()
-    }
-
-    fn at_end(&self) -> bool {
-        self.peek().kind == TokenType::Eof
-    }
-
-    fn peek(&self) -> &Token {
-        &self.tokens[self.current]
-    }
-
-    fn previous(&self) -> &Token {
-        &self.tokens[self.current - 1]
-    }
-
-    fn synchronize(&mut self) {
-        self.advance();
-
-        while !self.at_end() {
-            if self.previous().kind == TokenType::Semicolon {
-                return;
-            }
-
-            match self.peek().kind {
-                TokenType::Class
-                | TokenType::Fun
-                | TokenType::Var
-                | TokenType::For
-                | TokenType::If
-                | TokenType::While
-                | TokenType::Print
-                | TokenType::Return => return,
-                _ => {}
-            }
-            self.advance();
-        }
-    }
-}
@@ -0,0 +1,225 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use lox_repl_macros::generate_ast;
+
+use crate::object::Object;
+use crate::token::Token;
+
+generate_ast! {
+    Expr
+    Assign {
+        id: usize
+        name: Token
+        value: Box<Expr>
+    }
+    Binary {
+        left: Box<Expr>
+        operator: Token
+        right: Box<Expr>
+    }
+    Call {
+        callee: Box<Expr>
+        paren: Token
+        arguments: Vec<Expr>
+    }
+    Get {
+        object: Box<Expr>
+        name: Token
+    }
+    Grouping {
+        expression: Box<Expr>
+    }
+    Index {
+        object: Box<Expr>
+        bracket: Token
+        index: Box<Expr>
+    }
+    List {
+        elements: Vec<Expr>
+    }
+    Literal {
+        value: Option<Object>
+    }
+    Logical {
+        left: Box<Expr>
+        operator: Token
+        right: Box<Expr>
+    }
+    Set {
+        object: Box<Expr>
+        name: Token
+        value: Box<Expr>
+    }
+    SetIndex {
+        object: Box<Expr>
+        bracket: Token
+        index: Box<Expr>
+        value: Box<Expr>
+    }
+    Super {
+        id: usize
+        keyword: Token
+        method: Token
+    }
+    This {
+        id: usize
+        keyword: Token
+    }
+    Unary {
+        operator: Token
+        right: Box<Expr>
+    }
+    Variable {
+        id: usize
+        name: Token
+    }
+}
+
+generate_ast! {
+    Stmt
+    Block {
+        statements: Vec<Stmt>
+    }
+    Class {
+        name: Token
+        superclass: Option<VariableExpr>
+        methods: Vec<FunctionStmt>
+    }
+    Expression {
+        expression: Expr
+    }
+    Function {
+        name: Token
+        params: Vec<Token>
+        body: Arc<Vec<Stmt>>
+    }
+    If {
+        condition: Expr
+        then_branch: Box<Stmt>
+        else_branch: Option<Box<Stmt>>
+    }
+    Print {
+        expression: Expr
+    }
+    Return {
+        keyword: Token
+        value: Option<Expr>
+    }
+    Var {
+        name: Token
+        initializer: Option<Expr>
+    }
+    While {
+        condition: Expr
+        body: Box<Stmt>
+    }
+}
+
+/// Renders expressions in a fully parenthesized, Lisp-like form, to show
+/// how they were parsed.
+pub struct ExprPrinter;
+impl ExprPrinter {
+    pub fn print(&mut self, expr: &Expr) -> String {
+        expr.accept(self)
+    }
+    fn parenthesize(&mut self, name: &str, exprs: &[&Expr]) -> String {
+        let mut s = format!("({}", name);
+        for expr in exprs {
+            s.push_str(&format!(" {}", expr.accept(self)));
+        }
+        s.push(')');
+        s
+    }
+}
+impl ExprVisitor<String> for ExprPrinter {
+    fn visit_assign_expr(&mut self, expr: &AssignExpr) -> String {
+        let name = format!("= {}", expr.name.lexeme);
+        self.parenthesize(&name, &[&expr.value])
+    }
+    fn visit_binary_expr(&mut self, expr: &BinaryExpr) -> String {
+        self.parenthesize(&expr.operator.lexeme, &[&expr.left, &expr.right])
+    }
+    fn visit_call_expr(&mut self, expr: &CallExpr) -> String {
+        let mut exprs = vec![expr.callee.as_ref()];
+        exprs.extend(&expr.arguments);
+        self.parenthesize("call", &exprs)
+    }
+    fn visit_get_expr(&mut self, expr: &GetExpr) -> String {
+        let name = format!(". {}", expr.name.lexeme);
+        self.parenthesize(&name, &[&expr.object])
+    }
+    fn visit_grouping_expr(&mut self, expr: &GroupingExpr) -> String {
+        self.parenthesize("group", &[&expr.expression])
+    }
+    fn visit_index_expr(&mut self, expr: &IndexExpr) -> String {
+        self.parenthesize("[]", &[&expr.object, &expr.index])
+    }
+    fn visit_list_expr(&mut self, expr: &ListExpr) -> String {
+        let elements = expr.elements.iter().collect::<Vec<_>>();
+        self.parenthesize("list", &elements)
+    }
+    fn visit_literal_expr(&mut self, expr: &LiteralExpr) -> String {
+        match &expr.value {
+            None => String::from("nil"),
+            Some(value) => value.to_string(),
+        }
+    }
+    fn visit_logical_expr(&mut self, expr: &LogicalExpr) -> String {
+        self.parenthesize(&expr.operator.lexeme, &[&expr.left, &expr.right])
+    }
+    fn visit_set_expr(&mut self, expr: &SetExpr) -> String {
+        let name = format!(".= {}", expr.name.lexeme);
+        self.parenthesize(&name, &[&expr.object, &expr.value])
+    }
+    fn visit_set_index_expr(&mut self, expr: &SetIndexExpr) -> String {
+        self.parenthesize("[]=", &[&expr.object, &expr.index, &expr.value])
+    }
+    fn visit_super_expr(&mut self, expr: &SuperExpr) -> String {
+        format!("(super {})", expr.method.lexeme)
+    }
+    fn visit_this_expr(&mut self, _expr: &ThisExpr) -> String {
+        String::from("this")
+    }
+    fn visit_unary_expr(&mut self, expr: &UnaryExpr) -> String {
+        self.parenthesize(&expr.operator.lexeme, &[&expr.right])
+    }
+    fn visit_variable_expr(&mut self, expr: &VariableExpr) -> String {
+        expr.name.lexeme.clone()
+    }
+}
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Allocates an identifier for an expression that the resolver binds.
+///
+/// Identifiers are unique across every parse, so that resolutions from
+/// earlier REPL lines can never be mistaken for ones from later lines.
+pub fn next_id() -> usize {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use crate::reporter::Reporter;
+    use crate::scanner::Scanner;
+
+    #[test]
+    fn prints_expressions() {
+        let mut reporter = Reporter::new();
+        let tokens =
+            Scanner::starting_at("-1 * (2 + x.y) or f(z = 3);", 0, &mut reporter).scan_tokens();
+        let statements = Parser::new(tokens, &mut reporter).parse();
+        let expression = match &statements[..] {
+            [Stmt::Expression(stmt)] => &stmt.expression,
+            _ => panic!("expected one expression statement"),
+        };
+        assert_eq!(
+            ExprPrinter.print(expression),
+            "(or (* (- 1) (group (+ 2 (. y x)))) (call f (= z 3)))"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+use crate::interpreter::Interpreter;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+
+pub trait LoxCallable {
+    fn arity(&self) -> Arity;
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<Option<Object>>,
+    ) -> Result<Option<Object>, RuntimeError>;
+}
+
+/// How many arguments a callable takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    Exactly(usize),
+    /// Variadic, with this many required arguments first.
+    AtLeast(usize),
+}
+
+impl Arity {
+    pub fn accepts(self, count: usize) -> bool {
+        match self {
+            Self::Exactly(arity) => count == arity,
+            Self::AtLeast(arity) => count >= arity,
+        }
+    }
+}
+
+impl From<usize> for Arity {
+    fn from(arity: usize) -> Self {
+        Self::Exactly(arity)
+    }
+}
+
+impl Display for Arity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exactly(arity) => write!(f, "{}", arity),
+            Self::AtLeast(arity) => write!(f, "at least {}", arity),
+        }
+    }
+}
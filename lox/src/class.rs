@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::callable::Arity;
+use crate::callable::LoxCallable;
+use crate::function::LoxFunction;
+use crate::instance::LoxInstance;
+use crate::interpreter::Interpreter;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+
+pub struct LoxClass {
+    pub name: String,
+    superclass: Option<Arc<LoxClass>>,
+    methods: HashMap<String, Arc<LoxFunction>>,
+}
+
+impl LoxClass {
+    pub fn new(
+        name: String,
+        superclass: Option<Arc<LoxClass>>,
+        methods: HashMap<String, Arc<LoxFunction>>,
+    ) -> Self {
+        Self {
+            name,
+            superclass,
+            methods,
+        }
+    }
+
+    /// Looks up a method on this class, then up the superclass chain.
+    pub fn find_method(&self, name: &str) -> Option<Arc<LoxFunction>> {
+        self.methods.get(name).cloned().or_else(|| {
+            self.superclass
+                .as_ref()
+                .and_then(|superclass| superclass.find_method(name))
+        })
+    }
+}
+
+impl LoxCallable for Arc<LoxClass> {
+    fn arity(&self) -> Arity {
+        self.find_method("init")
+            .map(|initializer| initializer.arity())
+            .unwrap_or(Arity::Exactly(0))
+    }
+
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<Option<Object>>,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let instance = Arc::new(RwLock::new(LoxInstance::new(self.clone())));
+        if let Some(initializer) = self.find_method("init") {
+            initializer
+                .bind(instance.clone())
+                .call(interpreter, arguments)?;
+        }
+
+        Ok(Some(Object::Instance(instance)))
+    }
+}
+
+impl Display for LoxClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Debug for LoxClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
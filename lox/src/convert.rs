@@ -0,0 +1,257 @@
+//! Conversions between Rust values and Lox values, where a Lox value is an
+//! `Option<Object>` with `None` standing for `nil`.
+//!
+//! Plain structs can convert to and from instances of a class with the same
+//! name using `#[derive(FromLox, IntoLox)]`.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::class::LoxClass;
+use crate::instance::LoxInstance;
+use crate::list;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+
+pub trait IntoLox {
+    fn into_lox(self) -> Option<Object>;
+}
+
+pub trait FromLox: Sized {
+    fn from_lox(value: Option<Object>) -> Result<Self, FromLoxError>;
+}
+
+/// A Lox value that couldn't be converted to the Rust type asked for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromLoxError {
+    /// What the value should have been, such as "a number".
+    pub expected: String,
+}
+
+impl FromLoxError {
+    pub fn new(expected: impl Into<String>) -> Self {
+        Self {
+            expected: expected.into(),
+        }
+    }
+}
+
+impl Display for FromLoxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Expected {}.", self.expected)
+    }
+}
+
+impl std::error::Error for FromLoxError {}
+
+/// Converts the `index`th argument of a native function, reporting a value
+/// of the wrong type as a runtime error.
+pub fn argument<T: FromLox>(arguments: &[Option<Object>], index: usize) -> Result<T, RuntimeError> {
+    T::from_lox(arguments.get(index).cloned().flatten())
+        .map_err(|e| RuntimeError::argument_type(index, &e.expected))
+}
+
+/// Makes a new instance of a class called `class`, which has no methods, for
+/// a struct to convert into.
+pub fn instance(class: &str, fields: Vec<(&str, Option<Object>)>) -> Option<Object> {
+    let class = LoxClass::new(class.to_string(), None, HashMap::new());
+    let mut instance = LoxInstance::new(Arc::new(class));
+    for (name, value) in fields {
+        instance.set_field(name, value);
+    }
+    Some(Object::Instance(Arc::new(RwLock::new(instance))))
+}
+
+impl IntoLox for Object {
+    fn into_lox(self) -> Option<Object> {
+        Some(self)
+    }
+}
+
+impl FromLox for Object {
+    fn from_lox(value: Option<Object>) -> Result<Self, FromLoxError> {
+        value.ok_or_else(|| FromLoxError::new("a value other than nil"))
+    }
+}
+
+impl IntoLox for () {
+    fn into_lox(self) -> Option<Object> {
+        None
+    }
+}
+
+impl<T: IntoLox> IntoLox for Option<T> {
+    fn into_lox(self) -> Option<Object> {
+        self.and_then(IntoLox::into_lox)
+    }
+}
+
+impl<T: FromLox> FromLox for Option<T> {
+    fn from_lox(value: Option<Object>) -> Result<Self, FromLoxError> {
+        match value {
+            None => Ok(None),
+            Some(_) => T::from_lox(value)
+                .map(Some)
+                .map_err(|e| FromLoxError::new(format!("{} or nil", e.expected))),
+        }
+    }
+}
+
+impl<T: IntoLox> IntoLox for Vec<T> {
+    fn into_lox(self) -> Option<Object> {
+        Some(list::new(self.into_iter().map(IntoLox::into_lox).collect()))
+    }
+}
+
+/// Copies the list, so later changes to it aren't seen in the `Vec`.
+impl<T: FromLox> FromLox for Vec<T> {
+    fn from_lox(value: Option<Object>) -> Result<Self, FromLoxError> {
+        let list = match value {
+            Some(Object::List(list)) => list,
+            _ => return Err(FromLoxError::new("a list")),
+        };
+        let elements = list.read().clone();
+        elements
+            .into_iter()
+            .map(T::from_lox)
+            .collect::<Result<_, _>>()
+            .map_err(|e| FromLoxError::new(format!("a list of only {}", e.expected)))
+    }
+}
+
+impl IntoLox for bool {
+    fn into_lox(self) -> Option<Object> {
+        Some(Object::Boolean(self))
+    }
+}
+
+impl FromLox for bool {
+    fn from_lox(value: Option<Object>) -> Result<Self, FromLoxError> {
+        match value {
+            Some(Object::Boolean(b)) => Ok(b),
+            _ => Err(FromLoxError::new("a boolean")),
+        }
+    }
+}
+
+impl IntoLox for String {
+    fn into_lox(self) -> Option<Object> {
+        Some(Object::String(self))
+    }
+}
+
+impl IntoLox for &str {
+    fn into_lox(self) -> Option<Object> {
+        Some(Object::String(self.to_string()))
+    }
+}
+
+impl FromLox for String {
+    fn from_lox(value: Option<Object>) -> Result<Self, FromLoxError> {
+        match value {
+            Some(Object::String(s)) => Ok(s),
+            _ => Err(FromLoxError::new("a string")),
+        }
+    }
+}
+
+impl IntoLox for f64 {
+    fn into_lox(self) -> Option<Object> {
+        Some(Object::Number(self))
+    }
+}
+
+impl FromLox for f64 {
+    fn from_lox(value: Option<Object>) -> Result<Self, FromLoxError> {
+        match value {
+            Some(Object::Number(n)) => Ok(n),
+            _ => Err(FromLoxError::new("a number")),
+        }
+    }
+}
+
+impl IntoLox for f32 {
+    fn into_lox(self) -> Option<Object> {
+        Some(Object::Number(self.into()))
+    }
+}
+
+impl FromLox for f32 {
+    fn from_lox(value: Option<Object>) -> Result<Self, FromLoxError> {
+        f64::from_lox(value).map(|n| n as f32)
+    }
+}
+
+/// Lox only has doubles, so integers convert back only if they're whole and
+/// in range.
+macro_rules! integer_conversions {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoLox for $ty {
+                fn into_lox(self) -> Option<Object> {
+                    Some(Object::Number(self as f64))
+                }
+            }
+
+            impl FromLox for $ty {
+                fn from_lox(value: Option<Object>) -> Result<Self, FromLoxError> {
+                    // `MAX as f64` rounds up for 64-bit types, so compare
+                    // against one past `MAX`, a power of two that converts
+                    // exactly.
+                    let end = (<$ty>::MAX / 2 + 1) as f64 * 2.0;
+                    match value {
+                        Some(Object::Number(n))
+                            if n.fract() == 0.0 && n >= <$ty>::MIN as f64 && n < end =>
+                        {
+                            Ok(n as $ty)
+                        }
+                        _ => Err(FromLoxError::new(format!(
+                            "an integer from {} to {}",
+                            <$ty>::MIN,
+                            <$ty>::MAX
+                        ))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_conversions!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(n: f64) -> Option<Object> {
+        Some(Object::Number(n))
+    }
+
+    #[test]
+    fn converts_integers_only_when_in_range() {
+        assert_eq!(u8::from_lox(number(255.0)), Ok(255));
+        assert!(u8::from_lox(number(256.0)).is_err());
+        assert!(u8::from_lox(number(-1.0)).is_err());
+        assert_eq!(i32::from_lox(number(-2147483648.0)), Ok(i32::MIN));
+        assert!(i32::from_lox(number(2147483648.0)).is_err());
+        assert!(i32::from_lox(number(1.5)).is_err());
+
+        // 2^63 and 2^64 are the first doubles past `i64::MAX` and `u64::MAX`.
+        assert!(i64::from_lox(number(9223372036854775808.0)).is_err());
+        assert_eq!(i64::from_lox(number(-9223372036854775808.0)), Ok(i64::MIN));
+        assert_eq!(
+            i64::from_lox(number(9223372036854774784.0)),
+            Ok(9223372036854774784)
+        );
+        assert!(u64::from_lox(number(18446744073709551616.0)).is_err());
+        assert_eq!(
+            u64::from_lox(number(18446744073709549568.0)),
+            Ok(18446744073709549568)
+        );
+        assert!(usize::from_lox(number(f64::INFINITY)).is_err());
+        assert!(isize::from_lox(number(f64::NAN)).is_err());
+    }
+}
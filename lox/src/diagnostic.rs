@@ -0,0 +1,438 @@
+//! Error reports shared by both interpreters, rendered with the offending
+//! source line and a caret under the problem.
+
+use std::fmt::Display;
+use std::fmt::Write as _;
+use std::io::IsTerminal;
+use std::str::FromStr;
+
+pub mod codes;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    fn label(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+
+    fn style(self) -> Style {
+        match self {
+            Severity::Error => Style::Red,
+            Severity::Warning => Style::Yellow,
+        }
+    }
+}
+
+/// How diagnostics are printed, chosen with `--error-format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// Rendered source snippets for people to read.
+    Human,
+    /// One JSON object per line, for editors and CI.
+    Json,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(ErrorFormat::Human),
+            "json" => Ok(ErrorFormat::Json),
+            _ => Err(format!("unknown error format '{}'", s)),
+        }
+    }
+}
+
+/// Where in the source a diagnostic points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    /// A range of byte offsets, from `start` up to but not including `end`.
+    Span { start: usize, end: usize },
+    /// A whole 1-based line, for when nothing more precise is known.
+    Line(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<&'static str>,
+    pub message: String,
+    pub location: Option<Location>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            code: None,
+            message: message.into(),
+            location: None,
+            notes: vec![],
+            help: None,
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn with_span(mut self, start: usize, end: usize) -> Self {
+        self.location = Some(Location::Span { start, end });
+        self
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.location = Some(Location::Line(line));
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Prints the diagnostic to stderr in the given `format`. Human-readable
+    /// output is coloured if stdout is a terminal.
+    pub fn emit(&self, format: ErrorFormat, name: &str, source: Option<&str>) {
+        match format {
+            ErrorFormat::Human => {
+                let color = std::io::stdout().is_terminal();
+                eprint!("{}", self.render(name, source, color));
+            }
+            ErrorFormat::Json => eprintln!("{}", self.to_json(name, source)),
+        }
+    }
+
+    /// Serializes the diagnostic as a single-line JSON object. Lines and
+    /// columns are 1-based, and columns count characters; the end is
+    /// exclusive. Columns are `null` when only the line is known.
+    pub fn to_json(&self, name: &str, source: Option<&str>) -> String {
+        let source = source.unwrap_or("");
+        let (start, end) = match self.location {
+            Some(Location::Span { start, end }) => (
+                Some(position(source, start)),
+                Some(position(source, end.max(start))),
+            ),
+            Some(Location::Line(line)) => (Some((line, None)), Some((line, None))),
+            None => (None, None),
+        };
+        let json_position = |position: Option<(usize, Option<usize>)>| match position {
+            Some((line, column)) => format!(
+                "{{\"line\":{},\"column\":{}}}",
+                line,
+                column.map_or(String::from("null"), |column| column.to_string())
+            ),
+            None => String::from("null"),
+        };
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| JsonString(note).to_string())
+            .collect::<Vec<_>>();
+
+        format!(
+            "{{\"severity\":\"{}\",\"code\":{},\"message\":{},\"file\":{},\"start\":{},\"end\":{},\"notes\":[{}],\"help\":{}}}",
+            self.severity.label(),
+            self.code.map_or(String::from("null"), |code| JsonString(code).to_string()),
+            JsonString(&self.message),
+            JsonString(name),
+            json_position(start),
+            json_position(end),
+            notes.join(","),
+            self.help
+                .as_deref()
+                .map_or(String::from("null"), |help| JsonString(help).to_string()),
+        )
+    }
+
+    /// Renders the diagnostic for the file `name`. The offending line is only
+    /// shown if its `source` is available.
+    pub fn render(&self, name: &str, source: Option<&str>, color: bool) -> String {
+        let paint = |style, text: &str| paint(style, text, color);
+        let mut out = String::new();
+
+        let severity = match self.code {
+            Some(code) => format!("{}[{}]", self.severity.label(), code),
+            None => self.severity.label().to_string(),
+        };
+        let _ = writeln!(
+            out,
+            "{}{}",
+            paint(self.severity.style(), &severity),
+            paint(Style::Bold, &format!(": {}", self.message)),
+        );
+
+        let snippet = self
+            .location
+            .map(|location| Snippet::new(location, source.unwrap_or("")));
+        let gutter = snippet
+            .as_ref()
+            .map_or(0, |snippet| snippet.line.to_string().len());
+        let pad = " ".repeat(gutter);
+
+        if let Some(snippet) = &snippet {
+            let position = match snippet.column {
+                Some(column) => format!("{}:{}:{}", name, snippet.line, column),
+                None => format!("{}:{}", name, snippet.line),
+            };
+            let _ = writeln!(out, "{}{} {}", pad, paint(Style::Blue, "-->"), position);
+        }
+
+        if let Some(snippet) = snippet.filter(|_| source.is_some()) {
+            let bar = paint(Style::Blue, "|");
+            let _ = writeln!(out, "{} {}", pad, bar);
+            let _ = writeln!(
+                out,
+                "{} {} {}",
+                paint(Style::Blue, &snippet.line.to_string()),
+                bar,
+                snippet.text
+            );
+            let carets = "^".repeat(snippet.width);
+            let _ = writeln!(
+                out,
+                "{} {} {}{}",
+                pad,
+                bar,
+                snippet.indent,
+                paint(self.severity.style(), &carets)
+            );
+        }
+
+        for note in &self.notes {
+            let _ = writeln!(
+                out,
+                "{} {} {}: {}",
+                pad,
+                paint(Style::Blue, "="),
+                paint(Style::Bold, "note"),
+                note
+            );
+        }
+        if let Some(help) = &self.help {
+            let _ = writeln!(
+                out,
+                "{} {} {}: {}",
+                pad,
+                paint(Style::Blue, "="),
+                paint(Style::Bold, "help"),
+                help
+            );
+        }
+
+        out
+    }
+}
+
+/// The source line a diagnostic points at, and where to put the carets.
+struct Snippet<'source> {
+    line: usize,
+    /// The 1-based column of the first caret, if the location is a span.
+    column: Option<usize>,
+    text: &'source str,
+    /// Whitespace that lines the carets up under `text`.
+    indent: String,
+    width: usize,
+}
+
+impl<'source> Snippet<'source> {
+    fn new(location: Location, source: &'source str) -> Self {
+        match location {
+            Location::Span { start, end } => {
+                let start = floor_char_boundary(source, start);
+                let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+                let line_end = source[start..]
+                    .find('\n')
+                    .map_or(source.len(), |i| start + i);
+                let end = floor_char_boundary(source, end).clamp(start, line_end);
+
+                let before = &source[line_start..start];
+                Self {
+                    line: source[..start].matches('\n').count() + 1,
+                    column: Some(before.chars().count() + 1),
+                    text: source[line_start..line_end].trim_end_matches('\r'),
+                    indent: indent(before),
+                    width: source[start..end].chars().count().max(1),
+                }
+            }
+            Location::Line(line) => {
+                let text = source
+                    .split('\n')
+                    .nth(line.wrapping_sub(1))
+                    .unwrap_or("")
+                    .trim_end_matches('\r');
+                let content = text.trim();
+                let before = &text[..text.len() - text.trim_start().len()];
+                Self {
+                    line,
+                    column: None,
+                    text,
+                    indent: indent(before),
+                    width: content.chars().count().max(1),
+                }
+            }
+        }
+    }
+}
+
+/// The 1-based line and character column of `offset` in `source`.
+fn position(source: &str, offset: usize) -> (usize, Option<usize>) {
+    let offset = floor_char_boundary(source, offset);
+    let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line = source[..offset].matches('\n').count() + 1;
+    (line, Some(source[line_start..offset].chars().count() + 1))
+}
+
+/// Writes a string as a quoted JSON string.
+struct JsonString<'a>(&'a str);
+
+impl Display for JsonString<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char('"')?;
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// Blanks out `text`, keeping its tabs so that whatever follows it lines up.
+fn indent(text: &str) -> String {
+    text.chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect()
+}
+
+fn floor_char_boundary(source: &str, offset: usize) -> usize {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Style {
+    Bold,
+    Red,
+    Yellow,
+    Blue,
+}
+
+fn paint(style: Style, text: &str, color: bool) -> String {
+    if !color {
+        return text.to_string();
+    }
+    let code = match style {
+        Style::Bold => "1",
+        Style::Red => "1;31",
+        Style::Yellow => "1;33",
+        Style::Blue => "1;34",
+    };
+    format!("\x1b[{}m{}\x1b[0m", code, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_span_with_carets() {
+        let source = "var a = 1;\nprint a +\t\"é\" - b;\n";
+        let diagnostic = Diagnostic::error("Operands must be numbers.")
+            .with_code(codes::OPERAND_TYPE)
+            .with_span(28, 29)
+            .with_note("The left operand is a string.");
+        assert_eq!(
+            diagnostic.render("test.lox", Some(source), false),
+            "error[E0103]: Operands must be numbers.\n \
+             --> test.lox:2:17\n  \
+              |\n\
+             2 | print a +\t\"é\" - b;\n  \
+              |          \t      ^\n  \
+              = note: The left operand is a string.\n"
+        );
+    }
+
+    #[test]
+    fn renders_whole_lines() {
+        let diagnostic = Diagnostic::error("Stack overflow.")
+            .with_line(2)
+            .with_help("Check for unbounded recursion.");
+        assert_eq!(
+            diagnostic.render("test.lox", Some("fun f() {\n  f();\n}"), false),
+            "error: Stack overflow.\n \
+             --> test.lox:2\n  \
+              |\n\
+             2 |   f();\n  \
+              |   ^^^^\n  \
+              = help: Check for unbounded recursion.\n"
+        );
+        assert_eq!(
+            diagnostic.render("test.loxc", None, false),
+            "error: Stack overflow.\n \
+             --> test.loxc:2\n  \
+              = help: Check for unbounded recursion.\n"
+        );
+    }
+
+    #[test]
+    fn serializes_to_json() {
+        let diagnostic = Diagnostic::error("Unterminated string.")
+            .with_code(codes::UNTERMINATED_STRING)
+            .with_span(8, 14)
+            .with_note("Strings can't span \"files\".");
+        assert_eq!(
+            diagnostic.to_json("dir\\test.lox", Some("var s =\n\"é\nabc")),
+            "{\"severity\":\"error\",\"code\":\"E0002\",\"message\":\"Unterminated string.\",\
+             \"file\":\"dir\\\\test.lox\",\"start\":{\"line\":2,\"column\":1},\
+             \"end\":{\"line\":3,\"column\":3},\"notes\":[\"Strings can't span \\\"files\\\".\"],\
+             \"help\":null}"
+        );
+        assert_eq!(
+            Diagnostic::error("Stack overflow.")
+                .with_line(4)
+                .to_json("a.loxc", None),
+            "{\"severity\":\"error\",\"code\":null,\"message\":\"Stack overflow.\",\
+             \"file\":\"a.loxc\",\"start\":{\"line\":4,\"column\":null},\
+             \"end\":{\"line\":4,\"column\":null},\"notes\":[],\"help\":null}"
+        );
+    }
+
+    #[test]
+    fn colours_only_when_asked() {
+        let diagnostic = Diagnostic::error("Unexpected character.");
+        assert_eq!(
+            diagnostic.render("test.lox", None, true),
+            "\x1b[1;31merror\x1b[0m\x1b[1m: Unexpected character.\x1b[0m\n"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! Error codes, shared by both interpreters so that the same mistake is
+//! reported the same way whichever one runs it. Codes below `E0100` are
+//! found before the program runs, the rest while it runs.
+
+pub const UNEXPECTED_CHARACTER: &str = "E0001";
+pub const UNTERMINATED_STRING: &str = "E0002";
+/// The tokens don't form a valid program.
+pub const SYNTAX: &str = "E0003";
+/// Too many parameters, arguments, locals or constants, or too much code to
+/// jump over.
+pub const LIMIT_EXCEEDED: &str = "E0004";
+pub const ALREADY_DECLARED: &str = "E0005";
+pub const READ_IN_OWN_INITIALIZER: &str = "E0006";
+/// A `return` at the top level, or returning a value from an initializer.
+pub const INVALID_RETURN: &str = "E0007";
+pub const INHERIT_FROM_SELF: &str = "E0008";
+/// `this` or `super` where there is no such object.
+pub const INVALID_RECEIVER: &str = "E0009";
+/// An operation that the type checker can prove will fail.
+pub const TYPE_MISMATCH: &str = "E0010";
+/// A compiled file that is corrupt or was written by another version.
+pub const INVALID_COMPILED_FILE: &str = "E0011";
+
+pub const UNDEFINED_VARIABLE: &str = "E0101";
+pub const UNDEFINED_PROPERTY: &str = "E0102";
+/// An operator applied to values of the wrong type.
+pub const OPERAND_TYPE: &str = "E0103";
+pub const NOT_CALLABLE: &str = "E0104";
+pub const ARITY_MISMATCH: &str = "E0105";
+pub const STACK_OVERFLOW: &str = "E0106";
+/// A property read or written on something other than an instance.
+pub const NOT_AN_INSTANCE: &str = "E0107";
+pub const SUPERCLASS_NOT_CLASS: &str = "E0108";
+/// A native function given an argument of the wrong type.
+pub const ARGUMENT_TYPE: &str = "E0109";
+/// Any other failure reported by a native function.
+pub const NATIVE: &str = "E0110";
+pub const INDEX_OUT_OF_BOUNDS: &str = "E0111";
+/// An index applied to something other than a list.
+pub const NOT_A_LIST: &str = "E0112";
+/// Methods added to something other than a class.
+pub const NOT_A_CLASS: &str = "E0113";
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::diagnostic::codes;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+use crate::token::Token;
+
+#[derive(Debug, Default)]
+pub struct Environment {
+    values: HashMap<String, Option<Object>>,
+    enclosing: Option<Arc<RwLock<Environment>>>,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_enclosing(enclosing: Arc<RwLock<Environment>>) -> Self {
+        Self {
+            values: HashMap::new(),
+            enclosing: Some(enclosing),
+        }
+    }
+
+    pub fn define(&mut self, name: String, value: Option<Object>) {
+        self.values.insert(name, value);
+    }
+
+    pub fn get(&self, name: &Token) -> Result<Option<Object>, RuntimeError> {
+        if let Some(value) = self.values.get(&name.lexeme) {
+            return Ok(value.clone());
+        }
+
+        match &self.enclosing {
+            Some(enclosing) => enclosing.read().get(name),
+            None => Err(undefined_variable(name)),
+        }
+    }
+
+    pub fn assign(&mut self, name: &Token, value: Option<Object>) -> Result<(), RuntimeError> {
+        if let Some(slot) = self.values.get_mut(&name.lexeme) {
+            *slot = value;
+            return Ok(());
+        }
+
+        match &self.enclosing {
+            Some(enclosing) => enclosing.write().assign(name, value),
+            None => Err(undefined_variable(name)),
+        }
+    }
+
+    pub fn get_at(&self, distance: usize, name: &str) -> Option<Object> {
+        if distance == 0 {
+            return self.values.get(name).cloned().flatten();
+        }
+
+        self.enclosing
+            .as_ref()
+            .expect("resolved scope depth exceeds environment chain")
+            .read()
+            .get_at(distance - 1, name)
+    }
+
+    pub fn assign_at(
+        &mut self,
+        distance: usize,
+        name: &Token,
+        value: Option<Object>,
+    ) -> Result<(), RuntimeError> {
+        if distance == 0 {
+            self.values.insert(name.lexeme.clone(), value);
+            return Ok(());
+        }
+
+        self.enclosing
+            .as_ref()
+            .expect("resolved scope depth exceeds environment chain")
+            .write()
+            .assign_at(distance - 1, name, value)
+    }
+}
+
+fn undefined_variable(name: &Token) -> RuntimeError {
+    RuntimeError::new(
+        name.clone(),
+        codes::UNDEFINED_VARIABLE,
+        format!("Undefined variable '{}'.", name.lexeme),
+    )
+}
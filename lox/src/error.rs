@@ -0,0 +1,67 @@
+use std::fmt::Display;
+use std::io;
+
+use crate::diagnostic::Diagnostic;
+
+/// Why a call into the [`Interpreter`](crate::Interpreter) failed.
+#[derive(Debug)]
+pub enum Error {
+    /// The script couldn't be read.
+    Io(io::Error),
+    /// The code was rejected before any of it ran.
+    Compile(Vec<Diagnostic>),
+    /// The code stopped partway through running.
+    Runtime(Diagnostic),
+}
+
+impl Error {
+    /// The problems with the code itself, if that's what went wrong. Their
+    /// spans point into [`Interpreter::source`](crate::Interpreter::source).
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        match self {
+            Self::Io(_) => &[],
+            Self::Compile(diagnostics) => diagnostics,
+            Self::Runtime(diagnostic) => std::slice::from_ref(diagnostic),
+        }
+    }
+
+    /// The conventional exit status for a script that failed this way.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Io(_) => 1,
+            Self::Compile(_) => 65,
+            Self::Runtime(_) => 70,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            _ => {
+                let messages = self
+                    .diagnostics()
+                    .iter()
+                    .map(|diagnostic| diagnostic.message.as_str())
+                    .collect::<Vec<_>>();
+                write!(f, "{}", messages.join("\n"))
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
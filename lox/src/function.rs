@@ -0,0 +1,96 @@
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::ast::FunctionStmt;
+use crate::ast::Stmt;
+use crate::callable::Arity;
+use crate::callable::LoxCallable;
+use crate::environment::Environment;
+use crate::instance::LoxInstance;
+use crate::interpreter::Interpreter;
+use crate::interpreter::Unwind;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+use crate::token::Token;
+
+pub struct LoxFunction {
+    name: Token,
+    params: Vec<Token>,
+    body: Arc<Vec<Stmt>>,
+    closure: Arc<RwLock<Environment>>,
+    is_initializer: bool,
+}
+
+impl LoxFunction {
+    pub fn new(
+        declaration: &FunctionStmt,
+        closure: Arc<RwLock<Environment>>,
+        is_initializer: bool,
+    ) -> Self {
+        Self {
+            name: declaration.name.clone(),
+            params: declaration.params.clone(),
+            body: declaration.body.clone(),
+            closure,
+            is_initializer,
+        }
+    }
+
+    /// Returns a copy of this method whose closure binds `this` to `instance`.
+    pub fn bind(&self, instance: Arc<RwLock<LoxInstance>>) -> Self {
+        let mut environment = Environment::with_enclosing(self.closure.clone());
+        environment.define(String::from("this"), Some(Object::Instance(instance)));
+        Self {
+            name: self.name.clone(),
+            params: self.params.clone(),
+            body: self.body.clone(),
+            closure: Arc::new(RwLock::new(environment)),
+            is_initializer: self.is_initializer,
+        }
+    }
+}
+
+impl LoxCallable for LoxFunction {
+    fn arity(&self) -> Arity {
+        Arity::Exactly(self.params.len())
+    }
+
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<Option<Object>>,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let mut environment = Environment::with_enclosing(self.closure.clone());
+        for (param, argument) in self.params.iter().zip(arguments) {
+            environment.define(param.lexeme.clone(), argument);
+        }
+
+        let value = match interpreter.execute_block(&self.body, Arc::new(RwLock::new(environment)))
+        {
+            Ok(()) => None,
+            Err(Unwind::Return(value)) => value,
+            Err(Unwind::Error(e)) => return Err(e),
+        };
+
+        if self.is_initializer {
+            return Ok(self.closure.read().get_at(0, "this"));
+        }
+
+        Ok(value)
+    }
+}
+
+impl Display for LoxFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<fn {}>", self.name.lexeme)
+    }
+}
+
+impl Debug for LoxFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::class::LoxClass;
+use crate::diagnostic::codes;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+use crate::token::Token;
+
+pub struct LoxInstance {
+    class: Arc<LoxClass>,
+    fields: HashMap<String, Option<Object>>,
+}
+
+impl LoxInstance {
+    pub fn new(class: Arc<LoxClass>) -> Self {
+        Self {
+            class,
+            fields: HashMap::new(),
+        }
+    }
+
+    /// Looks up a field, falling back to a method bound to `this`.
+    pub fn get(
+        this: &Arc<RwLock<LoxInstance>>,
+        name: &Token,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let instance = this.read();
+        if let Some(value) = instance.fields.get(&name.lexeme) {
+            return Ok(value.clone());
+        }
+
+        if let Some(method) = instance.class.find_method(&name.lexeme) {
+            return Ok(Some(Object::Function(Arc::new(method.bind(this.clone())))));
+        }
+
+        Err(RuntimeError::new(
+            name.clone(),
+            codes::UNDEFINED_PROPERTY,
+            format!("Undefined property '{}'.", name.lexeme),
+        ))
+    }
+
+    pub fn set(&mut self, name: &Token, value: Option<Object>) {
+        self.set_field(&name.lexeme, value);
+    }
+
+    /// The value of a field, or `None` if it's `nil` or was never set.
+    pub fn field(&self, name: &str) -> Option<Object> {
+        self.fields.get(name).cloned().flatten()
+    }
+
+    pub fn set_field(&mut self, name: &str, value: Option<Object>) {
+        self.fields.insert(name.to_string(), value);
+    }
+}
+
+impl Display for LoxInstance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} instance", self.class.name)
+    }
+}
+
+impl Debug for LoxInstance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
@@ -0,0 +1,919 @@
+use std::collections::HashMap;
+use std::fs;
+use std::panic;
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+
+use parking_lot::RwLock;
+
+use crate::ast::AcceptExprVisitor;
+use crate::ast::AcceptStmtVisitor;
+use crate::ast::ExprVisitor;
+use crate::ast::Stmt;
+use crate::ast::StmtVisitor;
+use crate::callable::Arity;
+use crate::callable::LoxCallable;
+use crate::class::LoxClass;
+use crate::diagnostic::codes;
+use crate::environment::Environment;
+use crate::error::Error;
+use crate::function::LoxFunction;
+use crate::instance::LoxInstance;
+use crate::list;
+use crate::list::List;
+use crate::native::NativeFunction;
+use crate::object::IsTruthy;
+use crate::object::Object;
+use crate::parser::Parser;
+use crate::reporter::Reporter;
+use crate::resolver::Resolver;
+use crate::runtime_error::RuntimeError;
+use crate::scanner::Scanner;
+use crate::stdlib;
+use crate::stdlib::Capabilities;
+use crate::token::Token;
+use crate::token_type::TokenType;
+
+/// How deeply Lox calls may nest before the interpreter reports a stack
+/// overflow.
+const MAX_CALL_DEPTH: usize = 1024;
+
+/// Lox calls recurse on the native stack, so programs run on a thread with
+/// room for [`MAX_CALL_DEPTH`] of them whatever stack the caller has.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Non-local exits out of statement execution.
+pub enum Unwind {
+    Error(RuntimeError),
+    Return(Option<Object>),
+}
+
+impl From<RuntimeError> for Unwind {
+    fn from(e: RuntimeError) -> Self {
+        Self::Error(e)
+    }
+}
+
+/// A running Lox program, which code can be fed into a piece at a time.
+///
+/// ```
+/// let mut lox = lox::Interpreter::new();
+/// lox.eval("var answer = 6 * 7;").unwrap();
+/// assert_eq!(lox.get_global("answer"), Some(lox::Object::Number(42.0)));
+/// ```
+pub struct Interpreter {
+    globals: Arc<RwLock<Environment>>,
+    environment: Arc<RwLock<Environment>>,
+    locals: HashMap<usize, usize>,
+    source: String,
+    /// How many Lox calls are currently running.
+    call_depth: usize,
+}
+
+impl Interpreter {
+    /// An interpreter with the whole [standard library](crate::stdlib)
+    /// installed.
+    pub fn new() -> Self {
+        Self::with_capabilities(Capabilities::ALL)
+    }
+
+    /// An interpreter with only the parts of the standard library that
+    /// `capabilities` allows, such as one that can't touch the filesystem.
+    pub fn with_capabilities(capabilities: Capabilities) -> Self {
+        let mut interpreter = Self::empty();
+        stdlib::install(&mut interpreter, capabilities);
+        interpreter
+    }
+
+    /// An interpreter with no globals at all, for running code that should
+    /// only reach what's been given to it with [`Self::define_native`] and
+    /// [`Self::set_global`].
+    pub fn empty() -> Self {
+        let globals = Arc::new(RwLock::new(Environment::new()));
+        Self {
+            environment: globals.clone(),
+            globals,
+            locals: HashMap::new(),
+            source: String::new(),
+            call_depth: 0,
+        }
+    }
+
+    /// Runs `source` after everything evaluated so far, so it can use the
+    /// functions, classes and globals declared earlier.
+    pub fn eval(&mut self, source: &str) -> Result<(), Error> {
+        let start = self.source.len();
+        self.source.push_str(source);
+        if !self.source.ends_with('\n') {
+            self.source.push('\n');
+        }
+
+        let mut reporter = Reporter::new();
+        let tokens = Scanner::starting_at(&self.source, start, &mut reporter).scan_tokens();
+        let statements = Parser::new(tokens, &mut reporter).parse();
+        let mut resolved = vec![];
+        if !reporter.had_error() {
+            let mut resolver = Resolver::new(self, &mut reporter);
+            resolver.resolve(&statements);
+            resolved = resolver.into_resolved();
+        }
+        let result = if reporter.had_error() {
+            Err(Error::Compile(reporter.take_diagnostics()))
+        } else {
+            self.interpret(&statements)
+                .map_err(|e| Error::Runtime(e.diagnostic()))
+        };
+
+        // Code outside functions never runs again, so its resolutions can go.
+        // Function bodies keep theirs, unless the program didn't run at all.
+        let ran = !matches!(result, Err(Error::Compile(_)));
+        for (id, in_function) in resolved {
+            if !(ran && in_function) {
+                self.locals.remove(&id);
+            }
+        }
+        result
+    }
+
+    /// Reads the script at `path` and [evaluates](Self::eval) it.
+    pub fn run_file(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let source = fs::read_to_string(path)?;
+        self.eval(&source)
+    }
+
+    /// Makes the Rust `function` callable from Lox as the global `name`.
+    ///
+    /// Calls with the wrong number of arguments are rejected before
+    /// `function` sees them, and any error it returns is reported at the
+    /// call site.
+    ///
+    /// ```
+    /// use lox::callable::Arity;
+    /// use lox::Object;
+    ///
+    /// let mut lox = lox::Interpreter::new();
+    /// lox.define_native("sum", Arity::AtLeast(0), |_, arguments| {
+    ///     let mut total = 0.0;
+    ///     for (i, argument) in arguments.iter().enumerate() {
+    ///         match argument {
+    ///             Some(Object::Number(n)) => total += n,
+    ///             _ => return Err(lox::RuntimeError::argument_type(i, "a number")),
+    ///         }
+    ///     }
+    ///     Ok(Some(Object::Number(total)))
+    /// });
+    /// lox.eval("var total = sum(1, 2, 3);").unwrap();
+    /// assert_eq!(lox.get_global("total"), Some(Object::Number(6.0)));
+    /// ```
+    pub fn define_native<F>(&mut self, name: &str, arity: impl Into<Arity>, function: F)
+    where
+        F: Fn(&mut Interpreter, &[Option<Object>]) -> Result<Option<Object>, RuntimeError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let native = NativeFunction::new(name, arity.into(), function);
+        self.set_global(name, Some(Object::Native(Arc::new(native))));
+    }
+
+    /// Everything evaluated so far, which the spans of any errors point into.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The value of the global variable `name`, or `None` if it's `nil` or
+    /// hasn't been declared.
+    pub fn get_global(&self, name: &str) -> Option<Object> {
+        self.globals.read().get_at(0, name)
+    }
+
+    /// Declares the global variable `name`, or changes its value if it
+    /// already exists. `None` stands for `nil`.
+    pub fn set_global(&mut self, name: &str, value: Option<Object>) {
+        self.globals.write().define(name.to_string(), value);
+    }
+
+    pub fn interpret(&mut self, statements: &[Stmt]) -> Result<(), RuntimeError> {
+        thread::scope(|scope| {
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, || self.execute_all(statements))
+                .expect("failed to start the interpreter thread")
+                .join()
+                .unwrap_or_else(|payload| panic::resume_unwind(payload))
+        })
+    }
+
+    fn execute_all(&mut self, statements: &[Stmt]) -> Result<(), RuntimeError> {
+        for statement in statements {
+            match self.execute(statement) {
+                Ok(()) => {}
+                Err(Unwind::Error(e)) => return Err(e),
+                Err(Unwind::Return(_)) => return Ok(()),
+            }
+        }
+        Ok(())
+    }
+    fn execute(&mut self, stmt: &Stmt) -> Result<(), Unwind> {
+        stmt.accept(self)
+    }
+    pub fn execute_block(
+        &mut self,
+        statements: &[Stmt],
+        environment: Arc<RwLock<Environment>>,
+    ) -> Result<(), Unwind> {
+        let previous = std::mem::replace(&mut self.environment, environment);
+        let result = statements
+            .iter()
+            .try_for_each(|statement| self.execute(statement));
+        self.environment = previous;
+        result
+    }
+    /// Records how many scopes separate the expression `id` from the
+    /// variable it refers to.
+    pub fn resolve(&mut self, id: usize, depth: usize) {
+        self.locals.insert(id, depth);
+    }
+    fn evaluate(&mut self, expr: &crate::ast::Expr) -> Result<Option<Object>, RuntimeError> {
+        expr.accept(self)
+    }
+    /// Evaluates the list being indexed, blaming `bracket` if it isn't one.
+    fn list(&mut self, expr: &crate::ast::Expr, bracket: &Token) -> Result<List, RuntimeError> {
+        match self.evaluate(expr)? {
+            Some(Object::List(list)) => Ok(list),
+            _ => Err(RuntimeError::new(
+                bracket.clone(),
+                codes::NOT_A_LIST,
+                String::from("Only lists can be indexed."),
+            )),
+        }
+    }
+    fn look_up_variable(&self, id: usize, name: &Token) -> Result<Option<Object>, RuntimeError> {
+        match self.locals.get(&id) {
+            Some(&distance) => Ok(self.environment.read().get_at(distance, &name.lexeme)),
+            None => self.globals.read().get(name),
+        }
+    }
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StmtVisitor<Result<(), Unwind>> for Interpreter {
+    fn visit_block_stmt(&mut self, stmt: &crate::ast::BlockStmt) -> Result<(), Unwind> {
+        let environment = Environment::with_enclosing(self.environment.clone());
+        self.execute_block(&stmt.statements, Arc::new(RwLock::new(environment)))
+    }
+
+    fn visit_class_stmt(&mut self, stmt: &crate::ast::ClassStmt) -> Result<(), Unwind> {
+        let superclass = match &stmt.superclass {
+            Some(superclass) => match self.visit_variable_expr(superclass)? {
+                Some(Object::Class(class)) => Some(class),
+                _ => {
+                    return Err(RuntimeError::new(
+                        superclass.name.clone(),
+                        codes::SUPERCLASS_NOT_CLASS,
+                        String::from("Superclass must be a class."),
+                    )
+                    .into())
+                }
+            },
+            None => None,
+        };
+
+        self.environment
+            .write()
+            .define(stmt.name.lexeme.clone(), None);
+
+        let enclosing = self.environment.clone();
+        if let Some(superclass) = &superclass {
+            let mut environment = Environment::with_enclosing(enclosing.clone());
+            environment.define(
+                String::from("super"),
+                Some(Object::Class(superclass.clone())),
+            );
+            self.environment = Arc::new(RwLock::new(environment));
+        }
+
+        let methods = stmt
+            .methods
+            .iter()
+            .map(|method| {
+                let function = LoxFunction::new(
+                    method,
+                    self.environment.clone(),
+                    method.name.lexeme == "init",
+                );
+                (method.name.lexeme.clone(), Arc::new(function))
+            })
+            .collect();
+
+        let class = LoxClass::new(stmt.name.lexeme.clone(), superclass, methods);
+        self.environment = enclosing;
+        self.environment
+            .write()
+            .assign(&stmt.name, Some(Object::Class(Arc::new(class))))?;
+        Ok(())
+    }
+
+    fn visit_expression_stmt(&mut self, stmt: &crate::ast::ExpressionStmt) -> Result<(), Unwind> {
+        self.evaluate(&stmt.expression)?;
+        Ok(())
+    }
+
+    fn visit_function_stmt(&mut self, stmt: &crate::ast::FunctionStmt) -> Result<(), Unwind> {
+        let function = LoxFunction::new(stmt, self.environment.clone(), false);
+        self.environment.write().define(
+            stmt.name.lexeme.clone(),
+            Some(Object::Function(Arc::new(function))),
+        );
+        Ok(())
+    }
+
+    fn visit_if_stmt(&mut self, stmt: &crate::ast::IfStmt) -> Result<(), Unwind> {
+        if self.evaluate(&stmt.condition)?.is_truthy() {
+            self.execute(&stmt.then_branch)
+        } else if let Some(else_branch) = &stmt.else_branch {
+            self.execute(else_branch)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn visit_print_stmt(&mut self, stmt: &crate::ast::PrintStmt) -> Result<(), Unwind> {
+        let value = self.evaluate(&stmt.expression)?;
+        println!("{}", stringify(&value));
+        Ok(())
+    }
+
+    fn visit_return_stmt(&mut self, stmt: &crate::ast::ReturnStmt) -> Result<(), Unwind> {
+        let value = match &stmt.value {
+            Some(value) => self.evaluate(value)?,
+            None => None,
+        };
+
+        Err(Unwind::Return(value))
+    }
+
+    fn visit_var_stmt(&mut self, stmt: &crate::ast::VarStmt) -> Result<(), Unwind> {
+        let value = match &stmt.initializer {
+            Some(initializer) => self.evaluate(initializer)?,
+            None => None,
+        };
+
+        self.environment
+            .write()
+            .define(stmt.name.lexeme.clone(), value);
+        Ok(())
+    }
+
+    fn visit_while_stmt(&mut self, stmt: &crate::ast::WhileStmt) -> Result<(), Unwind> {
+        while self.evaluate(&stmt.condition)?.is_truthy() {
+            self.execute(&stmt.body)?;
+        }
+        Ok(())
+    }
+}
+
+impl ExprVisitor<Result<Option<Object>, RuntimeError>> for Interpreter {
+    fn visit_assign_expr(
+        &mut self,
+        expr: &crate::ast::AssignExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let value = self.evaluate(&expr.value)?;
+        match self.locals.get(&expr.id) {
+            Some(&distance) => {
+                self.environment
+                    .write()
+                    .assign_at(distance, &expr.name, value.clone())?
+            }
+            None => self.globals.write().assign(&expr.name, value.clone())?,
+        }
+        Ok(value)
+    }
+
+    fn visit_binary_expr(
+        &mut self,
+        expr: &crate::ast::BinaryExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let left = self.evaluate(&expr.left)?;
+        let right = self.evaluate(&expr.right)?;
+
+        match expr.operator.kind {
+            TokenType::BangEqual => Ok(Some(Object::Boolean(left != right))),
+            TokenType::EqualEqual => Ok(Some(Object::Boolean(left == right))),
+            TokenType::Greater => {
+                let (left, right) = check_number_operands(expr.operator.clone(), left, right)?;
+                Ok(Some(Object::Boolean(left > right)))
+            }
+            TokenType::GreaterEqual => {
+                let (left, right) = check_number_operands(expr.operator.clone(), left, right)?;
+                Ok(Some(Object::Boolean(left >= right)))
+            }
+            TokenType::Less => {
+                let (left, right) = check_number_operands(expr.operator.clone(), left, right)?;
+                Ok(Some(Object::Boolean(left < right)))
+            }
+            TokenType::LessEqual => {
+                let (left, right) = check_number_operands(expr.operator.clone(), left, right)?;
+                Ok(Some(Object::Boolean(left <= right)))
+            }
+            TokenType::Minus => {
+                let (left, right) = check_number_operands(expr.operator.clone(), left, right)?;
+                Ok(Some(Object::Number(left - right)))
+            }
+            TokenType::Slash => {
+                let (left, right) = check_number_operands(expr.operator.clone(), left, right)?;
+                Ok(Some(Object::Number(left / right)))
+            }
+            TokenType::Star => {
+                let (left, right) = check_number_operands(expr.operator.clone(), left, right)?;
+                Ok(Some(Object::Number(left * right)))
+            }
+            TokenType::Plus => {
+                match left {
+                    Some(Object::Number(n)) => {
+                        if let Some(Object::Number(m)) = right {
+                            return Ok(Some(Object::Number(n + m)));
+                        }
+                    }
+                    Some(Object::String(s)) => {
+                        if let Some(Object::String(t)) = right {
+                            return Ok(Some(Object::String(s + &t)));
+                        }
+                    }
+                    _ => {}
+                }
+                Err(RuntimeError::new(
+                    expr.operator.clone(),
+                    codes::OPERAND_TYPE,
+                    String::from("Operands must be two numbers or two strings."),
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn visit_call_expr(
+        &mut self,
+        expr: &crate::ast::CallExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let callee = self.evaluate(&expr.callee)?;
+
+        let arguments = expr
+            .arguments
+            .iter()
+            .map(|argument| self.evaluate(argument))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let function: &dyn LoxCallable = match &callee {
+            Some(Object::Function(function)) => function.as_ref(),
+            Some(Object::Class(class)) => class,
+            Some(Object::Native(native)) => native.as_ref(),
+            _ => {
+                return Err(RuntimeError::new(
+                    expr.paren.clone(),
+                    codes::NOT_CALLABLE,
+                    String::from("Can only call functions and classes."),
+                ))
+            }
+        };
+
+        if !function.arity().accepts(arguments.len()) {
+            return Err(RuntimeError::new(
+                expr.paren.clone(),
+                codes::ARITY_MISMATCH,
+                format!(
+                    "Expected {} arguments but got {}.",
+                    function.arity(),
+                    arguments.len()
+                ),
+            ));
+        }
+
+        if self.call_depth == MAX_CALL_DEPTH {
+            return Err(RuntimeError::new(
+                expr.paren.clone(),
+                codes::STACK_OVERFLOW,
+                String::from("Stack overflow."),
+            ));
+        }
+
+        self.call_depth += 1;
+        let result = function.call(self, arguments);
+        self.call_depth -= 1;
+        result.map_err(|e| e.or_at(&expr.paren))
+    }
+
+    fn visit_get_expr(
+        &mut self,
+        expr: &crate::ast::GetExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        match self.evaluate(&expr.object)? {
+            Some(Object::Instance(instance)) => LoxInstance::get(&instance, &expr.name),
+            _ => Err(RuntimeError::new(
+                expr.name.clone(),
+                codes::NOT_AN_INSTANCE,
+                String::from("Only instances have properties."),
+            )),
+        }
+    }
+
+    fn visit_grouping_expr(
+        &mut self,
+        expr: &crate::ast::GroupingExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        self.evaluate(&expr.expression)
+    }
+
+    fn visit_index_expr(
+        &mut self,
+        expr: &crate::ast::IndexExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let list = self.list(&expr.object, &expr.bracket)?;
+        let index = self.evaluate(&expr.index)?;
+
+        let list = list.read();
+        let index = list::index(&index, list.len()).map_err(|e| e.or_at(&expr.bracket))?;
+        Ok(list[index].clone())
+    }
+
+    fn visit_list_expr(
+        &mut self,
+        expr: &crate::ast::ListExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let elements = expr
+            .elements
+            .iter()
+            .map(|element| self.evaluate(element))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(list::new(elements)))
+    }
+
+    fn visit_literal_expr(
+        &mut self,
+        expr: &crate::ast::LiteralExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        Ok(expr.value.clone())
+    }
+
+    fn visit_logical_expr(
+        &mut self,
+        expr: &crate::ast::LogicalExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let left = self.evaluate(&expr.left)?;
+
+        if expr.operator.kind == TokenType::Or {
+            if left.is_truthy() {
+                return Ok(left);
+            }
+        } else if !left.is_truthy() {
+            return Ok(left);
+        }
+
+        self.evaluate(&expr.right)
+    }
+
+    fn visit_set_expr(
+        &mut self,
+        expr: &crate::ast::SetExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let instance = match self.evaluate(&expr.object)? {
+            Some(Object::Instance(instance)) => instance,
+            _ => {
+                return Err(RuntimeError::new(
+                    expr.name.clone(),
+                    codes::NOT_AN_INSTANCE,
+                    String::from("Only instances have fields."),
+                ))
+            }
+        };
+
+        let value = self.evaluate(&expr.value)?;
+        instance.write().set(&expr.name, value.clone());
+        Ok(value)
+    }
+
+    fn visit_set_index_expr(
+        &mut self,
+        expr: &crate::ast::SetIndexExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let list = self.list(&expr.object, &expr.bracket)?;
+        let index = self.evaluate(&expr.index)?;
+        let value = self.evaluate(&expr.value)?;
+
+        let mut list = list.write();
+        let index = list::index(&index, list.len()).map_err(|e| e.or_at(&expr.bracket))?;
+        list[index] = value.clone();
+        Ok(value)
+    }
+
+    fn visit_super_expr(
+        &mut self,
+        expr: &crate::ast::SuperExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let distance = self.locals[&expr.id];
+        let superclass = match self.environment.read().get_at(distance, "super") {
+            Some(Object::Class(superclass)) => superclass,
+            _ => unreachable!(),
+        };
+        // "this" is always bound one scope inside the one holding "super".
+        let object = match self.environment.read().get_at(distance - 1, "this") {
+            Some(Object::Instance(object)) => object,
+            _ => unreachable!(),
+        };
+
+        match superclass.find_method(&expr.method.lexeme) {
+            Some(method) => Ok(Some(Object::Function(Arc::new(method.bind(object))))),
+            None => Err(RuntimeError::new(
+                expr.method.clone(),
+                codes::UNDEFINED_PROPERTY,
+                format!("Undefined property '{}'.", expr.method.lexeme),
+            )),
+        }
+    }
+
+    fn visit_this_expr(
+        &mut self,
+        expr: &crate::ast::ThisExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        self.look_up_variable(expr.id, &expr.keyword)
+    }
+
+    fn visit_unary_expr(
+        &mut self,
+        expr: &crate::ast::UnaryExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let right = self.evaluate(&expr.right)?;
+
+        match expr.operator.kind {
+            TokenType::Bang => Ok(Some(Object::Boolean(!right.is_truthy()))),
+            TokenType::Minus => {
+                let right = check_number_operand(expr.operator.clone(), right)?;
+                Ok(Some(Object::Number(-right)))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn visit_variable_expr(
+        &mut self,
+        expr: &crate::ast::VariableExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        self.look_up_variable(expr.id, &expr.name)
+    }
+}
+
+fn stringify(value: &Option<Object>) -> String {
+    match value {
+        None => String::from("nil"),
+        Some(value) => value.to_string(),
+    }
+}
+
+fn check_number_operand(operator: Token, operand: Option<Object>) -> Result<f64, RuntimeError> {
+    if let Some(Object::Number(n)) = operand {
+        Ok(n)
+    } else {
+        Err(RuntimeError::new(
+            operator,
+            codes::OPERAND_TYPE,
+            String::from("Operand must be a number."),
+        ))
+    }
+}
+
+fn check_number_operands(
+    operator: Token,
+    left: Option<Object>,
+    right: Option<Object>,
+) -> Result<(f64, f64), RuntimeError> {
+    if let Some(Object::Number(n)) = left {
+        if let Some(Object::Number(m)) = right {
+            return Ok((n, m));
+        }
+    }
+    Err(RuntimeError::new(
+        operator,
+        codes::OPERAND_TYPE,
+        String::from("Operands must be numbers."),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(source: &str) -> Interpreter {
+        let mut lox = Interpreter::empty();
+        lox.eval(source).unwrap();
+        lox
+    }
+
+    fn global(lox: &Interpreter, name: &str) -> String {
+        match lox.get_global(name) {
+            Some(value) => value.to_string(),
+            None => String::from("nil"),
+        }
+    }
+
+    #[test]
+    fn runs_statements_in_order() {
+        let lox = run("var a = 1 + 2;\na * 2;\nprint a;\nvar b = a;\nvar c;");
+        assert_eq!(global(&lox, "b"), "3");
+        assert_eq!(global(&lox, "c"), "nil");
+    }
+
+    #[test]
+    fn scopes_variables_to_blocks() {
+        let lox = run("var a = \"global\";
+             var inner;
+             { var a = \"block\"; { inner = a; } }
+             var outer = a;
+             var b = a = \"assigned\";");
+        assert_eq!(global(&lox, "inner"), "block");
+        assert_eq!(global(&lox, "outer"), "global");
+        assert_eq!(global(&lox, "a"), "assigned");
+        assert_eq!(global(&lox, "b"), "assigned");
+
+        let error = Interpreter::empty().eval("{ missing = 1; }").unwrap_err();
+        assert_eq!(error.to_string(), "Undefined variable 'missing'.");
+    }
+
+    #[test]
+    fn runs_control_flow() {
+        let lox = run("var sum = 0;
+             for (var i = 0; i < 5; i = i + 1) {
+                 if (i == 2) sum = sum + 10; else sum = sum + i;
+             }
+             var n = 0;
+             while (n < 3) n = n + 1;
+             var either = nil or \"default\";
+             var both = 1 and false;
+             var skipped = true;
+             false and (skipped = false);
+             true or (skipped = false);");
+        assert_eq!(global(&lox, "sum"), "18");
+        assert_eq!(global(&lox, "n"), "3");
+        assert_eq!(global(&lox, "either"), "default");
+        assert_eq!(global(&lox, "both"), "false");
+        assert_eq!(global(&lox, "skipped"), "true");
+        // The loop variable is scoped to the loop.
+        assert_eq!(lox.get_global("i"), None);
+    }
+
+    #[test]
+    fn calls_functions_and_closures() {
+        let lox = run("fun make_counter() {
+                 var i = 0;
+                 fun count() { i = i + 1; return i; }
+                 return count;
+             }
+             var counter = make_counter();
+             counter();
+             var second = counter();
+             var fresh = make_counter()();
+             fun fib(n) { if (n < 2) return n; return fib(n - 1) + fib(n - 2); }
+             var f = fib(10);
+             fun noop() {}
+             var nothing = noop();");
+        assert_eq!(global(&lox, "second"), "2");
+        assert_eq!(global(&lox, "fresh"), "1");
+        assert_eq!(global(&lox, "f"), "55");
+        assert_eq!(global(&lox, "nothing"), "nil");
+        assert_eq!(global(&lox, "fib"), "<fn fib>");
+
+        let mut lox = Interpreter::empty();
+        let error = lox.eval("fun f(a, b) {}\nf(1);").unwrap_err();
+        assert_eq!(error.to_string(), "Expected 2 arguments but got 1.");
+        let error = lox.eval("\"f\"();").unwrap_err();
+        assert_eq!(error.to_string(), "Can only call functions and classes.");
+    }
+
+    #[test]
+    fn forgets_resolutions_that_cannot_run_again() {
+        let mut lox = Interpreter::empty();
+        for _ in 0..3 {
+            lox.eval("{ var a = 1; var b = a; }").unwrap();
+        }
+        assert!(lox.locals.is_empty());
+
+        lox.eval("fun f(x) { return x; }").unwrap();
+        assert_eq!(lox.locals.len(), 1);
+        assert!(lox
+            .eval("{ var a = 1; fun g() { return a; } var a = 2; }")
+            .is_err());
+        assert_eq!(lox.locals.len(), 1);
+        lox.eval("var y = f(2);").unwrap();
+        assert_eq!(global(&lox, "y"), "2");
+    }
+
+    #[test]
+    fn runs_classes() {
+        let lox = run("class Counter {
+                 init(start) { this.n = start; }
+                 add() { this.n = this.n + 1; return this; }
+             }
+             var c = Counter(1);
+             c.add().add();
+             var method = c.add;
+             method();
+             var n = c.n;
+             var again = c.init(10);
+             var reset = c.n;
+             c.field = \"set\";
+             var field = c.field;");
+        assert_eq!(global(&lox, "n"), "4");
+        assert_eq!(global(&lox, "again"), "Counter instance");
+        assert_eq!(global(&lox, "reset"), "10");
+        assert_eq!(global(&lox, "field"), "set");
+        assert_eq!(global(&lox, "Counter"), "Counter");
+
+        let mut lox = Interpreter::empty();
+        let error = lox.eval("class A {}\nA().missing;").unwrap_err();
+        assert_eq!(error.to_string(), "Undefined property 'missing'.");
+        let error = lox.eval("A(1);").unwrap_err();
+        assert_eq!(error.to_string(), "Expected 0 arguments but got 1.");
+        let error = lox.eval("print this;").unwrap_err();
+        assert_eq!(error.to_string(), "Can't use 'this' outside of a class.");
+        let error = lox.eval("class B { init() { return 1; } }").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Can't return a value from an initializer."
+        );
+    }
+
+    #[test]
+    fn runs_inherited_and_super_methods() {
+        let lox = run("class A {
+                 init(name) { this.name = name; }
+                 describe() { return \"A \" + this.name; }
+                 greet() { return \"hi \" + this.describe(); }
+             }
+             class B < A {
+                 describe() { return \"B, \" + super.describe(); }
+             }
+             var greeting = B(\"b\").greet();");
+        assert_eq!(global(&lox, "greeting"), "hi B, A b");
+
+        let mut lox = Interpreter::empty();
+        let error = lox.eval("class C < C {}").unwrap_err();
+        assert_eq!(error.to_string(), "A class can't inherit from itself.");
+        let error = lox.eval("var x = 1;\nclass D < x {}").unwrap_err();
+        assert_eq!(error.to_string(), "Superclass must be a class.");
+        let error = lox
+            .eval("class E { f() { return super.f(); } }")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Can't use 'super' in a class with no superclass."
+        );
+        let error = lox.eval("super.f();").unwrap_err();
+        assert_eq!(error.to_string(), "Can't use 'super' outside of a class.");
+    }
+
+    #[test]
+    fn prints_lists_that_contain_themselves() {
+        let lox = run("var xs = [1, 2]; xs[1] = xs; var a = [1]; var twice = [a, a];");
+        assert_eq!(global(&lox, "xs"), "[1, [...]]");
+        assert_eq!(global(&lox, "twice"), "[[1], [1]]");
+    }
+
+    #[test]
+    fn allows_trailing_commas_in_lists() {
+        let lox = run("var xs = [1, 2,]; var ys = [\n  \"a\",\n];");
+        assert_eq!(global(&lox, "xs"), "[1, 2]");
+        assert_eq!(global(&lox, "ys"), "[\"a\"]");
+
+        let error = Interpreter::empty().eval("var zs = [,];").unwrap_err();
+        assert_eq!(error.to_string(), "Expect expression.");
+    }
+
+    #[test]
+    fn reports_unbounded_recursion() {
+        let lox = run(
+            "fun depth(n) { if (n == 0) return 0; return depth(n - 1) + 1; }\nvar d = depth(1000);",
+        );
+        assert_eq!(global(&lox, "d"), "1000");
+
+        let mut lox = Interpreter::empty();
+        let error = lox.eval("fun f() { f(); }\nf();").unwrap_err();
+        assert_eq!(error.to_string(), "Stack overflow.");
+        let diagnostic = &error.diagnostics()[0];
+        assert_eq!(diagnostic.code, Some(codes::STACK_OVERFLOW));
+        match diagnostic.location {
+            Some(crate::diagnostic::Location::Span { start, .. }) => {
+                assert_eq!(&lox.source()[start..start + 1], ")")
+            }
+            _ => panic!("stack overflows should have a span"),
+        }
+
+        // Unwinding the overflow leaves the interpreter usable.
+        lox.eval("fun g() { return 1; }\nvar one = g();").unwrap();
+        assert_eq!(global(&lox, "one"), "1");
+    }
+}
@@ -1,7 +1,185 @@
+//! A tree-walking interpreter for Lox, along with the diagnostics shared by
+//! every Lox front end.
+//!
+//! [`Interpreter`] is the place to start when embedding Lox in another
+//! program; the scanner, parser and resolver it's built from are available
+//! for tools that only need part of the pipeline.
+
+// Lets the derives, which name `::lox`, be used in this crate too.
+extern crate self as lox;
+
+pub mod ast;
+pub mod callable;
+pub mod class;
+pub mod convert;
+pub mod diagnostic;
+pub mod environment;
+mod error;
+pub mod function;
+pub mod instance;
+pub mod interpreter;
+pub mod list;
+pub mod native;
+pub mod object;
+pub mod parser;
+pub mod reporter;
+pub mod resolver;
+pub mod runtime_error;
+pub mod scanner;
+pub mod stdlib;
+pub mod token;
+pub mod token_type;
+
+pub use convert::FromLox;
+pub use convert::IntoLox;
+pub use error::Error;
+pub use interpreter::Interpreter;
+pub use lox_repl_macros::FromLox;
+pub use lox_repl_macros::IntoLox;
+pub use object::Object;
+pub use parser::Parser;
+pub use runtime_error::RuntimeError;
+pub use scanner::Scanner;
+pub use stdlib::Capabilities;
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
-    fn it_works() {
-        assert_eq!(2 + 2, 4);
+    fn globals_survive_between_evals() {
+        let mut lox = Interpreter::new();
+        lox.eval("fun double(n) { return n * 2; }").unwrap();
+        lox.set_global("x", Some(Object::Number(21.0)));
+        lox.eval("var y = double(x);").unwrap();
+        assert_eq!(lox.get_global("y"), Some(Object::Number(42.0)));
+        assert_eq!(lox.get_global("z"), None);
+    }
+
+    #[test]
+    fn reports_every_compile_error() {
+        let mut lox = Interpreter::new();
+        let error = lox.eval("var = 1;\nprint ;").unwrap_err();
+        assert!(matches!(error, Error::Compile(_)));
+        assert_eq!(error.diagnostics().len(), 2);
+        assert_eq!(error.exit_code(), 65);
+    }
+
+    #[test]
+    fn stops_at_runtime_errors() {
+        let mut lox = Interpreter::new();
+        let error = lox.eval("var a = 1;\na = -\"a\";\nvar b = 2;").unwrap_err();
+        assert_eq!(error.to_string(), "Operand must be a number.");
+        assert_eq!(lox.get_global("a"), Some(Object::Number(1.0)));
+        assert_eq!(lox.get_global("b"), None);
+
+        // The error's span points into everything evaluated so far.
+        let diagnostic = &error.diagnostics()[0];
+        match diagnostic.location {
+            Some(diagnostic::Location::Span { start, .. }) => {
+                assert_eq!(&lox.source()[start..start + 1], "-")
+            }
+            _ => panic!("runtime errors should have a span"),
+        }
+    }
+
+    #[test]
+    fn indexes_lists() {
+        let mut lox = Interpreter::new();
+        lox.eval("var xs = [1, [2, 3], \"a\"];\nxs[1][0] = xs[0] + 1;\nvar ys = xs;\nys[0] = nil;")
+            .unwrap();
+        assert_eq!(
+            lox.get_global("xs").unwrap().to_string(),
+            "[nil, [2, 3], \"a\"]"
+        );
+        assert_eq!(lox.get_global("xs"), lox.get_global("ys"));
+
+        let error = lox.eval("xs[3];").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Index 3 is out of bounds for a list of length 3."
+        );
+        match error.diagnostics()[0].location {
+            Some(diagnostic::Location::Span { start, .. }) => {
+                assert_eq!(&lox.source()[start..start + 1], "]")
+            }
+            _ => panic!("index errors should have a span"),
+        }
+        let error = lox.eval("xs[0.5] = 1;").unwrap_err();
+        assert_eq!(error.to_string(), "Index must be a whole number.");
+        let error = lox.eval("\"abc\"[0];").unwrap_err();
+        assert_eq!(error.to_string(), "Only lists can be indexed.");
+    }
+
+    #[test]
+    fn natives_report_errors_at_the_call_site() {
+        let mut lox = Interpreter::new();
+        lox.define_native("half", 1, |_, arguments| match arguments[0] {
+            Some(Object::Number(n)) => Ok(Some(Object::Number(n / 2.0))),
+            _ => Err(RuntimeError::argument_type(0, "a number")),
+        });
+        lox.eval("var a = half(8);").unwrap();
+        assert_eq!(lox.get_global("a"), Some(Object::Number(4.0)));
+
+        let error = lox.eval("half(1, 2);").unwrap_err();
+        assert_eq!(error.to_string(), "Expected 1 arguments but got 2.");
+
+        let error = lox.eval("half(\"a\");").unwrap_err();
+        assert_eq!(error.to_string(), "Argument 1 must be a number.");
+        let diagnostic = &error.diagnostics()[0];
+        assert_eq!(diagnostic.code, Some(diagnostic::codes::ARGUMENT_TYPE));
+        match diagnostic.location {
+            Some(diagnostic::Location::Span { start, .. }) => {
+                assert_eq!(&lox.source()[start..start + 1], ")")
+            }
+            _ => panic!("native errors should have a span"),
+        }
+    }
+
+    #[derive(Debug, PartialEq, FromLox, IntoLox)]
+    struct Point {
+        x: i32,
+        y: i32,
+        label: Option<String>,
+    }
+
+    #[test]
+    fn converts_structs_to_and_from_instances() {
+        let mut lox = Interpreter::new();
+        lox.define_native("point", 2, |_, arguments| {
+            let x: i32 = convert::argument(arguments, 0)?;
+            let y: i32 = convert::argument(arguments, 1)?;
+            Ok(Point { x, y, label: None }.into_lox())
+        });
+        lox.eval("var p = point(1, 2);\np.label = \"origin\";\np.x = p.x - 1;")
+            .unwrap();
+        assert_eq!(
+            Point::from_lox(lox.get_global("p")),
+            Ok(Point {
+                x: 0,
+                y: 2,
+                label: Some(String::from("origin")),
+            })
+        );
+
+        lox.eval("p.y = 0.5;").unwrap();
+        let error = Point::from_lox(lox.get_global("p")).unwrap_err();
+        assert_eq!(
+            error.expected,
+            "an instance whose 'y' field is an integer from -2147483648 to 2147483647"
+        );
+
+        let error = lox.eval("point(1, \"2\");").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Argument 2 must be an integer from -2147483648 to 2147483647."
+        );
+    }
+
+    #[test]
+    fn run_file_reports_io_errors() {
+        let mut lox = Interpreter::new();
+        let error = lox.run_file("does/not/exist.lox").unwrap_err();
+        assert!(matches!(error, Error::Io(_)));
     }
 }
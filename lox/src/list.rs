@@ -0,0 +1,38 @@
+//! Lists are shared by reference, so a change made through one variable is
+//! seen through every other that holds the same list.
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::diagnostic::codes;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+
+pub type List = Arc<RwLock<Vec<Option<Object>>>>;
+
+pub fn new(elements: Vec<Option<Object>>) -> Object {
+    Object::List(Arc::new(RwLock::new(elements)))
+}
+
+/// Checks that `index` picks out one of `len` elements. Errors don't know
+/// where they happened, so the caller must blame a token for them.
+pub fn index(index: &Option<Object>, len: usize) -> Result<usize, RuntimeError> {
+    match *index {
+        Some(Object::Number(n)) if n >= 0.0 && n.fract() == 0.0 => {
+            if n < len as f64 {
+                Ok(n as usize)
+            } else {
+                Err(RuntimeError::native(format!(
+                    "Index {} is out of bounds for a list of length {}.",
+                    n, len
+                ))
+                .with_code(codes::INDEX_OUT_OF_BOUNDS))
+            }
+        }
+        _ => {
+            Err(RuntimeError::native("Index must be a whole number.")
+                .with_code(codes::OPERAND_TYPE))
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt::Debug;
+use std::fmt::Display;
+
+use crate::callable::Arity;
+use crate::callable::LoxCallable;
+use crate::interpreter::Interpreter;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+
+type NativeFn = dyn Fn(&mut Interpreter, &[Option<Object>]) -> Result<Option<Object>, RuntimeError>
+    + Send
+    + Sync;
+
+/// A function written in Rust and called from Lox. See
+/// [`Interpreter::define_native`].
+pub struct NativeFunction {
+    name: String,
+    arity: Arity,
+    function: Box<NativeFn>,
+}
+
+impl NativeFunction {
+    pub fn new<F>(name: &str, arity: Arity, function: F) -> Self
+    where
+        F: Fn(&mut Interpreter, &[Option<Object>]) -> Result<Option<Object>, RuntimeError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            arity,
+            function: Box::new(function),
+        }
+    }
+}
+
+impl LoxCallable for NativeFunction {
+    fn arity(&self) -> Arity {
+        self.arity
+    }
+
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<Option<Object>>,
+    ) -> Result<Option<Object>, RuntimeError> {
+        (self.function)(interpreter, &arguments)
+    }
+}
+
+impl Display for NativeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn {}>", self.name)
+    }
+}
+
+impl Debug for NativeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
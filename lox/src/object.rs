@@ -0,0 +1,121 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::class::LoxClass;
+use crate::function::LoxFunction;
+use crate::instance::LoxInstance;
+use crate::list::List;
+use crate::native::NativeFunction;
+
+#[derive(Debug, Clone)]
+pub enum Object {
+    Number(f64),
+    String(String),
+    Boolean(bool),
+    Function(Arc<LoxFunction>),
+    Class(Arc<LoxClass>),
+    Instance(Arc<RwLock<LoxInstance>>),
+    Native(Arc<NativeFunction>),
+    List(List),
+}
+
+impl Object {
+    /// Returns `true` if the object is [`Number`](Self::Number).
+    pub fn is_number(&self) -> bool {
+        matches!(self, Self::Number(..))
+    }
+}
+
+impl Display for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Number(n) => n.to_string(),
+                Self::String(s) => s.clone(),
+                Self::Boolean(b) => b.to_string(),
+                Self::Function(function) => function.to_string(),
+                Self::Class(class) => class.to_string(),
+                Self::Instance(instance) => instance.read().to_string(),
+                Self::Native(native) => native.to_string(),
+                Self::List(list) => format_list(list, &mut Vec::new()),
+            }
+        )
+    }
+}
+
+impl PartialEq for Object {
+    fn eq(&self, other: &Self) -> bool {
+        match self {
+            Self::Number(n) => match other {
+                Self::Number(m) => n == m,
+                _ => false,
+            },
+            Self::String(s) => match other {
+                Self::String(t) => s == t,
+                _ => false,
+            },
+            Self::Boolean(b) => match other {
+                Self::Boolean(c) => b == c,
+                _ => false,
+            },
+            Self::Function(function) => match other {
+                Self::Function(other) => Arc::ptr_eq(function, other),
+                _ => false,
+            },
+            Self::Class(class) => match other {
+                Self::Class(other) => Arc::ptr_eq(class, other),
+                _ => false,
+            },
+            Self::Instance(instance) => match other {
+                Self::Instance(other) => Arc::ptr_eq(instance, other),
+                _ => false,
+            },
+            Self::Native(native) => match other {
+                Self::Native(other) => Arc::ptr_eq(native, other),
+                _ => false,
+            },
+            Self::List(list) => match other {
+                Self::List(other) => Arc::ptr_eq(list, other),
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Shows a list's elements, quoting strings so that they stand apart.
+///
+/// `printing` holds the lists this one is being shown inside of, so that a
+/// list containing itself is shown as `[...]` instead of forever.
+fn format_list(list: &List, printing: &mut Vec<List>) -> String {
+    if printing.iter().any(|outer| Arc::ptr_eq(outer, list)) {
+        return String::from("[...]");
+    }
+
+    printing.push(list.clone());
+    let elements = list
+        .read()
+        .iter()
+        .map(|element| match element {
+            None => String::from("nil"),
+            Some(Object::String(s)) => format!("\"{}\"", s),
+            Some(Object::List(inner)) => format_list(inner, printing),
+            Some(value) => value.to_string(),
+        })
+        .collect::<Vec<_>>();
+    printing.pop();
+    format!("[{}]", elements.join(", "))
+}
+
+pub trait IsTruthy {
+    fn is_truthy(&self) -> bool;
+}
+
+impl IsTruthy for Option<Object> {
+    fn is_truthy(&self) -> bool {
+        !matches!(self, None | Some(Object::Boolean(false)))
+    }
+}
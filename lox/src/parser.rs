@@ -0,0 +1,638 @@
+use std::sync::Arc;
+
+use crate::ast;
+use crate::ast::AssignExpr;
+use crate::ast::BinaryExpr;
+use crate::ast::BlockStmt;
+use crate::ast::CallExpr;
+use crate::ast::ClassStmt;
+use crate::ast::Expr;
+use crate::ast::ExpressionStmt;
+use crate::ast::FunctionStmt;
+use crate::ast::GetExpr;
+use crate::ast::GroupingExpr;
+use crate::ast::IfStmt;
+use crate::ast::IndexExpr;
+use crate::ast::ListExpr;
+use crate::ast::LiteralExpr;
+use crate::ast::LogicalExpr;
+use crate::ast::PrintStmt;
+use crate::ast::ReturnStmt;
+use crate::ast::SetExpr;
+use crate::ast::SetIndexExpr;
+use crate::ast::Stmt;
+use crate::ast::SuperExpr;
+use crate::ast::ThisExpr;
+use crate::ast::UnaryExpr;
+use crate::ast::VarStmt;
+use crate::ast::VariableExpr;
+use crate::ast::WhileStmt;
+use crate::diagnostic::codes;
+use crate::object::Object;
+use crate::reporter::Reporter;
+use crate::token::Token;
+use crate::token_type::TokenType;
+
+pub struct Parser<'r> {
+    tokens: Vec<Token>,
+    current: usize,
+    reporter: &'r mut Reporter,
+}
+
+struct ParseError;
+
+const MAX_ARGUMENTS: usize = 255;
+
+impl<'r> Parser<'r> {
+    pub fn new(tokens: Vec<Token>, reporter: &'r mut Reporter) -> Self {
+        Self {
+            tokens,
+            current: 0,
+            reporter,
+        }
+    }
+
+    /// Parses every declaration in the tokens. One that fails to parse is
+    /// reported and skipped up to the next statement boundary, so the result
+    /// holds every statement that did parse.
+    pub fn parse(&mut self) -> Vec<Stmt> {
+        let mut statements = vec![];
+        while !self.at_end() {
+            if let Some(statement) = self.declaration() {
+                statements.push(statement);
+            }
+        }
+        statements
+    }
+
+    fn declaration(&mut self) -> Option<Stmt> {
+        let result = if self.matches(&[TokenType::Class]) {
+            self.class_declaration()
+        } else if self.matches(&[TokenType::Fun]) {
+            self.function("function").map(Stmt::Function)
+        } else if self.matches(&[TokenType::Var]) {
+            self.var_declaration()
+        } else {
+            self.statement()
+        };
+
+        match result {
+            Ok(statement) => Some(statement),
+            Err(ParseError) => {
+                self.synchronize();
+                None
+            }
+        }
+    }
+
+    fn class_declaration(&mut self) -> Result<Stmt, ParseError> {
+        let name = self
+            .consume(TokenType::Identifier, "Expect class name.")?
+            .clone();
+
+        let superclass = if self.matches(&[TokenType::Less]) {
+            let name = self
+                .consume(TokenType::Identifier, "Expect superclass name.")?
+                .clone();
+            Some(VariableExpr::new(ast::next_id(), name))
+        } else {
+            None
+        };
+
+        self.consume(TokenType::LeftBrace, "Expect '{' before class body.")?;
+
+        let mut methods = vec![];
+        while !self.check(TokenType::RightBrace) && !self.at_end() {
+            methods.push(self.function("method")?);
+        }
+
+        self.consume(TokenType::RightBrace, "Expect '}' after class body.")?;
+
+        Ok(Stmt::Class(ClassStmt::new(name, superclass, methods)))
+    }
+
+    fn function(&mut self, kind: &str) -> Result<FunctionStmt, ParseError> {
+        let name = self
+            .consume(TokenType::Identifier, &format!("Expect {} name.", kind))?
+            .clone();
+        self.consume(
+            TokenType::LeftParen,
+            &format!("Expect '(' after {} name.", kind),
+        )?;
+        let mut params = vec![];
+        if !self.check(TokenType::RightParen) {
+            loop {
+                if params.len() >= MAX_ARGUMENTS {
+                    self.error_at_current(
+                        codes::LIMIT_EXCEEDED,
+                        "Can't have more than 255 parameters.",
+                    );
+                }
+                params.push(
+                    self.consume(TokenType::Identifier, "Expect parameter name.")?
+                        .clone(),
+                );
+                self.optional_annotation()?;
+                if !self.matches(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+        self.consume(TokenType::RightParen, "Expect ')' after parameters.")?;
+        self.optional_annotation()?;
+
+        self.consume(
+            TokenType::LeftBrace,
+            &format!("Expect '{{' before {} body.", kind),
+        )?;
+        let body = self.block()?;
+        Ok(FunctionStmt::new(name, params, Arc::new(body)))
+    }
+
+    fn var_declaration(&mut self) -> Result<Stmt, ParseError> {
+        let name = self
+            .consume(TokenType::Identifier, "Expect variable name.")?
+            .clone();
+        self.optional_annotation()?;
+
+        let initializer = if self.matches(&[TokenType::Equal]) {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+
+        self.consume(
+            TokenType::Semicolon,
+            "Expect ';' after variable declaration.",
+        )?;
+        Ok(Stmt::Var(VarStmt::new(name, initializer)))
+    }
+
+    /// Skips a `: type` annotation if there is one. Annotations are only
+    /// checked by `lox-compiler check`, so the interpreter discards them.
+    fn optional_annotation(&mut self) -> Result<(), ParseError> {
+        if self.matches(&[TokenType::Colon]) {
+            self.type_annotation()?;
+        }
+        Ok(())
+    }
+
+    fn type_annotation(&mut self) -> Result<(), ParseError> {
+        if self.matches(&[TokenType::Identifier, TokenType::Nil]) {
+            return Ok(());
+        }
+
+        self.consume(TokenType::Fun, "Expect type.")?;
+        self.consume(TokenType::LeftParen, "Expect '(' after 'fun'.")?;
+        if !self.check(TokenType::RightParen) {
+            loop {
+                self.type_annotation()?;
+                if !self.matches(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+        self.consume(TokenType::RightParen, "Expect ')' after parameter types.")?;
+        self.consume(TokenType::Colon, "Expect ':' before return type.")?;
+        self.type_annotation()
+    }
+
+    fn statement(&mut self) -> Result<Stmt, ParseError> {
+        if self.matches(&[TokenType::For]) {
+            self.for_statement()
+        } else if self.matches(&[TokenType::If]) {
+            self.if_statement()
+        } else if self.matches(&[TokenType::Print]) {
+            self.print_statement()
+        } else if self.matches(&[TokenType::Return]) {
+            self.return_statement()
+        } else if self.matches(&[TokenType::While]) {
+            self.while_statement()
+        } else if self.matches(&[TokenType::LeftBrace]) {
+            Ok(Stmt::Block(BlockStmt::new(self.block()?)))
+        } else {
+            self.expression_statement()
+        }
+    }
+
+    fn for_statement(&mut self) -> Result<Stmt, ParseError> {
+        self.consume(TokenType::LeftParen, "Expect '(' after 'for'.")?;
+
+        let initializer = if self.matches(&[TokenType::Semicolon]) {
+            None
+        } else if self.matches(&[TokenType::Var]) {
+            Some(self.var_declaration()?)
+        } else {
+            Some(self.expression_statement()?)
+        };
+
+        let condition = if self.check(TokenType::Semicolon) {
+            None
+        } else {
+            Some(self.expression()?)
+        };
+        self.consume(TokenType::Semicolon, "Expect ';' after loop condition.")?;
+
+        let increment = if self.check(TokenType::RightParen) {
+            None
+        } else {
+            Some(self.expression()?)
+        };
+        self.consume(TokenType::RightParen, "Expect ')' after for clauses.")?;
+
+        let mut body = self.statement()?;
+
+        // Desugar into a while loop wrapped in the initializer's scope.
+        if let Some(increment) = increment {
+            body = Stmt::Block(BlockStmt::new(vec![
+                body,
+                Stmt::Expression(ExpressionStmt::new(increment)),
+            ]));
+        }
+
+        let condition = condition
+            .unwrap_or_else(|| Expr::Literal(LiteralExpr::new(Some(Object::Boolean(true)))));
+        body = Stmt::While(WhileStmt::new(condition, Box::new(body)));
+
+        if let Some(initializer) = initializer {
+            body = Stmt::Block(BlockStmt::new(vec![initializer, body]));
+        }
+
+        Ok(body)
+    }
+
+    fn if_statement(&mut self) -> Result<Stmt, ParseError> {
+        self.consume(TokenType::LeftParen, "Expect '(' after 'if'.")?;
+        let condition = self.expression()?;
+        self.consume(TokenType::RightParen, "Expect ')' after if condition.")?;
+
+        let then_branch = Box::new(self.statement()?);
+        let else_branch = if self.matches(&[TokenType::Else]) {
+            Some(Box::new(self.statement()?))
+        } else {
+            None
+        };
+
+        Ok(Stmt::If(IfStmt::new(condition, then_branch, else_branch)))
+    }
+
+    fn print_statement(&mut self) -> Result<Stmt, ParseError> {
+        let value = self.expression()?;
+        self.consume(TokenType::Semicolon, "Expect ';' after value.")?;
+        Ok(Stmt::Print(PrintStmt::new(value)))
+    }
+
+    fn return_statement(&mut self) -> Result<Stmt, ParseError> {
+        let keyword = self.previous().clone();
+        let value = if self.check(TokenType::Semicolon) {
+            None
+        } else {
+            Some(self.expression()?)
+        };
+
+        self.consume(TokenType::Semicolon, "Expect ';' after return value.")?;
+        Ok(Stmt::Return(ReturnStmt::new(keyword, value)))
+    }
+
+    fn while_statement(&mut self) -> Result<Stmt, ParseError> {
+        self.consume(TokenType::LeftParen, "Expect '(' after 'while'.")?;
+        let condition = self.expression()?;
+        self.consume(TokenType::RightParen, "Expect ')' after condition.")?;
+        let body = Box::new(self.statement()?);
+
+        Ok(Stmt::While(WhileStmt::new(condition, body)))
+    }
+
+    fn expression_statement(&mut self) -> Result<Stmt, ParseError> {
+        let expr = self.expression()?;
+        self.consume(TokenType::Semicolon, "Expect ';' after expression.")?;
+        Ok(Stmt::Expression(ExpressionStmt::new(expr)))
+    }
+
+    fn block(&mut self) -> Result<Vec<Stmt>, ParseError> {
+        let mut statements = vec![];
+
+        while !self.check(TokenType::RightBrace) && !self.at_end() {
+            if let Some(statement) = self.declaration() {
+                statements.push(statement);
+            }
+        }
+
+        self.consume(TokenType::RightBrace, "Expect '}' after block.")?;
+        Ok(statements)
+    }
+
+    fn expression(&mut self) -> Result<Expr, ParseError> {
+        self.assignment()
+    }
+
+    fn assignment(&mut self) -> Result<Expr, ParseError> {
+        let expr = self.or()?;
+
+        if self.matches(&[TokenType::Equal]) {
+            let equals = self.previous().clone();
+            let value = self.assignment()?;
+
+            match expr {
+                Expr::Variable(variable) => {
+                    return Ok(Expr::Assign(AssignExpr::new(
+                        ast::next_id(),
+                        variable.name,
+                        Box::new(value),
+                    )));
+                }
+                Expr::Get(get) => {
+                    return Ok(Expr::Set(SetExpr::new(
+                        get.object,
+                        get.name,
+                        Box::new(value),
+                    )));
+                }
+                Expr::Index(index) => {
+                    return Ok(Expr::SetIndex(SetIndexExpr::new(
+                        index.object,
+                        index.bracket,
+                        index.index,
+                        Box::new(value),
+                    )));
+                }
+                _ => {}
+            }
+
+            self.error(&equals, codes::SYNTAX, "Invalid assignment target.");
+        }
+
+        Ok(expr)
+    }
+
+    fn or(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.and()?;
+
+        while self.matches(&[TokenType::Or]) {
+            let operator = self.previous().clone();
+            let right = self.and()?;
+            expr = Expr::Logical(LogicalExpr::new(Box::new(expr), operator, Box::new(right)));
+        }
+
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.equality()?;
+
+        while self.matches(&[TokenType::And]) {
+            let operator = self.previous().clone();
+            let right = self.equality()?;
+            expr = Expr::Logical(LogicalExpr::new(Box::new(expr), operator, Box::new(right)));
+        }
+
+        Ok(expr)
+    }
+
+    fn equality(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.comparison()?;
+
+        while self.matches(&[TokenType::BangEqual, TokenType::EqualEqual]) {
+            let operator = self.previous().clone();
+            let right = self.comparison()?;
+            expr = Expr::Binary(BinaryExpr::new(Box::new(expr), operator, Box::new(right)));
+        }
+
+        Ok(expr)
+    }
+
+    fn comparison(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.term()?;
+
+        while self.matches(&[
+            TokenType::Greater,
+            TokenType::GreaterEqual,
+            TokenType::Less,
+            TokenType::LessEqual,
+        ]) {
+            let operator = self.previous().clone();
+            let right = self.term()?;
+            expr = Expr::Binary(BinaryExpr::new(Box::new(expr), operator, Box::new(right)));
+        }
+
+        Ok(expr)
+    }
+
+    fn term(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.factor()?;
+
+        while self.matches(&[TokenType::Minus, TokenType::Plus]) {
+            let operator = self.previous().clone();
+            let right = self.factor()?;
+            expr = Expr::Binary(BinaryExpr::new(Box::new(expr), operator, Box::new(right)));
+        }
+
+        Ok(expr)
+    }
+
+    fn factor(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.unary()?;
+
+        while self.matches(&[TokenType::Slash, TokenType::Star]) {
+            let operator = self.previous().clone();
+            let right = self.unary()?;
+            expr = Expr::Binary(BinaryExpr::new(Box::new(expr), operator, Box::new(right)));
+        }
+
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        if self.matches(&[TokenType::Bang, TokenType::Minus]) {
+            let operator = self.previous().clone();
+            let right = self.unary()?;
+            return Ok(Expr::Unary(UnaryExpr::new(operator, Box::new(right))));
+        }
+
+        self.call()
+    }
+
+    fn call(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.primary()?;
+
+        loop {
+            if self.matches(&[TokenType::LeftParen]) {
+                expr = self.finish_call(expr)?;
+            } else if self.matches(&[TokenType::Dot]) {
+                let name = self
+                    .consume(TokenType::Identifier, "Expect property name after '.'.")?
+                    .clone();
+                expr = Expr::Get(GetExpr::new(Box::new(expr), name));
+            } else if self.matches(&[TokenType::LeftBracket]) {
+                let index = self.expression()?;
+                let bracket = self
+                    .consume(TokenType::RightBracket, "Expect ']' after index.")?
+                    .clone();
+                expr = Expr::Index(IndexExpr::new(Box::new(expr), bracket, Box::new(index)));
+            } else {
+                break;
+            }
+        }
+
+        Ok(expr)
+    }
+
+    fn finish_call(&mut self, callee: Expr) -> Result<Expr, ParseError> {
+        let mut arguments = vec![];
+        if !self.check(TokenType::RightParen) {
+            loop {
+                if arguments.len() >= MAX_ARGUMENTS {
+                    self.error_at_current(
+                        codes::LIMIT_EXCEEDED,
+                        "Can't have more than 255 arguments.",
+                    );
+                }
+                arguments.push(self.expression()?);
+                if !self.matches(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+
+        let paren = self
+            .consume(TokenType::RightParen, "Expect ')' after arguments.")?
+            .clone();
+
+        Ok(Expr::Call(CallExpr::new(
+            Box::new(callee),
+            paren,
+            arguments,
+        )))
+    }
+
+    fn primary(&mut self) -> Result<Expr, ParseError> {
+        if self.matches(&[TokenType::False]) {
+            Ok(Expr::Literal(LiteralExpr::new(Some(Object::Boolean(
+                false,
+            )))))
+        } else if self.matches(&[TokenType::True]) {
+            Ok(Expr::Literal(LiteralExpr::new(Some(Object::Boolean(true)))))
+        } else if self.matches(&[TokenType::Nil]) {
+            Ok(Expr::Literal(LiteralExpr::new(None)))
+        } else if self.matches(&[TokenType::Number, TokenType::String]) {
+            Ok(Expr::Literal(LiteralExpr::new(
+                self.previous().literal.clone(),
+            )))
+        } else if self.matches(&[TokenType::Super]) {
+            let keyword = self.previous().clone();
+            self.consume(TokenType::Dot, "Expect '.' after 'super'.")?;
+            let method = self
+                .consume(TokenType::Identifier, "Expect superclass method name.")?
+                .clone();
+            Ok(Expr::Super(SuperExpr::new(ast::next_id(), keyword, method)))
+        } else if self.matches(&[TokenType::This]) {
+            Ok(Expr::This(ThisExpr::new(
+                ast::next_id(),
+                self.previous().clone(),
+            )))
+        } else if self.matches(&[TokenType::Identifier]) {
+            Ok(Expr::Variable(VariableExpr::new(
+                ast::next_id(),
+                self.previous().clone(),
+            )))
+        } else if self.matches(&[TokenType::LeftParen]) {
+            let expr = self.expression()?;
+            self.consume(TokenType::RightParen, "Expect ')' after expression.")?;
+            Ok(Expr::Grouping(GroupingExpr::new(Box::new(expr))))
+        } else if self.matches(&[TokenType::LeftBracket]) {
+            let mut elements = vec![];
+            if !self.check(TokenType::RightBracket) {
+                loop {
+                    elements.push(self.expression()?);
+                    // A trailing comma is allowed, as in the generated grammar.
+                    if !self.matches(&[TokenType::Comma]) || self.check(TokenType::RightBracket) {
+                        break;
+                    }
+                }
+            }
+            self.consume(TokenType::RightBracket, "Expect ']' after list elements.")?;
+            Ok(Expr::List(ListExpr::new(elements)))
+        } else {
+            Err(self.error_at_current(codes::SYNTAX, "Expect expression."))
+        }
+    }
+
+    fn consume(&mut self, kind: TokenType, message: &str) -> Result<&Token, ParseError> {
+        if self.check(kind) {
+            return Ok(self.advance());
+        }
+
+        Err(self.error_at_current(codes::SYNTAX, message))
+    }
+
+    fn error(&mut self, token: &Token, code: &'static str, message: &str) -> ParseError {
+        self.reporter.error_at(token, code, message);
+        ParseError
+    }
+
+    fn error_at_current(&mut self, code: &'static str, message: &str) -> ParseError {
+        self.reporter
+            .error_at(&self.tokens[self.current], code, message);
+        ParseError
+    }
+
+    fn matches(&mut self, types: &[TokenType]) -> bool {
+        for ty in types {
+            if self.check(*ty) {
+                self.advance();
+                return true;
+            }
+        }
+        false
+    }
+
+    fn check(&self, ty: TokenType) -> bool {
+        if self.at_end() {
+            false
+        } else {
+            self.peek().kind == ty
+        }
+    }
+
+    fn advance(&mut self) -> &Token {
+        if !self.at_end() {
+            self.current += 1;
+        }
+        self.previous()
+    }
+
+    fn at_end(&self) -> bool {
+        self.peek().kind == TokenType::Eof
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.current]
+    }
+
+    fn previous(&self) -> &Token {
+        &self.tokens[self.current - 1]
+    }
+
+    fn synchronize(&mut self) {
+        self.advance();
+
+        while !self.at_end() {
+            if self.previous().kind == TokenType::Semicolon {
+                return;
+            }
+
+            match self.peek().kind {
+                TokenType::Class
+                | TokenType::Fun
+                | TokenType::Var
+                | TokenType::For
+                | TokenType::If
+                | TokenType::While
+                | TokenType::Print
+                | TokenType::Return => return,
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::diagnostic::Diagnostic;
+use crate::token::Token;
+
+/// Collects the errors found in a piece of code before it runs, so that
+/// scanning and parsing can carry on past the first one.
+#[derive(Debug, Default)]
+pub struct Reporter {
+    diagnostics: Vec<Diagnostic>,
+    had_error: bool,
+}
+
+impl Reporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn error(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+        self.had_error = true;
+    }
+
+    pub fn error_at(&mut self, token: &Token, code: &'static str, message: &str) {
+        self.error(diagnostic_at(token, code, message));
+    }
+
+    pub fn had_error(&self) -> bool {
+        self.had_error
+    }
+
+    /// Takes the errors recorded so far.
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+}
+
+pub fn diagnostic_at(token: &Token, code: &'static str, message: &str) -> Diagnostic {
+    Diagnostic::error(message)
+        .with_code(code)
+        .with_span(token.offset, token.end())
+}
@@ -0,0 +1,407 @@
+use std::collections::HashMap;
+
+use crate::ast::AcceptExprVisitor;
+use crate::ast::AcceptStmtVisitor;
+use crate::ast::Expr;
+use crate::ast::ExprVisitor;
+use crate::ast::FunctionStmt;
+use crate::ast::Stmt;
+use crate::ast::StmtVisitor;
+use crate::diagnostic::codes;
+use crate::interpreter::Interpreter;
+use crate::reporter;
+use crate::reporter::Reporter;
+use crate::token::Token;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FunctionType {
+    None,
+    Function,
+    Initializer,
+    Method,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClassType {
+    None,
+    Class,
+    Subclass,
+}
+
+/// Statically binds every variable use to the scope that declares it.
+pub struct Resolver<'i> {
+    interpreter: &'i mut Interpreter,
+    reporter: &'i mut Reporter,
+    scopes: Vec<HashMap<String, bool>>,
+    current_function: FunctionType,
+    current_class: ClassType,
+    /// The expressions resolved so far, and whether each is in a function
+    /// body.
+    resolved: Vec<(usize, bool)>,
+}
+
+impl<'i> Resolver<'i> {
+    pub fn new(interpreter: &'i mut Interpreter, reporter: &'i mut Reporter) -> Self {
+        Self {
+            interpreter,
+            reporter,
+            scopes: vec![],
+            current_function: FunctionType::None,
+            current_class: ClassType::None,
+            resolved: vec![],
+        }
+    }
+
+    /// Every expression this resolver bound to a local, and whether it's
+    /// inside a function body, which may run again after this program ends.
+    pub fn into_resolved(self) -> Vec<(usize, bool)> {
+        self.resolved
+    }
+
+    pub fn resolve(&mut self, statements: &[Stmt]) {
+        for statement in statements {
+            self.resolve_stmt(statement);
+        }
+    }
+
+    fn resolve_stmt(&mut self, stmt: &Stmt) {
+        stmt.accept(self)
+    }
+
+    fn resolve_expr(&mut self, expr: &Expr) {
+        expr.accept(self)
+    }
+
+    fn resolve_function(&mut self, function: &FunctionStmt, kind: FunctionType) {
+        let enclosing_function = self.current_function;
+        self.current_function = kind;
+
+        self.begin_scope();
+        for param in &function.params {
+            self.declare(param);
+            self.define(param);
+        }
+        self.resolve(&function.body);
+        self.end_scope();
+
+        self.current_function = enclosing_function;
+    }
+
+    fn begin_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    fn end_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn declare(&mut self, name: &Token) {
+        if let Some(scope) = self.scopes.last_mut() {
+            if scope.contains_key(&name.lexeme) {
+                self.reporter.error_at(
+                    name,
+                    codes::ALREADY_DECLARED,
+                    "Already a variable with this name in this scope.",
+                );
+            }
+            scope.insert(name.lexeme.clone(), false);
+        }
+    }
+
+    fn define(&mut self, name: &Token) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.lexeme.clone(), true);
+        }
+    }
+
+    fn resolve_local(&mut self, id: usize, name: &Token) {
+        if let Some(depth) = self
+            .scopes
+            .iter()
+            .rev()
+            .position(|scope| scope.contains_key(&name.lexeme))
+        {
+            self.interpreter.resolve(id, depth);
+            self.resolved
+                .push((id, self.current_function != FunctionType::None));
+        }
+    }
+}
+
+impl<'i> StmtVisitor<()> for Resolver<'i> {
+    fn visit_block_stmt(&mut self, stmt: &crate::ast::BlockStmt) {
+        self.begin_scope();
+        self.resolve(&stmt.statements);
+        self.end_scope();
+    }
+
+    fn visit_class_stmt(&mut self, stmt: &crate::ast::ClassStmt) {
+        let enclosing_class = self.current_class;
+        self.current_class = ClassType::Class;
+
+        self.declare(&stmt.name);
+        self.define(&stmt.name);
+
+        if let Some(superclass) = &stmt.superclass {
+            if superclass.name.lexeme == stmt.name.lexeme {
+                self.reporter.error_at(
+                    &superclass.name,
+                    codes::INHERIT_FROM_SELF,
+                    "A class can't inherit from itself.",
+                );
+            }
+
+            self.current_class = ClassType::Subclass;
+            self.visit_variable_expr(superclass);
+
+            self.begin_scope();
+            self.scopes
+                .last_mut()
+                .unwrap()
+                .insert(String::from("super"), true);
+        }
+
+        self.begin_scope();
+        self.scopes
+            .last_mut()
+            .unwrap()
+            .insert(String::from("this"), true);
+
+        for method in &stmt.methods {
+            let declaration = if method.name.lexeme == "init" {
+                FunctionType::Initializer
+            } else {
+                FunctionType::Method
+            };
+            self.resolve_function(method, declaration);
+        }
+
+        self.end_scope();
+
+        if stmt.superclass.is_some() {
+            self.end_scope();
+        }
+
+        self.current_class = enclosing_class;
+    }
+
+    fn visit_expression_stmt(&mut self, stmt: &crate::ast::ExpressionStmt) {
+        self.resolve_expr(&stmt.expression);
+    }
+
+    fn visit_function_stmt(&mut self, stmt: &crate::ast::FunctionStmt) {
+        self.declare(&stmt.name);
+        self.define(&stmt.name);
+
+        self.resolve_function(stmt, FunctionType::Function);
+    }
+
+    fn visit_if_stmt(&mut self, stmt: &crate::ast::IfStmt) {
+        self.resolve_expr(&stmt.condition);
+        self.resolve_stmt(&stmt.then_branch);
+        if let Some(else_branch) = &stmt.else_branch {
+            self.resolve_stmt(else_branch);
+        }
+    }
+
+    fn visit_print_stmt(&mut self, stmt: &crate::ast::PrintStmt) {
+        self.resolve_expr(&stmt.expression);
+    }
+
+    fn visit_return_stmt(&mut self, stmt: &crate::ast::ReturnStmt) {
+        if self.current_function == FunctionType::None {
+            self.reporter.error_at(
+                &stmt.keyword,
+                codes::INVALID_RETURN,
+                "Can't return from top-level code.",
+            );
+        }
+
+        if let Some(value) = &stmt.value {
+            if self.current_function == FunctionType::Initializer {
+                self.reporter.error(
+                    reporter::diagnostic_at(
+                        &stmt.keyword,
+                        codes::INVALID_RETURN,
+                        "Can't return a value from an initializer.",
+                    )
+                    .with_note("Initializers always return 'this'."),
+                );
+            }
+
+            self.resolve_expr(value);
+        }
+    }
+
+    fn visit_var_stmt(&mut self, stmt: &crate::ast::VarStmt) {
+        self.declare(&stmt.name);
+        if let Some(initializer) = &stmt.initializer {
+            self.resolve_expr(initializer);
+        }
+        self.define(&stmt.name);
+    }
+
+    fn visit_while_stmt(&mut self, stmt: &crate::ast::WhileStmt) {
+        self.resolve_expr(&stmt.condition);
+        self.resolve_stmt(&stmt.body);
+    }
+}
+
+impl<'i> ExprVisitor<()> for Resolver<'i> {
+    fn visit_assign_expr(&mut self, expr: &crate::ast::AssignExpr) {
+        self.resolve_expr(&expr.value);
+        self.resolve_local(expr.id, &expr.name);
+    }
+
+    fn visit_binary_expr(&mut self, expr: &crate::ast::BinaryExpr) {
+        self.resolve_expr(&expr.left);
+        self.resolve_expr(&expr.right);
+    }
+
+    fn visit_call_expr(&mut self, expr: &crate::ast::CallExpr) {
+        self.resolve_expr(&expr.callee);
+        for argument in &expr.arguments {
+            self.resolve_expr(argument);
+        }
+    }
+
+    fn visit_get_expr(&mut self, expr: &crate::ast::GetExpr) {
+        self.resolve_expr(&expr.object);
+    }
+
+    fn visit_grouping_expr(&mut self, expr: &crate::ast::GroupingExpr) {
+        self.resolve_expr(&expr.expression);
+    }
+
+    fn visit_index_expr(&mut self, expr: &crate::ast::IndexExpr) {
+        self.resolve_expr(&expr.object);
+        self.resolve_expr(&expr.index);
+    }
+
+    fn visit_list_expr(&mut self, expr: &crate::ast::ListExpr) {
+        for element in &expr.elements {
+            self.resolve_expr(element);
+        }
+    }
+
+    fn visit_literal_expr(&mut self, _expr: &crate::ast::LiteralExpr) {}
+
+    fn visit_logical_expr(&mut self, expr: &crate::ast::LogicalExpr) {
+        self.resolve_expr(&expr.left);
+        self.resolve_expr(&expr.right);
+    }
+
+    fn visit_set_expr(&mut self, expr: &crate::ast::SetExpr) {
+        self.resolve_expr(&expr.value);
+        self.resolve_expr(&expr.object);
+    }
+
+    fn visit_set_index_expr(&mut self, expr: &crate::ast::SetIndexExpr) {
+        self.resolve_expr(&expr.object);
+        self.resolve_expr(&expr.index);
+        self.resolve_expr(&expr.value);
+    }
+
+    fn visit_super_expr(&mut self, expr: &crate::ast::SuperExpr) {
+        match self.current_class {
+            ClassType::None => self.reporter.error_at(
+                &expr.keyword,
+                codes::INVALID_RECEIVER,
+                "Can't use 'super' outside of a class.",
+            ),
+            ClassType::Class => self.reporter.error(
+                reporter::diagnostic_at(
+                    &expr.keyword,
+                    codes::INVALID_RECEIVER,
+                    "Can't use 'super' in a class with no superclass.",
+                )
+                .with_help("Give the class a superclass with '<'."),
+            ),
+            ClassType::Subclass => {}
+        }
+
+        self.resolve_local(expr.id, &expr.keyword);
+    }
+
+    fn visit_this_expr(&mut self, expr: &crate::ast::ThisExpr) {
+        if self.current_class == ClassType::None {
+            self.reporter.error_at(
+                &expr.keyword,
+                codes::INVALID_RECEIVER,
+                "Can't use 'this' outside of a class.",
+            );
+            return;
+        }
+
+        self.resolve_local(expr.id, &expr.keyword);
+    }
+
+    fn visit_unary_expr(&mut self, expr: &crate::ast::UnaryExpr) {
+        self.resolve_expr(&expr.right);
+    }
+
+    fn visit_variable_expr(&mut self, expr: &crate::ast::VariableExpr) {
+        if let Some(scope) = self.scopes.last() {
+            if scope.get(&expr.name.lexeme) == Some(&false) {
+                self.reporter.error_at(
+                    &expr.name,
+                    codes::READ_IN_OWN_INITIALIZER,
+                    "Can't read local variable in its own initializer.",
+                );
+            }
+        }
+
+        self.resolve_local(expr.id, &expr.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Interpreter;
+
+    fn compile_errors(source: &str) -> Vec<String> {
+        match Interpreter::empty().eval(source) {
+            Err(error) => error
+                .diagnostics()
+                .iter()
+                .map(|diagnostic| diagnostic.message.clone())
+                .collect(),
+            Ok(()) => vec![],
+        }
+    }
+
+    #[test]
+    fn binds_closures_statically() {
+        let mut lox = Interpreter::empty();
+        lox.eval(
+            "var a = \"global\";
+             var first;
+             var second;
+             {
+                 fun show() { return a; }
+                 first = show();
+                 var a = \"block\";
+                 second = show();
+             }",
+        )
+        .unwrap();
+        assert_eq!(lox.get_global("first"), lox.get_global("second"));
+    }
+
+    #[test]
+    fn reports_resolution_errors() {
+        assert_eq!(
+            compile_errors("{ var a = 1; { var a = a; } }"),
+            vec!["Can't read local variable in its own initializer."]
+        );
+        assert_eq!(
+            compile_errors("fun f() { var a; var a; }\nreturn 1;"),
+            vec![
+                "Already a variable with this name in this scope.",
+                "Can't return from top-level code.",
+            ]
+        );
+        // Globals may be redeclared.
+        assert!(compile_errors("var a; var a;").is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::Display;
+
+use crate::diagnostic::codes;
+use crate::diagnostic::Diagnostic;
+use crate::reporter;
+use crate::token::Token;
+
+#[derive(Debug)]
+pub struct RuntimeError {
+    /// Where the error happened. Native functions don't know this, so the
+    /// call site is filled in for them.
+    token: Option<Token>,
+    code: &'static str,
+    message: String,
+}
+
+impl RuntimeError {
+    pub fn new(token: Token, code: &'static str, message: String) -> Self {
+        Self {
+            token: Some(token),
+            code,
+            message,
+        }
+    }
+
+    /// An error raised by a native function.
+    pub fn native(message: impl Into<String>) -> Self {
+        Self {
+            token: None,
+            code: codes::NATIVE,
+            message: message.into(),
+        }
+    }
+
+    /// The error for a native function given the wrong type of value as its
+    /// `index`th argument, counting from zero.
+    pub fn argument_type(index: usize, expected: &str) -> Self {
+        Self {
+            token: None,
+            code: codes::ARGUMENT_TYPE,
+            message: format!("Argument {} must be {}.", index + 1, expected),
+        }
+    }
+
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = code;
+        self
+    }
+
+    /// Blames `token` for this error, unless it already knows where it
+    /// happened.
+    pub fn or_at(mut self, token: &Token) -> Self {
+        self.token.get_or_insert_with(|| token.clone());
+        self
+    }
+
+    pub fn diagnostic(&self) -> Diagnostic {
+        match &self.token {
+            Some(token) => reporter::diagnostic_at(token, self.code, &self.message),
+            None => Diagnostic::error(&self.message).with_code(self.code),
+        }
+    }
+}
+
+impl Display for RuntimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for RuntimeError {}
@@ -3,16 +3,22 @@ use std::collections::HashMap;
 use maplit::hashmap;
 use once_cell::sync::Lazy;
 
+use crate::diagnostic::codes;
+use crate::diagnostic::Diagnostic;
 use crate::object::Object;
+use crate::reporter::Reporter;
 use crate::token::Token;
 use crate::token_type::TokenType;
 
-pub(crate) struct Scanner {
+pub struct Scanner<'r> {
     source: Vec<char>,
+    /// The byte offset of each character in the original source, followed by
+    /// the offset of its end.
+    offsets: Vec<usize>,
     tokens: Vec<Token>,
     start: usize,
     current: usize,
-    line: usize,
+    reporter: &'r mut Reporter,
 }
 
 static KEYWORDS: Lazy<HashMap<&'static str, TokenType>> = Lazy::new(|| {
@@ -36,25 +42,39 @@ static KEYWORDS: Lazy<HashMap<&'static str, TokenType>> = Lazy::new(|| {
     }
 });
 
-impl Scanner {
-    pub(crate) fn new(source: &str) -> Self {
+impl<'r> Scanner<'r> {
+    /// Scans `source` from the byte offset `start`, as if it had been
+    /// scanned from the beginning.
+    pub fn starting_at(source: &str, start: usize, reporter: &'r mut Reporter) -> Self {
+        let rest = &source[start..];
         Self {
-            source: source.chars().collect(),
+            source: rest.chars().collect(),
+            offsets: rest
+                .char_indices()
+                .map(|(i, _)| start + i)
+                .chain(std::iter::once(source.len()))
+                .collect(),
             tokens: vec![],
             start: 0,
             current: 0,
-            line: 1,
+            reporter,
         }
     }
 
-    pub(crate) fn scan_tokens(mut self) -> Vec<Token> {
+    pub fn scan_tokens(mut self) -> Vec<Token> {
         while !self.at_end() {
             self.start = self.current;
             self.scan_token();
         }
 
+        // Errors at the end of input point just past the last token rather
+        // than at any trailing blank lines.
+        let end = self
+            .tokens
+            .last()
+            .map_or(self.offsets[0], |token| token.end());
         self.tokens
-            .push(Token::new(TokenType::Eof, String::new(), None, self.line));
+            .push(Token::new(TokenType::Eof, String::new(), None, end));
         self.tokens
     }
 
@@ -69,11 +89,14 @@ impl Scanner {
             ')' => self.add_token(TokenType::RightParen),
             '{' => self.add_token(TokenType::LeftBrace),
             '}' => self.add_token(TokenType::RightBrace),
+            '[' => self.add_token(TokenType::LeftBracket),
+            ']' => self.add_token(TokenType::RightBracket),
             ',' => self.add_token(TokenType::Comma),
             '.' => self.add_token(TokenType::Dot),
             '-' => self.add_token(TokenType::Minus),
             '+' => self.add_token(TokenType::Plus),
             ';' => self.add_token(TokenType::Semicolon),
+            ':' => self.add_token(TokenType::Colon),
             '*' => self.add_token(TokenType::Star),
             '!' => {
                 let kind = if self.matches('=') {
@@ -117,12 +140,11 @@ impl Scanner {
                     self.add_token(TokenType::Slash);
                 }
             }
-            ' ' | '\r' | '\t' => {}
-            '\n' => self.line += 1,
+            ' ' | '\r' | '\t' | '\n' => {}
             '"' => self.string(),
             c if c.is_ascii_digit() => self.number(),
             c if c.is_ascii_alphabetic() || c == '_' => self.identifier(),
-            _ => crate::err(self.line, "Unexpected character."),
+            _ => self.error(codes::UNEXPECTED_CHARACTER, "Unexpected character."),
         }
     }
 
@@ -174,14 +196,16 @@ impl Scanner {
 
     fn string(&mut self) {
         while self.peek() != '"' && !self.at_end() {
-            if self.peek() == '\n' {
-                self.line += 1;
-            }
             self.advance();
         }
 
         if self.at_end() {
-            crate::err(self.line, "Unterminated string.");
+            self.reporter.error(
+                Diagnostic::error("Unterminated string.")
+                    .with_code(codes::UNTERMINATED_STRING)
+                    .with_span(self.offsets[self.start], self.offsets[self.start] + 1)
+                    .with_help("Close the string with '\"'."),
+            );
             return;
         }
 
@@ -227,7 +251,19 @@ impl Scanner {
         O: Into<Option<Object>>,
     {
         let text = self.source[self.start..self.current].iter().collect();
-        self.tokens
-            .push(Token::new(kind, text, literal.into(), self.line));
+        self.tokens.push(Token::new(
+            kind,
+            text,
+            literal.into(),
+            self.offsets[self.start],
+        ));
+    }
+
+    fn error(&mut self, code: &'static str, message: &str) {
+        self.reporter.error(
+            Diagnostic::error(message)
+                .with_code(code)
+                .with_span(self.offsets[self.start], self.offsets[self.current]),
+        );
     }
 }
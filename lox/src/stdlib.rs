@@ -0,0 +1,219 @@
+//! The native functions every [`Interpreter`] starts with, unless it's made
+//! with [`Interpreter::empty`] or without some [`Capabilities`].
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::callable::Arity;
+use crate::convert::argument;
+use crate::interpreter::Interpreter;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+use crate::IntoLox;
+
+pub mod io;
+mod list;
+
+/// Which parts of the standard library an interpreter starts with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The functions that only compute: strings, lists, maths, `clock` and
+    /// `type`.
+    pub core: bool,
+    /// Reading and writing files and stdin. See [`io`].
+    pub io: bool,
+}
+
+impl Capabilities {
+    pub const ALL: Self = Self {
+        core: true,
+        io: true,
+    };
+    pub const NONE: Self = Self {
+        core: false,
+        io: false,
+    };
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+pub fn install(interpreter: &mut Interpreter, capabilities: Capabilities) {
+    if capabilities.core {
+        install_core(interpreter);
+    }
+    if capabilities.io {
+        io::install(interpreter);
+    }
+}
+
+fn install_core(interpreter: &mut Interpreter) {
+    interpreter.define_native("clock", 0, |_, _| {
+        let elapsed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| RuntimeError::native(e.to_string()))?;
+        Ok(elapsed.as_secs_f64().into_lox())
+    });
+    interpreter.define_native("type", 1, |_, arguments| {
+        Ok(type_name(&arguments[0]).into_lox())
+    });
+
+    interpreter.define_native("str", 1, |_, arguments| {
+        let s = match &arguments[0] {
+            Some(value) => value.to_string(),
+            None => String::from("nil"),
+        };
+        Ok(s.into_lox())
+    });
+    // Returns `nil` for strings that aren't numbers, so scripts can check.
+    interpreter.define_native("num", 1, |_, arguments| {
+        let s: String = argument(arguments, 0)?;
+        Ok(parse_number(&s).into_lox())
+    });
+    interpreter.define_native("len", 1, |_, arguments| match &arguments[0] {
+        Some(Object::String(s)) => Ok(s.chars().count().into_lox()),
+        Some(Object::List(list)) => Ok(list.read().len().into_lox()),
+        _ => Err(RuntimeError::argument_type(0, "a string or a list")),
+    });
+    // Counts in characters, and stops early at the end of the string.
+    interpreter.define_native("substr", 3, |_, arguments| {
+        let s: String = argument(arguments, 0)?;
+        let start = index(arguments, 1)?;
+        let length = index(arguments, 2)?;
+        Ok(s.chars()
+            .skip(start)
+            .take(length)
+            .collect::<String>()
+            .into_lox())
+    });
+    interpreter.define_native("upper", 1, |_, arguments| {
+        let s: String = argument(arguments, 0)?;
+        Ok(s.to_uppercase().into_lox())
+    });
+    interpreter.define_native("lower", 1, |_, arguments| {
+        let s: String = argument(arguments, 0)?;
+        Ok(s.to_lowercase().into_lox())
+    });
+
+    list::install(interpreter);
+
+    math(interpreter, "floor", f64::floor);
+    math(interpreter, "ceil", f64::ceil);
+    math(interpreter, "sqrt", f64::sqrt);
+    math(interpreter, "abs", f64::abs);
+    interpreter.define_native("pow", 2, |_, arguments| {
+        let base: f64 = argument(arguments, 0)?;
+        let exponent: f64 = argument(arguments, 1)?;
+        Ok(base.powf(exponent).into_lox())
+    });
+    interpreter.define_native("min", Arity::AtLeast(1), |_, arguments| {
+        Ok(numbers(arguments)?
+            .into_iter()
+            .fold(f64::INFINITY, f64::min)
+            .into_lox())
+    });
+    interpreter.define_native("max", Arity::AtLeast(1), |_, arguments| {
+        Ok(numbers(arguments)?
+            .into_iter()
+            .fold(f64::NEG_INFINITY, f64::max)
+            .into_lox())
+    });
+}
+
+/// The name `type()` gives to the kind of value `value` is.
+pub fn type_name(value: &Option<Object>) -> &'static str {
+    match value {
+        None => "nil",
+        Some(Object::Boolean(_)) => "boolean",
+        Some(Object::Number(_)) => "number",
+        Some(Object::String(_)) => "string",
+        Some(Object::Function(_)) | Some(Object::Native(_)) => "function",
+        Some(Object::Class(_)) => "class",
+        Some(Object::Instance(_)) => "instance",
+        Some(Object::List(_)) => "list",
+    }
+}
+
+/// Parses a number the way it would be written in Lox source, such as `12`
+/// or `3.5`, ignoring surrounding whitespace. Signs, exponents, `inf` and
+/// `NaN` are not numbers.
+pub fn parse_number(s: &str) -> Option<f64> {
+    let s = s.trim();
+    let (whole, fraction) = match s.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (s, None),
+    };
+    let digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    if !digits(whole) || !fraction.is_none_or(digits) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+fn math(interpreter: &mut Interpreter, name: &str, f: fn(f64) -> f64) {
+    interpreter.define_native(name, 1, move |_, arguments| {
+        let n: f64 = argument(arguments, 0)?;
+        Ok(f(n).into_lox())
+    });
+}
+
+fn index(arguments: &[Option<Object>], index: usize) -> Result<usize, RuntimeError> {
+    match arguments[index] {
+        Some(Object::Number(n)) if n >= 0.0 && n.fract() == 0.0 => Ok(n as usize),
+        _ => Err(RuntimeError::argument_type(index, "a whole number")),
+    }
+}
+
+fn numbers(arguments: &[Option<Object>]) -> Result<Vec<f64>, RuntimeError> {
+    (0..arguments.len())
+        .map(|i| argument(arguments, i))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(source: &str) -> Option<Object> {
+        let mut lox = Interpreter::new();
+        lox.eval(&format!("var result = {};", source)).unwrap();
+        lox.get_global("result")
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(eval("str(nil) + str(12)"), "nil12".into_lox());
+        assert_eq!(eval("num(\" 4 \")"), 4.0.into_lox());
+        assert_eq!(eval("num(\"four\")"), None);
+        assert_eq!(eval("num(\"0.25\")"), 0.25.into_lox());
+        for input in ["inf", "NaN", "1e3", "+5", "-5", "1.", ".5"] {
+            assert_eq!(eval(&format!("num(\"{}\")", input)), None, "{}", input);
+        }
+        assert_eq!(eval("len(\"héllo\")"), 5.into_lox());
+        assert_eq!(eval("substr(\"héllo\", 1, 10)"), "éllo".into_lox());
+        assert_eq!(eval("upper(\"a\") + lower(\"B\")"), "Ab".into_lox());
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(eval("floor(7 / 2) + ceil(7 / 2)"), 7.into_lox());
+        assert_eq!(eval("sqrt(pow(3, 2)) + abs(-1)"), 4.into_lox());
+        assert_eq!(eval("min(3, 1, 2) + max(3, 1, 2)"), 4.into_lox());
+        assert_eq!(eval("type(clock())"), "number".into_lox());
+    }
+
+    #[test]
+    fn type_names() {
+        assert_eq!(eval("type(type)"), "function".into_lox());
+        assert_eq!(eval("type(nil) + type(true)"), "nilboolean".into_lox());
+    }
+
+    #[test]
+    fn empty_interpreters_have_no_globals() {
+        let mut lox = Interpreter::empty();
+        assert!(lox.eval("clock();").is_err());
+    }
+}
@@ -0,0 +1,129 @@
+//! Natives for reading and writing files and stdin, installed only when
+//! [`Capabilities::io`](super::Capabilities::io) is set. Anything that goes
+//! wrong is a runtime error, which an embedder gets back as
+//! [`Error::Runtime`](crate::Error::Runtime).
+
+use std::fs;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+
+use crate::convert::argument;
+use crate::interpreter::Interpreter;
+use crate::runtime_error::RuntimeError;
+use crate::IntoLox;
+
+pub fn install(interpreter: &mut Interpreter) {
+    interpreter.define_native("read_file", 1, |_, arguments| {
+        let path: String = argument(arguments, 0)?;
+        let contents = fs::read_to_string(&path).map_err(|e| failure("read", &path, e))?;
+        Ok(contents.into_lox())
+    });
+    interpreter.define_native("write_file", 2, |_, arguments| {
+        let path: String = argument(arguments, 0)?;
+        let contents: String = argument(arguments, 1)?;
+        fs::write(&path, contents).map_err(|e| failure("write", &path, e))?;
+        Ok(None)
+    });
+    interpreter.define_native("append_file", 2, |_, arguments| {
+        let path: String = argument(arguments, 0)?;
+        let contents: String = argument(arguments, 1)?;
+        OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(|e| failure("append to", &path, e))?;
+        Ok(None)
+    });
+    // Returns a list of the lines, without their endings.
+    interpreter.define_native("lines", 1, |_, arguments| {
+        let path: String = argument(arguments, 0)?;
+        let contents = fs::read_to_string(&path).map_err(|e| failure("read", &path, e))?;
+        Ok(contents.lines().collect::<Vec<_>>().into_lox())
+    });
+    interpreter.define_native("file_exists", 1, |_, arguments| {
+        let path: String = argument(arguments, 0)?;
+        Ok(Path::new(&path).exists().into_lox())
+    });
+    // Returns the line without its ending, or `nil` once stdin is finished.
+    interpreter.define_native("read_line", 0, |_, _| {
+        let mut line = String::new();
+        let read = io::stdin()
+            .lock()
+            .read_line(&mut line)
+            .map_err(|e| failure("read", "stdin", e))?;
+        if read == 0 {
+            return Ok(None);
+        }
+
+        let ending = if line.ends_with("\r\n") {
+            2
+        } else if line.ends_with('\n') {
+            1
+        } else {
+            0
+        };
+        line.truncate(line.len() - ending);
+        Ok(line.into_lox())
+    });
+}
+
+fn failure(action: &str, path: &str, e: io::Error) -> RuntimeError {
+    RuntimeError::native(format!("Couldn't {} '{}': {}.", action, path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+    use crate::Capabilities;
+    use crate::Error;
+    use crate::FromLox;
+    use crate::Object;
+
+    #[test]
+    fn reads_and_writes_files() {
+        let path = env::temp_dir().join(format!("lox-io-test-{}.txt", process::id()));
+
+        let mut lox = Interpreter::new();
+        lox.eval(&format!(
+            "var path = \"{}\";
+             var before = file_exists(path);
+             write_file(path, \"one\");
+             append_file(path, \" two\");
+             var contents = read_file(path);
+             append_file(path, \"\nthree\n\");
+             var lines = lines(path);",
+            path.display()
+        ))
+        .unwrap();
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(lox.get_global("before"), Some(Object::Boolean(false)));
+        assert_eq!(lox.get_global("contents"), "one two".into_lox());
+        assert_eq!(
+            Vec::<String>::from_lox(lox.get_global("lines")),
+            Ok(vec![String::from("one two"), String::from("three")])
+        );
+
+        let error = lox.eval("read_file(path);").unwrap_err();
+        assert!(matches!(error, Error::Runtime(_)));
+        assert!(error.to_string().starts_with("Couldn't read '"));
+    }
+
+    #[test]
+    fn can_be_left_out() {
+        let capabilities = Capabilities {
+            io: false,
+            ..Capabilities::ALL
+        };
+        let mut lox = Interpreter::with_capabilities(capabilities);
+        assert_eq!(lox.get_global("read_file"), None);
+        assert!(lox.eval("var s = upper(\"still here\");").is_ok());
+    }
+}
@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+
+use crate::callable::Arity;
+use crate::diagnostic::codes;
+use crate::interpreter::Interpreter;
+use crate::list;
+use crate::list::List;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+
+pub(super) fn install(interpreter: &mut Interpreter) {
+    interpreter.define_native("push", Arity::AtLeast(2), |_, arguments| {
+        list_argument(arguments, 0)?
+            .write()
+            .extend(arguments[1..].iter().cloned());
+        Ok(None)
+    });
+    interpreter.define_native("pop", 1, |_, arguments| {
+        list_argument(arguments, 0)?.write().pop().ok_or_else(|| {
+            RuntimeError::native("Can't pop from an empty list.")
+                .with_code(codes::INDEX_OUT_OF_BOUNDS)
+        })
+    });
+    // The index may be the list's length, to add to the end.
+    interpreter.define_native("insert", 3, |_, arguments| {
+        let list = list_argument(arguments, 0)?;
+        let mut list = list.write();
+        let index = list::index(&arguments[1], list.len() + 1)?;
+        list.insert(index, arguments[2].clone());
+        Ok(None)
+    });
+    interpreter.define_native("remove", 2, |_, arguments| {
+        let list = list_argument(arguments, 0)?;
+        let mut list = list.write();
+        let index = list::index(&arguments[1], list.len())?;
+        Ok(list.remove(index))
+    });
+    // Copies the elements from `start` up to but not including `end`.
+    interpreter.define_native("slice", 3, |_, arguments| {
+        let list = list_argument(arguments, 0)?;
+        let list = list.read();
+        let start = list::index(&arguments[1], list.len() + 1)?;
+        let end = list::index(&arguments[2], list.len() + 1)?;
+        if start > end {
+            return Err(RuntimeError::native("Slice can't end before it starts.")
+                .with_code(codes::INDEX_OUT_OF_BOUNDS));
+        }
+        Ok(Some(list::new(list[start..end].to_vec())))
+    });
+    // Sorts in place. Only numbers, or only strings, can be compared.
+    interpreter.define_native("sort", 1, |_, arguments| {
+        let list = list_argument(arguments, 0)?;
+        let mut list = list.write();
+        if !list.iter().all(|element| comparable(element, &list[0])) {
+            return Err(
+                RuntimeError::native("Can only sort lists of all numbers or all strings.")
+                    .with_code(codes::ARGUMENT_TYPE),
+            );
+        }
+        list.sort_by(compare);
+        Ok(None)
+    });
+    interpreter.define_native("reverse", 1, |_, arguments| {
+        list_argument(arguments, 0)?.write().reverse();
+        Ok(None)
+    });
+}
+
+fn list_argument(arguments: &[Option<Object>], index: usize) -> Result<List, RuntimeError> {
+    match &arguments[index] {
+        Some(Object::List(list)) => Ok(list.clone()),
+        _ => Err(RuntimeError::argument_type(index, "a list")),
+    }
+}
+
+fn comparable(a: &Option<Object>, b: &Option<Object>) -> bool {
+    matches!(
+        (a, b),
+        (Some(Object::Number(_)), Some(Object::Number(_)))
+            | (Some(Object::String(_)), Some(Object::String(_)))
+    )
+}
+
+fn compare(a: &Option<Object>, b: &Option<Object>) -> Ordering {
+    match (a, b) {
+        (Some(Object::Number(a)), Some(Object::Number(b))) => {
+            a.partial_cmp(b).unwrap_or(Ordering::Equal)
+        }
+        (Some(Object::String(a)), Some(Object::String(b))) => a.cmp(b),
+        _ => unreachable!("checked that every element is comparable"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoLox;
+
+    fn eval(source: &str) -> String {
+        let mut lox = Interpreter::new();
+        lox.eval(&format!("var result = {};", source)).unwrap();
+        lox.get_global("result").unwrap().to_string()
+    }
+
+    #[test]
+    fn edits_in_place() {
+        let mut lox = Interpreter::new();
+        lox.eval(
+            "var xs = [3, 1];
+             var ys = xs;
+             push(ys, 2, 5);
+             insert(xs, 0, 4);
+             var popped = pop(xs);
+             var removed = remove(xs, 1);
+             sort(xs);",
+        )
+        .unwrap();
+        assert_eq!(lox.get_global("xs").unwrap().to_string(), "[1, 2, 4]");
+        assert_eq!(lox.get_global("popped"), 5.into_lox());
+        assert_eq!(lox.get_global("removed"), 3.into_lox());
+    }
+
+    #[test]
+    fn copies_slices() {
+        assert_eq!(eval("slice([1, 2, 3], 1, 3)"), "[2, 3]");
+        assert_eq!(eval("slice([1, 2, 3], 3, 3)"), "[]");
+        assert_eq!(eval("len([1, nil, \"a\"])"), "3");
+    }
+
+    #[test]
+    fn reports_bad_indices() {
+        let mut lox = Interpreter::new();
+        let error = lox.eval("remove([1], 1);").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Index 1 is out of bounds for a list of length 1."
+        );
+        let error = lox.eval("sort([1, \"a\"]);").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Can only sort lists of all numbers or all strings."
+        );
+        let error = lox.eval("pop([]);").unwrap_err();
+        assert_eq!(error.to_string(), "Can't pop from an empty list.");
+    }
+}
@@ -4,15 +4,16 @@ use crate::object::Object;
 use crate::token_type::TokenType;
 
 #[derive(Debug, Clone)]
-pub(crate) struct Token {
-    pub(crate) kind: TokenType,
-    pub(crate) lexeme: String,
-    pub(crate) literal: Option<Object>,
-    pub(crate) line: usize,
+pub struct Token {
+    pub kind: TokenType,
+    pub lexeme: String,
+    pub literal: Option<Object>,
+    /// The byte offset of the lexeme in the source.
+    pub offset: usize,
 }
 
 impl Token {
-    pub(crate) fn new<O>(kind: TokenType, lexeme: String, literal: O, line: usize) -> Self
+    pub fn new<O>(kind: TokenType, lexeme: String, literal: O, offset: usize) -> Self
     where
         O: Into<Option<Object>>,
     {
@@ -20,9 +21,14 @@ impl Token {
             kind,
             lexeme,
             literal: literal.into(),
-            line,
+            offset,
         }
     }
+
+    /// The byte offset just past the lexeme.
+    pub fn end(&self) -> usize {
+        self.offset + self.lexeme.len()
+    }
 }
 
 impl Display for Token {